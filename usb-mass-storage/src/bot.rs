@@ -0,0 +1,302 @@
+//! Bulk-Only Transport: every command is a command block wrapper (CBW) on the bulk OUT endpoint,
+//! an optional data stage and a command status wrapper (CSW) on the bulk IN endpoint.
+
+use std::time::Duration;
+
+use log::{debug, trace, warn};
+use wasi_usb::{ControlRequest, DeviceHandle, Direction, Endpoint, Interface, LibusbError, Recipient};
+
+use crate::error::{Error, Result};
+use crate::scsi;
+
+const CBW_SIGNATURE: u32 = 0x4342_5355;
+const CSW_SIGNATURE: u32 = 0x5342_5355;
+const CBW_LENGTH: usize = 31;
+const CSW_LENGTH: usize = 13;
+
+/// Class request that resets the mass storage interface.
+const BULK_ONLY_RESET: u8 = 0xff;
+
+const CSW_PASSED: u8 = 0;
+const CSW_FAILED: u8 = 1;
+const CSW_PHASE_ERROR: u8 = 2;
+
+/// Data stage of a command.
+pub(crate) enum DataPhase<'a> {
+    None,
+    In(&'a mut [u8]),
+    Out(&'a [u8]),
+}
+
+impl DataPhase<'_> {
+    fn len(&self) -> usize {
+        match self {
+            DataPhase::None => 0,
+            DataPhase::In(data) => data.len(),
+            DataPhase::Out(data) => data.len(),
+        }
+    }
+}
+
+pub(crate) struct BulkOnly<'h> {
+    // declared before the interface so they are dropped before it is released
+    bulk_in: Endpoint<'h>,
+    bulk_out: Endpoint<'h>,
+    interface: Interface<'h>,
+    handle: &'h DeviceHandle,
+    lun: u8,
+    tag: u32,
+    timeout: Duration,
+}
+
+impl<'h> BulkOnly<'h> {
+    pub(crate) fn new(handle: &'h DeviceHandle, interface: Interface<'h>, bulk_in: Endpoint<'h>, bulk_out: Endpoint<'h>) -> Self {
+        Self {
+            bulk_in,
+            bulk_out,
+            interface,
+            handle,
+            lun: 0,
+            tag: 0,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    pub(crate) fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Run `command` and return how many bytes of the data stage the device processed.
+    /// A failed command is answered with the sense data of a REQUEST SENSE.
+    pub(crate) fn execute(&mut self, command: &[u8], data: DataPhase<'_>) -> Result<usize> {
+        let status = self.transport(command, data);
+        match self.recover(status)? {
+            Status::Passed(transferred) => Ok(transferred),
+            Status::Failed => Err(self.request_sense()?),
+        }
+    }
+
+    fn request_sense(&mut self) -> Result<Error> {
+        let mut sense = [0u8; scsi::SENSE_LENGTH];
+        let status = self.transport(&scsi::request_sense(), DataPhase::In(&mut sense));
+        match self.recover(status)? {
+            Status::Passed(_) => {
+                let (sense_key, asc, ascq) = scsi::parse_sense(&sense);
+                debug!("sense key {:#04x}, additional sense {:#04x}/{:#04x}", sense_key, asc, ascq);
+                Ok(Error::CommandFailed { sense_key, asc, ascq })
+            }
+            Status::Failed => Err(Error::CommandFailed { sense_key: 0, asc: 0, ascq: 0 }),
+        }
+    }
+
+    /// The device only accepts commands again after a reset recovery once it answered with an
+    /// invalid CSW or a phase error, or once a transfer failed other than with a stall that was
+    /// cleared, which leaves host and device disagreeing on the stage they are in.
+    fn recover(&self, status: Result<Status>) -> Result<Status> {
+        if let Err(e) = status {
+            if needs_reset(&e) {
+                warn!("{}, resetting the transport", e);
+                self.reset_recovery()?;
+            }
+        }
+        status
+    }
+
+    fn transport(&mut self, command: &[u8], data: DataPhase<'_>) -> Result<Status> {
+        self.tag = self.tag.wrapping_add(1);
+        let tag = self.tag;
+        let expected = data.len();
+
+        let direction_in = matches!(data, DataPhase::In(_));
+        let cbw = command_block_wrapper(tag, expected as u32, direction_in, self.lun, command);
+        trace!("CBW tag {} command {:02x?} data {} bytes", tag, command, expected);
+        self.bulk_out.write(&cbw, self.timeout)?;
+
+        // a stalled data stage still ends with a CSW once the halt is cleared
+        let stalled = |e: &wasi_usb::Error| e.kind() == LibusbError::Pipe;
+        let transferred = match data {
+            DataPhase::None => 0,
+            DataPhase::In(buf) => match self.bulk_in.read(buf.len() as u32, self.timeout) {
+                Ok(received) => {
+                    let n = received.len().min(buf.len());
+                    buf[..n].copy_from_slice(&received[..n]);
+                    n
+                }
+                Err(e) if stalled(&e) => {
+                    self.bulk_in.clear_halt()?;
+                    0
+                }
+                Err(e) => return Err(e.into()),
+            },
+            DataPhase::Out(buf) => match self.bulk_out.write(buf, self.timeout) {
+                Ok(()) => buf.len(),
+                Err(e) if stalled(&e) => {
+                    self.bulk_out.clear_halt()?;
+                    0
+                }
+                Err(e) => return Err(e.into()),
+            },
+        };
+
+        // a second stall of the CSW is an error like any other and ends in a reset recovery
+        let csw = match self.bulk_in.read(CSW_LENGTH as u32, self.timeout) {
+            Err(e) if stalled(&e) => {
+                self.bulk_in.clear_halt()?;
+                self.bulk_in.read(CSW_LENGTH as u32, self.timeout)?
+            }
+            result => result?,
+        };
+        let (status, residue) = parse_csw(&csw, tag, expected)?;
+        trace!("CSW tag {} status {} residue {}", tag, status, residue);
+
+        match status {
+            CSW_PASSED => {
+                // the residue is authoritative, it also covers a device that padded a short read
+                let processed = expected - residue;
+                if direction_in && transferred < processed {
+                    return Err(Error::ShortTransfer { expected: processed, transferred });
+                }
+                Ok(Status::Passed(processed))
+            }
+            CSW_FAILED => Ok(Status::Failed),
+            _ => Err(Error::PhaseError),
+        }
+    }
+
+    /// Reset the interface and clear the halt on both bulk endpoints.
+    pub(crate) fn reset_recovery(&self) -> Result<()> {
+        let request =
+            ControlRequest::class(Direction::Out, Recipient::Interface, BULK_ONLY_RESET, 0, self.interface.number().into());
+        self.handle.control_out(request, &[], self.timeout)?;
+        self.bulk_in.clear_halt()?;
+        self.bulk_out.clear_halt()?;
+        Ok(())
+    }
+}
+
+enum Status {
+    Passed(usize),
+    Failed,
+}
+
+/// Whether the transport has to be reset after `error`. A device that is gone cannot be reset,
+/// a short transfer ended with a valid CSW.
+fn needs_reset(error: &Error) -> bool {
+    match error {
+        Error::InvalidCsw(_) | Error::PhaseError => true,
+        Error::Usb(e) => !e.is_disconnect(),
+        _ => false,
+    }
+}
+
+fn command_block_wrapper(tag: u32, length: u32, direction_in: bool, lun: u8, command: &[u8]) -> [u8; CBW_LENGTH] {
+    let mut cbw = [0u8; CBW_LENGTH];
+    cbw[0..4].copy_from_slice(&CBW_SIGNATURE.to_le_bytes());
+    cbw[4..8].copy_from_slice(&tag.to_le_bytes());
+    cbw[8..12].copy_from_slice(&length.to_le_bytes());
+    cbw[12] = if direction_in { 0x80 } else { 0 };
+    cbw[13] = lun;
+    cbw[14] = command.len() as u8;
+    cbw[15..15 + command.len()].copy_from_slice(command);
+    cbw
+}
+
+/// Check a CSW against the CBW it answers, returning its status and data residue.
+fn parse_csw(csw: &[u8], tag: u32, expected: usize) -> Result<(u8, usize)> {
+    if csw.len() != CSW_LENGTH {
+        return Err(Error::InvalidCsw("wrong length"));
+    }
+    let field = |offset: usize| u32::from_le_bytes(csw[offset..offset + 4].try_into().unwrap());
+    if field(0) != CSW_SIGNATURE {
+        return Err(Error::InvalidCsw("wrong signature"));
+    }
+    if field(4) != tag {
+        return Err(Error::InvalidCsw("tag does not match the command"));
+    }
+    let residue = field(8) as usize;
+    let status = csw[12];
+    if residue > expected {
+        return Err(Error::InvalidCsw("residue exceeds the transfer length"));
+    }
+    if status > CSW_PHASE_ERROR {
+        return Err(Error::InvalidCsw("unknown status"));
+    }
+    Ok((status, residue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn csw(signature: u32, tag: u32, residue: u32, status: u8) -> Vec<u8> {
+        let mut csw = Vec::with_capacity(CSW_LENGTH);
+        csw.extend_from_slice(&signature.to_le_bytes());
+        csw.extend_from_slice(&tag.to_le_bytes());
+        csw.extend_from_slice(&residue.to_le_bytes());
+        csw.push(status);
+        csw
+    }
+
+    #[test]
+    fn cbw_layout() {
+        let command = scsi::read_10(0x0102_0304, 8);
+        let cbw = command_block_wrapper(7, 4096, true, 0, &command);
+        assert_eq!(&cbw[0..4], b"USBC");
+        assert_eq!(&cbw[4..8], &7u32.to_le_bytes());
+        assert_eq!(&cbw[8..12], &4096u32.to_le_bytes());
+        assert_eq!(cbw[12], 0x80);
+        assert_eq!(cbw[13], 0);
+        assert_eq!(cbw[14] as usize, command.len());
+        assert_eq!(&cbw[15..15 + command.len()], &command[..]);
+        assert!(cbw[15 + command.len()..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn cbw_out_and_lun() {
+        let command = scsi::test_unit_ready();
+        let cbw = command_block_wrapper(u32::MAX, 0, false, 3, &command);
+        assert_eq!(&cbw[4..8], &[0xff; 4]);
+        assert_eq!(&cbw[8..12], &[0; 4]);
+        assert_eq!(cbw[12], 0);
+        assert_eq!(cbw[13], 3);
+        assert_eq!(cbw[14] as usize, command.len());
+    }
+
+    #[test]
+    fn csw_passed_with_residue() {
+        assert_eq!(parse_csw(&csw(CSW_SIGNATURE, 9, 512, CSW_PASSED), 9, 4096), Ok((CSW_PASSED, 512)));
+        assert_eq!(parse_csw(&csw(CSW_SIGNATURE, 9, 0, CSW_FAILED), 9, 0), Ok((CSW_FAILED, 0)));
+        assert_eq!(parse_csw(&csw(CSW_SIGNATURE, 9, 0, CSW_PHASE_ERROR), 9, 0), Ok((CSW_PHASE_ERROR, 0)));
+    }
+
+    #[test]
+    fn csw_rejects_malformed() {
+        let valid = csw(CSW_SIGNATURE, 1, 0, CSW_PASSED);
+        assert_eq!(parse_csw(&valid[..12], 1, 0), Err(Error::InvalidCsw("wrong length")));
+        let mut long = valid.clone();
+        long.push(0);
+        assert_eq!(parse_csw(&long, 1, 0), Err(Error::InvalidCsw("wrong length")));
+        assert_eq!(
+            parse_csw(&csw(CBW_SIGNATURE, 1, 0, CSW_PASSED), 1, 0),
+            Err(Error::InvalidCsw("wrong signature"))
+        );
+        assert_eq!(parse_csw(&valid, 2, 0), Err(Error::InvalidCsw("tag does not match the command")));
+        assert_eq!(
+            parse_csw(&csw(CSW_SIGNATURE, 1, 13, CSW_PASSED), 1, 12),
+            Err(Error::InvalidCsw("residue exceeds the transfer length"))
+        );
+        assert_eq!(parse_csw(&csw(CSW_SIGNATURE, 1, 0, 3), 1, 0), Err(Error::InvalidCsw("unknown status")));
+    }
+
+    #[test]
+    fn reset_after_transport_errors() {
+        assert!(needs_reset(&Error::InvalidCsw("wrong signature")));
+        assert!(needs_reset(&Error::PhaseError));
+        assert!(needs_reset(&Error::Usb(LibusbError::Timeout.into())));
+        assert!(needs_reset(&Error::Usb(LibusbError::Pipe.into())));
+        assert!(needs_reset(&Error::Usb(LibusbError::Io.into())));
+        assert!(!needs_reset(&Error::Usb(LibusbError::NoDevice.into())));
+        assert!(!needs_reset(&Error::ShortTransfer { expected: 512, transferred: 0 }));
+        assert!(!needs_reset(&Error::CommandFailed { sense_key: 5, asc: 0x20, ascq: 0 }));
+    }
+}
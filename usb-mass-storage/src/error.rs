@@ -0,0 +1,70 @@
+use std::{fmt, io};
+
+/// Error of a mass storage operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The USB transfer itself failed, the transport was reset unless the device is gone.
+    Usb(wasi_usb::Error),
+    /// The device has no SCSI interface with Bulk-Only Transport and a pair of bulk endpoints.
+    NoInterface,
+    /// The device answered with a malformed command status wrapper, the transport was reset.
+    InvalidCsw(&'static str),
+    /// The device reported a phase error, the transport was reset.
+    PhaseError,
+    /// The device rejected the command, with the sense data it reported for it.
+    CommandFailed { sense_key: u8, asc: u8, ascq: u8 },
+    /// The device transferred fewer bytes than the command asked for.
+    ShortTransfer { expected: usize, transferred: usize },
+    /// The access lies beyond the last block of the device.
+    OutOfRange,
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl From<wasi_usb::Error> for Error {
+    fn from(error: wasi_usb::Error) -> Self {
+        Self::Usb(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usb(e) => write!(f, "USB error: {}", e),
+            Error::NoInterface => f.write_str("no bulk-only mass storage interface"),
+            Error::InvalidCsw(reason) => write!(f, "invalid command status wrapper: {}", reason),
+            Error::PhaseError => f.write_str("phase error"),
+            Error::CommandFailed { sense_key, asc, ascq } => write!(
+                f,
+                "command failed: sense key {:#04x}, additional sense {:#04x}/{:#04x}",
+                sense_key, asc, ascq
+            ),
+            Error::ShortTransfer { expected, transferred } => {
+                write!(f, "short transfer: {} of {} bytes", transferred, expected)
+            }
+            Error::OutOfRange => f.write_str("access beyond the end of the device"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Usb(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::Usb(e) if e.is_disconnect() => io::ErrorKind::NotConnected,
+            Error::Usb(e) if e.kind() == wasi_usb::LibusbError::Timeout => io::ErrorKind::TimedOut,
+            Error::ShortTransfer { .. } => io::ErrorKind::UnexpectedEof,
+            Error::OutOfRange => io::ErrorKind::InvalidInput,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, error)
+    }
+}
@@ -0,0 +1,31 @@
+//! USB mass storage for WASI-USB guests.
+//!
+//! Drives SCSI devices over the Bulk-Only Transport (USB flash drives, card readers, external
+//! disks) and exposes the medium through [`std::io::Read`], [`std::io::Write`] and
+//! [`std::io::Seek`], so partition table and file system crates can work on it directly.
+//! [`Slice`] narrows it down to a partition.
+//!
+//! ```no_run
+//! use std::io::Read;
+//! use usb_mass_storage::MassStorage;
+//! use wasi_usb::{Context, Filter};
+//!
+//! let context = Context::new()?;
+//! let device = context.devices_matching(&Filter::new().class(0x08))?.into_iter().next().expect("no drive");
+//! let handle = device.open()?;
+//! let mut storage = MassStorage::open(&device, &handle)?;
+//! let mut mbr = [0u8; 512];
+//! storage.read_exact(&mut mbr)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+mod bot;
+mod error;
+mod scsi;
+mod slice;
+mod storage;
+
+pub use error::{Error, Result};
+pub use scsi::{Capacity, Inquiry};
+pub use slice::Slice;
+pub use storage::MassStorage;
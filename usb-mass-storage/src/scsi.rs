@@ -0,0 +1,97 @@
+//! Command blocks of the SCSI commands a USB mass storage device has to support.
+
+pub(crate) const SENSE_LENGTH: usize = 18;
+pub(crate) const INQUIRY_LENGTH: usize = 36;
+pub(crate) const CAPACITY_LENGTH: usize = 8;
+
+/// Sense key of a command the device does not support.
+pub(crate) const ILLEGAL_REQUEST: u8 = 0x05;
+
+pub(crate) fn test_unit_ready() -> [u8; 6] {
+    [0x00, 0, 0, 0, 0, 0]
+}
+
+pub(crate) fn request_sense() -> [u8; 6] {
+    [0x03, 0, 0, 0, SENSE_LENGTH as u8, 0]
+}
+
+pub(crate) fn inquiry() -> [u8; 6] {
+    [0x12, 0, 0, 0, INQUIRY_LENGTH as u8, 0]
+}
+
+pub(crate) fn read_capacity() -> [u8; 10] {
+    [0x25, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+}
+
+pub(crate) fn read_10(lba: u32, blocks: u16) -> [u8; 10] {
+    block_command(0x28, lba, blocks)
+}
+
+pub(crate) fn write_10(lba: u32, blocks: u16) -> [u8; 10] {
+    block_command(0x2a, lba, blocks)
+}
+
+/// SYNCHRONIZE CACHE(10) of the whole medium.
+pub(crate) fn synchronize_cache() -> [u8; 10] {
+    [0x35, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+}
+
+fn block_command(opcode: u8, lba: u32, blocks: u16) -> [u8; 10] {
+    let mut command = [0u8; 10];
+    command[0] = opcode;
+    command[2..6].copy_from_slice(&lba.to_be_bytes());
+    command[7..9].copy_from_slice(&blocks.to_be_bytes());
+    command
+}
+
+/// Sense key, additional sense code and qualifier of fixed format sense data.
+pub(crate) fn parse_sense(sense: &[u8; SENSE_LENGTH]) -> (u8, u8, u8) {
+    (sense[2] & 0x0f, sense[12], sense[13])
+}
+
+/// Size of the medium as reported by READ CAPACITY(10).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Capacity {
+    /// Number of logical blocks.
+    pub blocks: u64,
+    /// Size of a logical block in bytes.
+    pub block_length: u32,
+}
+
+impl Capacity {
+    pub(crate) fn parse(data: &[u8; CAPACITY_LENGTH]) -> Self {
+        // the device reports the address of the last block, not the number of blocks
+        let last_lba = u32::from_be_bytes(data[0..4].try_into().unwrap());
+        let block_length = u32::from_be_bytes(data[4..8].try_into().unwrap());
+        Self { blocks: last_lba as u64 + 1, block_length }
+    }
+
+    /// Total size in bytes.
+    pub fn size(&self) -> u64 {
+        self.blocks * self.block_length as u64
+    }
+}
+
+/// Standard INQUIRY data identifying the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inquiry {
+    /// Peripheral device type, 0 for a direct access block device.
+    pub device_type: u8,
+    pub removable: bool,
+    pub vendor: String,
+    pub product: String,
+    pub revision: String,
+}
+
+impl Inquiry {
+    pub(crate) fn parse(data: &[u8; INQUIRY_LENGTH]) -> Self {
+        let text = |range: std::ops::Range<usize>| String::from_utf8_lossy(&data[range]).trim().to_string();
+        Self {
+            device_type: data[0] & 0x1f,
+            removable: data[1] & 0x80 != 0,
+            vendor: text(8..16),
+            product: text(16..32),
+            revision: text(32..36),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+/// A byte range of a seekable stream, e.g. a partition of a [`MassStorage`](crate::MassStorage),
+/// with positions relative to the start of the range.
+pub struct Slice<T> {
+    inner: T,
+    start: u64,
+    end: u64,
+    /// Position relative to `start`, it may lie past the end.
+    position: u64,
+}
+
+impl<T: Seek> Slice<T> {
+    /// The bytes `start..end` of `inner`.
+    pub fn new(inner: T, start: u64, end: u64) -> io::Result<Self> {
+        if start > end {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "slice ends before it starts"));
+        }
+        Ok(Self { inner, start, end, position: 0 })
+    }
+
+    pub fn len(&self) -> u64 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// How much of `length` bytes fit before the end, after moving `inner` to the position.
+    fn prepare(&mut self, length: usize) -> io::Result<usize> {
+        let remaining = self.len().saturating_sub(self.position);
+        if remaining == 0 || length == 0 {
+            return Ok(0);
+        }
+        self.inner.seek(SeekFrom::Start(self.start + self.position))?;
+        Ok(remaining.min(length as u64) as usize)
+    }
+}
+
+impl<T: Read + Seek> Read for Slice<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.prepare(buf.len())?;
+        if length == 0 {
+            return Ok(0);
+        }
+        let n = self.inner.read(&mut buf[..length])?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl<T: Write + Seek> Write for Slice<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let length = self.prepare(buf.len())?;
+        if length == 0 {
+            return Ok(0);
+        }
+        let n = self.inner.write(&buf[..length])?;
+        self.position += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<T: Seek> Seek for Slice<T> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek to a negative or overflowing position"))?;
+        Ok(self.position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn slice(start: u64, end: u64) -> Slice<Cursor<Vec<u8>>> {
+        Slice::new(Cursor::new((0..=255).collect()), start, end).unwrap()
+    }
+
+    #[test]
+    fn rejects_inverted_range() {
+        let error = Slice::new(Cursor::new(vec![0u8; 4]), 3, 2).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(slice(2, 2).is_empty());
+    }
+
+    #[test]
+    fn reads_only_the_range() {
+        let mut slice = slice(16, 24);
+        assert_eq!(slice.len(), 8);
+        let mut data = Vec::new();
+        slice.read_to_end(&mut data).unwrap();
+        assert_eq!(data, (16..24).collect::<Vec<u8>>());
+        assert_eq!(slice.read(&mut [0u8; 4]).unwrap(), 0);
+    }
+
+    #[test]
+    fn seeks_relative_to_the_range() {
+        let mut slice = slice(16, 24);
+        assert_eq!(slice.seek(SeekFrom::Start(2)).unwrap(), 2);
+        let mut byte = [0u8];
+        slice.read_exact(&mut byte).unwrap();
+        assert_eq!(byte, [18]);
+        assert_eq!(slice.seek(SeekFrom::End(-1)).unwrap(), 7);
+        slice.read_exact(&mut byte).unwrap();
+        assert_eq!(byte, [23]);
+        assert_eq!(slice.seek(SeekFrom::Current(-4)).unwrap(), 4);
+        assert!(slice.seek(SeekFrom::Current(-5)).is_err());
+        // the position may lie past the end, reads there return nothing
+        assert_eq!(slice.seek(SeekFrom::End(10)).unwrap(), 18);
+        assert_eq!(slice.read(&mut byte).unwrap(), 0);
+    }
+
+    #[test]
+    fn writes_are_cut_at_the_end() {
+        let mut slice = slice(4, 8);
+        slice.seek(SeekFrom::Start(2)).unwrap();
+        assert_eq!(slice.write(&[0xaa; 4]).unwrap(), 2);
+        assert_eq!(slice.write(&[0xbb]).unwrap(), 0);
+        let inner = slice.into_inner().into_inner();
+        assert_eq!(&inner[4..10], &[4, 5, 0xaa, 0xaa, 8, 9]);
+    }
+}
@@ -0,0 +1,320 @@
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use wasi_usb::{Device, DeviceHandle, Direction, EndpointDescriptor, InterfaceDescriptor, TransferType};
+
+use crate::bot::{BulkOnly, DataPhase};
+use crate::error::{Error, Result};
+use crate::scsi::{self, Capacity, Inquiry};
+
+const CLASS_MASS_STORAGE: u8 = 0x08;
+const SUBCLASS_SCSI: u8 = 0x06;
+const PROTOCOL_BULK_ONLY: u8 = 0x50;
+
+/// Most data a single READ(10) or WRITE(10) moves.
+const MAX_TRANSFER: u64 = 64 * 1024;
+
+/// How often TEST UNIT READY is repeated while the device reports a unit attention or is
+/// still spinning up.
+const READY_ATTEMPTS: usize = 5;
+
+/// A USB mass storage device as one block device, readable, writable and seekable like a file.
+///
+/// Reads and writes that are not aligned to blocks are split up, partial blocks are written
+/// with a read-modify-write. Only LUN 0 is used.
+pub struct MassStorage<'h> {
+    transport: BulkOnly<'h>,
+    capacity: Capacity,
+    position: u64,
+}
+
+impl<'h> MassStorage<'h> {
+    /// Claim the SCSI Bulk-Only interface of `device`, opened as `handle`, and wait for the
+    /// medium to become ready. The device is configured if it is not yet, the endpoints are
+    /// taken from the descriptor.
+    pub fn open(device: &Device, handle: &'h DeviceHandle) -> Result<Self> {
+        if handle.configuration()?.is_none() {
+            let value = device.config_descriptor(0)?.configuration_value;
+            debug!("configuring the device with configuration {}", value);
+            handle.set_configuration(Some(value))?;
+        }
+
+        let config = device.active_config_descriptor()?;
+        let setting = config
+            .interfaces
+            .iter()
+            .flat_map(|interface| &interface.alt_settings)
+            .find(|setting| is_bulk_only(setting))
+            .ok_or(Error::NoInterface)?;
+        let number = setting.interface_number;
+        info!("mass storage interface {} alternate setting {}", number, setting.alternate_setting);
+
+        if let Err(e) = handle.set_auto_detach_kernel_driver(true) {
+            debug!("cannot detach the kernel driver automatically: {}", e);
+        }
+        let interface = handle.claim_interface(number)?;
+        if setting.alternate_setting != 0 {
+            interface.set_alt_setting(setting.alternate_setting)?;
+        }
+        let bulk_in = interface.find_endpoint(TransferType::Bulk, Direction::In)?;
+        let bulk_out = interface.find_endpoint(TransferType::Bulk, Direction::Out)?;
+        debug!("bulk endpoints IN {:#04x} OUT {:#04x}", bulk_in.address(), bulk_out.address());
+
+        let mut storage = Self {
+            transport: BulkOnly::new(handle, interface, bulk_in, bulk_out),
+            capacity: Capacity::default(),
+            position: 0,
+        };
+        storage.wait_ready()?;
+        storage.capacity = storage.read_capacity()?;
+        if storage.capacity.block_length == 0 {
+            // card readers without a card answer with an empty capacity, report it as
+            // MEDIUM NOT PRESENT
+            return Err(Error::CommandFailed { sense_key: 0x02, asc: 0x3a, ascq: 0 });
+        }
+        info!(
+            "{} blocks of {} bytes, {} bytes",
+            storage.capacity.blocks,
+            storage.capacity.block_length,
+            storage.capacity.size()
+        );
+        Ok(storage)
+    }
+
+    /// Timeout of each transfer, 5 seconds by default.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.transport.set_timeout(timeout);
+    }
+
+    pub fn capacity(&self) -> Capacity {
+        self.capacity
+    }
+
+    pub fn block_length(&self) -> u32 {
+        self.capacity.block_length
+    }
+
+    /// Size of the medium in bytes.
+    pub fn len(&self) -> u64 {
+        self.capacity.size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn inquiry(&mut self) -> Result<Inquiry> {
+        let mut data = [0u8; scsi::INQUIRY_LENGTH];
+        self.transport.execute(&scsi::inquiry(), DataPhase::In(&mut data))?;
+        Ok(Inquiry::parse(&data))
+    }
+
+    /// Read whole blocks starting at `lba`, `buf` has to be a multiple of the block length.
+    pub fn read_blocks(&mut self, lba: u64, buf: &mut [u8]) -> Result<()> {
+        let block_length = self.block_length() as usize;
+        self.check_range(lba, buf.len())?;
+        for (i, chunk) in buf.chunks_mut(self.max_blocks() as usize * block_length).enumerate() {
+            let first = lba + (i * self.max_blocks() as usize) as u64;
+            let blocks = (chunk.len() / block_length) as u16;
+            let expected = chunk.len();
+            let transferred = self.transport.execute(&scsi::read_10(first as u32, blocks), DataPhase::In(chunk))?;
+            if transferred < expected {
+                return Err(Error::ShortTransfer { expected, transferred });
+            }
+        }
+        Ok(())
+    }
+
+    /// Write whole blocks starting at `lba`, `data` has to be a multiple of the block length.
+    pub fn write_blocks(&mut self, lba: u64, data: &[u8]) -> Result<()> {
+        let block_length = self.block_length() as usize;
+        self.check_range(lba, data.len())?;
+        for (i, chunk) in data.chunks(self.max_blocks() as usize * block_length).enumerate() {
+            let first = lba + (i * self.max_blocks() as usize) as u64;
+            let blocks = (chunk.len() / block_length) as u16;
+            let transferred = self.transport.execute(&scsi::write_10(first as u32, blocks), DataPhase::Out(chunk))?;
+            if transferred < chunk.len() {
+                return Err(Error::ShortTransfer { expected: chunk.len(), transferred });
+            }
+        }
+        Ok(())
+    }
+
+    fn wait_ready(&mut self) -> Result<()> {
+        let mut attempt = 1;
+        loop {
+            match self.transport.execute(&scsi::test_unit_ready(), DataPhase::None) {
+                Ok(_) => return Ok(()),
+                Err(e @ Error::CommandFailed { .. }) if attempt < READY_ATTEMPTS => {
+                    warn!("device not ready: {}", e);
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+                Err(e) => return Err(e),
+            }
+            attempt += 1;
+        }
+    }
+
+    fn read_capacity(&mut self) -> Result<Capacity> {
+        let mut data = [0u8; scsi::CAPACITY_LENGTH];
+        let transferred = self.transport.execute(&scsi::read_capacity(), DataPhase::In(&mut data))?;
+        if transferred < data.len() {
+            return Err(Error::ShortTransfer { expected: data.len(), transferred });
+        }
+        Ok(Capacity::parse(&data))
+    }
+
+    fn check_range(&self, lba: u64, length: usize) -> Result<()> {
+        let block_length = self.block_length() as usize;
+        if block_length == 0 || !length.is_multiple_of(block_length) {
+            return Err(Error::OutOfRange);
+        }
+        let end = lba + (length / block_length) as u64;
+        // READ(10) and WRITE(10) only address the first 2^32 blocks
+        if end > self.capacity.blocks || end > u32::MAX as u64 + 1 {
+            return Err(Error::OutOfRange);
+        }
+        Ok(())
+    }
+
+    fn max_blocks(&self) -> u64 {
+        max_blocks(self.block_length())
+    }
+
+    /// Range of blocks the next access of at most `length` bytes at the position touches,
+    /// as first block, offset into it and number of bytes.
+    fn span(&self, length: usize) -> (u64, usize, usize) {
+        span(self.position, self.len(), self.block_length(), length)
+    }
+}
+
+fn max_blocks(block_length: u32) -> u64 {
+    (MAX_TRANSFER / block_length as u64).max(1)
+}
+
+/// See [`MassStorage::span`], for a medium of `size` bytes.
+fn span(position: u64, size: u64, block_length: u32, length: usize) -> (u64, usize, usize) {
+    let first = position / block_length as u64;
+    let offset = (position % block_length as u64) as usize;
+    let limit = max_blocks(block_length) * block_length as u64 - offset as u64;
+    let length = (length as u64).min(size - position).min(limit) as usize;
+    (first, offset, length)
+}
+
+impl Read for MassStorage<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.position >= self.len() {
+            return Ok(0);
+        }
+        let block_length = self.block_length() as usize;
+        let (first, offset, length) = self.span(buf.len());
+
+        if offset == 0 && length >= block_length {
+            let length = length - length % block_length;
+            self.read_blocks(first, &mut buf[..length])?;
+            self.position += length as u64;
+            return Ok(length);
+        }
+
+        let mut data = vec![0u8; (offset + length).div_ceil(block_length) * block_length];
+        self.read_blocks(first, &mut data)?;
+        buf[..length].copy_from_slice(&data[offset..offset + length]);
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl Write for MassStorage<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.position >= self.len() {
+            return Ok(0);
+        }
+        let block_length = self.block_length() as usize;
+        let (first, offset, length) = self.span(buf.len());
+
+        if offset == 0 && length >= block_length {
+            let length = length - length % block_length;
+            self.write_blocks(first, &buf[..length])?;
+            self.position += length as u64;
+            return Ok(length);
+        }
+
+        let mut data = vec![0u8; (offset + length).div_ceil(block_length) * block_length];
+        self.read_blocks(first, &mut data)?;
+        data[offset..offset + length].copy_from_slice(&buf[..length]);
+        self.write_blocks(first, &data)?;
+        self.position += length as u64;
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.transport.execute(&scsi::synchronize_cache(), DataPhase::None) {
+            Ok(_) => Ok(()),
+            // devices without a write cache may not know the command
+            Err(Error::CommandFailed { sense_key: scsi::ILLEGAL_REQUEST, .. }) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl Seek for MassStorage<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seek to a negative or overflowing position"))?;
+        Ok(self.position)
+    }
+}
+
+fn is_bulk_only(setting: &InterfaceDescriptor) -> bool {
+    let bulk = |e: &&EndpointDescriptor| e.attributes & 0x03 == 0x02;
+    setting.interface_class == CLASS_MASS_STORAGE
+        && setting.interface_subclass == SUBCLASS_SCSI
+        && setting.interface_protocol == PROTOCOL_BULK_ONLY
+        && setting.endpoints.iter().filter(bulk).any(|e| e.endpoint_address & 0x80 != 0)
+        && setting.endpoints.iter().filter(bulk).any(|e| e.endpoint_address & 0x80 == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: u64 = 1024 * 512;
+
+    #[test]
+    fn span_aligned() {
+        assert_eq!(span(0, SIZE, 512, 512), (0, 0, 512));
+        assert_eq!(span(4096, SIZE, 512, 1024), (8, 0, 1024));
+    }
+
+    #[test]
+    fn span_unaligned() {
+        assert_eq!(span(100, SIZE, 512, 10), (0, 100, 10));
+        assert_eq!(span(1000, SIZE, 512, 100), (1, 488, 100));
+    }
+
+    #[test]
+    fn span_stops_at_the_end() {
+        assert_eq!(span(SIZE - 10, SIZE, 512, 512), (1023, 502, 10));
+        assert_eq!(span(SIZE, SIZE, 512, 512), (1024, 0, 0));
+    }
+
+    #[test]
+    fn span_is_limited_to_one_transfer() {
+        assert_eq!(span(0, SIZE, 512, 1 << 20), (0, 0, MAX_TRANSFER as usize));
+        // the blocks of a transfer start at the one the position lies in
+        assert_eq!(span(10, SIZE, 512, 1 << 20), (0, 10, MAX_TRANSFER as usize - 10));
+    }
+
+    #[test]
+    fn span_of_blocks_larger_than_a_transfer() {
+        let block_length = 128 * 1024;
+        assert_eq!(max_blocks(block_length), 1);
+        assert_eq!(span(5, 4 * block_length as u64, block_length, 1 << 20), (0, 5, block_length as usize - 5));
+    }
+}
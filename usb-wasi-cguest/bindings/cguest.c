@@ -1,86 +1,209 @@
 // Generated by `wit-bindgen` 0.42.1. DO NOT EDIT!
 #include "cguest.h"
 #include <stdlib.h>
+#include <string.h>
 
-// Imported Functions from `component:usb/transfers@0.2.1`
+// Imported Functions from `component:usb/transfers@0.3.0`
 
-__attribute__((__import_module__("component:usb/transfers@0.2.1"), __import_name__("[method]transfer.submit-transfer")))
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("[method]transfer.submit-transfer")))
 extern void __wasm_import_component_usb_transfers_method_transfer_submit_transfer(int32_t, uint8_t *, size_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/transfers@0.2.1"), __import_name__("[method]transfer.cancel-transfer")))
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("[method]transfer.cancel-transfer")))
 extern void __wasm_import_component_usb_transfers_method_transfer_cancel_transfer(int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/transfers@0.2.1"), __import_name__("await-transfer")))
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("await-transfer")))
 extern void __wasm_import_component_usb_transfers_await_transfer(int32_t, uint8_t *);
 
-// Imported Functions from `component:usb/device@0.2.1`
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("submit-batch")))
+extern void __wasm_import_component_usb_transfers_submit_batch(uint8_t *, size_t, uint8_t *, size_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]usb-device.open")))
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("await-any")))
+extern void __wasm_import_component_usb_transfers_await_any(uint8_t *, size_t, int32_t, uint8_t *);
+
+// Imported Functions from `wasi:io/poll@0.2.5`
+
+__attribute__((__import_module__("wasi:io/poll@0.2.5"), __import_name__("[method]pollable.ready")))
+extern int32_t __wasm_import_wasi_io_poll_method_pollable_ready(int32_t);
+
+__attribute__((__import_module__("wasi:io/poll@0.2.5"), __import_name__("[method]pollable.block")))
+extern void __wasm_import_wasi_io_poll_method_pollable_block(int32_t);
+
+__attribute__((__import_module__("wasi:io/poll@0.2.5"), __import_name__("poll")))
+extern void __wasm_import_wasi_io_poll_poll(uint8_t *, size_t, uint8_t *);
+
+// Imported Functions from `component:usb/device@0.3.0`
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.open")))
 extern void __wasm_import_component_usb_device_method_usb_device_open(int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]usb-device.get-configuration-descriptor")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.id")))
+extern int64_t __wasm_import_component_usb_device_method_usb_device_id(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-device-descriptor")))
+extern void __wasm_import_component_usb_device_method_usb_device_get_device_descriptor(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-configuration-descriptor")))
 extern void __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]usb-device.get-configuration-descriptor-by-value")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-configuration-descriptor-by-value")))
 extern void __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor_by_value(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]usb-device.get-active-configuration-descriptor")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-active-configuration-descriptor")))
 extern void __wasm_import_component_usb_device_method_usb_device_get_active_configuration_descriptor(int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.get-configuration")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-location")))
+extern void __wasm_import_component_usb_device_method_usb_device_get_location(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-speed")))
+extern int32_t __wasm_import_component_usb_device_method_usb_device_get_speed(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-port-path")))
+extern void __wasm_import_component_usb_device_method_usb_device_get_port_path(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-metadata")))
+extern void __wasm_import_component_usb_device_method_usb_device_get_metadata(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]usb-device.get-parent")))
+extern void __wasm_import_component_usb_device_method_usb_device_get_parent(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-configuration")))
 extern void __wasm_import_component_usb_device_method_device_handle_get_configuration(int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.set-configuration")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.set-configuration")))
 extern void __wasm_import_component_usb_device_method_device_handle_set_configuration(int32_t, int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.claim-interface")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.claim-interface")))
 extern void __wasm_import_component_usb_device_method_device_handle_claim_interface(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.release-interface")))
-extern void __wasm_import_component_usb_device_method_device_handle_release_interface(int32_t, int32_t, uint8_t *);
-
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.set-interface-altsetting")))
-extern void __wasm_import_component_usb_device_method_device_handle_set_interface_altsetting(int32_t, int32_t, int32_t, uint8_t *);
-
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.clear-halt")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.clear-halt")))
 extern void __wasm_import_component_usb_device_method_device_handle_clear_halt(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.reset-device")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.reset-device")))
 extern void __wasm_import_component_usb_device_method_device_handle_reset_device(int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.alloc-streams")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.alloc-streams")))
 extern void __wasm_import_component_usb_device_method_device_handle_alloc_streams(int32_t, int32_t, uint8_t *, size_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.free-streams")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.free-streams")))
 extern void __wasm_import_component_usb_device_method_device_handle_free_streams(int32_t, uint8_t *, size_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.kernel-driver-active")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.kernel-driver-active")))
 extern void __wasm_import_component_usb_device_method_device_handle_kernel_driver_active(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.detach-kernel-driver")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.detach-kernel-driver")))
 extern void __wasm_import_component_usb_device_method_device_handle_detach_kernel_driver(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.attach-kernel-driver")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.set-auto-detach-kernel-driver")))
+extern void __wasm_import_component_usb_device_method_device_handle_set_auto_detach_kernel_driver(int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.attach-kernel-driver")))
 extern void __wasm_import_component_usb_device_method_device_handle_attach_kernel_driver(int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.new-transfer")))
-extern void __wasm_import_component_usb_device_method_device_handle_new_transfer(int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *);
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-string-descriptor")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_string_descriptor(int32_t, int32_t, int32_t, uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[method]device-handle.close")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-supported-languages")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_supported_languages(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-manufacturer")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_manufacturer(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-product")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_product(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-serial-number")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_serial_number(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-raw-configuration-descriptor")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_raw_configuration_descriptor(int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.get-bos-descriptor")))
+extern void __wasm_import_component_usb_device_method_device_handle_get_bos_descriptor(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.new-control-transfer")))
+extern void __wasm_import_component_usb_device_method_device_handle_new_control_transfer(int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.control-in")))
+extern void __wasm_import_component_usb_device_method_device_handle_control_in(int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.control-out")))
+extern void __wasm_import_component_usb_device_method_device_handle_control_out(int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *, size_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.bulk-read")))
+extern void __wasm_import_component_usb_device_method_device_handle_bulk_read(int32_t, int32_t, int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.bulk-write")))
+extern void __wasm_import_component_usb_device_method_device_handle_bulk_write(int32_t, int32_t, uint8_t *, size_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.interrupt-read")))
+extern void __wasm_import_component_usb_device_method_device_handle_interrupt_read(int32_t, int32_t, int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.interrupt-write")))
+extern void __wasm_import_component_usb_device_method_device_handle_interrupt_write(int32_t, int32_t, uint8_t *, size_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.device-id")))
+extern int64_t __wasm_import_component_usb_device_method_device_handle_device_id(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.on-disconnect")))
+extern int32_t __wasm_import_component_usb_device_method_device_handle_on_disconnect(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]device-handle.close")))
 extern void __wasm_import_component_usb_device_method_device_handle_close(int32_t);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("init")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]claimed-interface.interface-number")))
+extern int32_t __wasm_import_component_usb_device_method_claimed_interface_interface_number(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]claimed-interface.alt-setting")))
+extern int32_t __wasm_import_component_usb_device_method_claimed_interface_alt_setting(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]claimed-interface.set-alt-setting")))
+extern void __wasm_import_component_usb_device_method_claimed_interface_set_alt_setting(int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]claimed-interface.endpoints")))
+extern void __wasm_import_component_usb_device_method_claimed_interface_endpoints(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]claimed-interface.endpoint")))
+extern void __wasm_import_component_usb_device_method_claimed_interface_endpoint(int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.address")))
+extern int32_t __wasm_import_component_usb_device_method_endpoint_address(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.direction")))
+extern int32_t __wasm_import_component_usb_device_method_endpoint_direction(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.transfer-type")))
+extern int32_t __wasm_import_component_usb_device_method_endpoint_transfer_type(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.max-packet-size")))
+extern int32_t __wasm_import_component_usb_device_method_endpoint_max_packet_size(int32_t);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.descriptor")))
+extern void __wasm_import_component_usb_device_method_endpoint_descriptor(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.new-transfer")))
+extern void __wasm_import_component_usb_device_method_endpoint_new_transfer(int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[method]endpoint.clear-halt")))
+extern void __wasm_import_component_usb_device_method_endpoint_clear_halt(int32_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("init")))
 extern void __wasm_import_component_usb_device_init(uint8_t *);
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("list-devices")))
-extern void __wasm_import_component_usb_device_list_devices(uint8_t *);
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("list-devices")))
+extern void __wasm_import_component_usb_device_list_devices(int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, int32_t, uint8_t *, size_t, int32_t, uint8_t *, size_t, uint8_t *);
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("open-device-with-vid-pid")))
+extern void __wasm_import_component_usb_device_open_device_with_vid_pid(int32_t, int32_t, uint8_t *);
 
-// Imported Functions from `component:usb/usb-hotplug@0.2.1`
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("capabilities")))
+extern void __wasm_import_component_usb_device_capabilities(uint8_t *);
 
-__attribute__((__import_module__("component:usb/usb-hotplug@0.2.1"), __import_name__("enable-hotplug")))
+// Imported Functions from `component:usb/usb-hotplug@0.3.0`
+
+__attribute__((__import_module__("component:usb/usb-hotplug@0.3.0"), __import_name__("enable-hotplug")))
 extern void __wasm_import_component_usb_usb_hotplug_enable_hotplug(uint8_t *);
 
-__attribute__((__import_module__("component:usb/usb-hotplug@0.2.1"), __import_name__("poll-events")))
+__attribute__((__import_module__("component:usb/usb-hotplug@0.3.0"), __import_name__("poll-events")))
 extern void __wasm_import_component_usb_usb_hotplug_poll_events(uint8_t *);
 
 // Exported Functions from `wasi:cli/run@0.2.5`
@@ -99,7 +222,7 @@ void *cabi_realloc(void *ptr, size_t old_size, size_t align, size_t new_size) {
 
 // Helper Functions
 
-__attribute__((__import_module__("component:usb/transfers@0.2.1"), __import_name__("[resource-drop]transfer")))
+__attribute__((__import_module__("component:usb/transfers@0.3.0"), __import_name__("[resource-drop]transfer")))
 extern void __wasm_import_component_usb_transfers_transfer_drop(int32_t handle);
 
 void component_usb_transfers_transfer_drop_own(component_usb_transfers_own_transfer_t handle) {
@@ -133,6 +256,44 @@ void component_usb_transfers_result_list_u8_libusb_error_free(component_usb_tran
   }
 }
 
+void component_usb_transfers_list_borrow_transfer_free(component_usb_transfers_list_borrow_transfer_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_transfers_borrow_transfer_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+    }
+    free(list_ptr);
+  }
+}
+
+void cguest_list_list_u8_free(cguest_list_list_u8_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    cguest_list_u8_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+      cguest_list_u8_free(&list_ptr[i]);
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_transfers_result_void_batch_error_free(component_usb_transfers_result_void_batch_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
+
+void component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_free(component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t *ptr) {
+  component_usb_transfers_result_list_u8_libusb_error_free(&ptr->f1);
+}
+
+void component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_free(component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+    component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_free(&ptr->val.ok);
+  } else {
+  }
+}
+
 void component_usb_configuration_config_value_free(component_usb_configuration_config_value_t *ptr) {
   switch ((int32_t) ptr->tag) {
     case 1: {
@@ -141,11 +302,22 @@ void component_usb_configuration_config_value_free(component_usb_configuration_c
   }
 }
 
+void component_usb_descriptors_option_ss_endpoint_companion_free(component_usb_descriptors_option_ss_endpoint_companion_t *ptr) {
+  if (ptr->is_some) {
+  }
+}
+
+void component_usb_descriptors_endpoint_descriptor_free(component_usb_descriptors_endpoint_descriptor_t *ptr) {
+  cguest_list_u8_free(&ptr->extra);
+  component_usb_descriptors_option_ss_endpoint_companion_free(&ptr->ss_endpoint_companion);
+}
+
 void component_usb_descriptors_list_endpoint_descriptor_free(component_usb_descriptors_list_endpoint_descriptor_t *ptr) {
   size_t list_len = ptr->len;
   if (list_len > 0) {
     component_usb_descriptors_endpoint_descriptor_t *list_ptr = ptr->ptr;
     for (size_t i = 0; i < list_len; i++) {
+      component_usb_descriptors_endpoint_descriptor_free(&list_ptr[i]);
     }
     free(list_ptr);
   }
@@ -153,6 +325,7 @@ void component_usb_descriptors_list_endpoint_descriptor_free(component_usb_descr
 
 void component_usb_descriptors_interface_descriptor_free(component_usb_descriptors_interface_descriptor_t *ptr) {
   component_usb_descriptors_list_endpoint_descriptor_free(&ptr->endpoints);
+  cguest_list_u8_free(&ptr->extra);
 }
 
 void component_usb_descriptors_list_interface_descriptor_free(component_usb_descriptors_list_interface_descriptor_t *ptr) {
@@ -166,8 +339,133 @@ void component_usb_descriptors_list_interface_descriptor_free(component_usb_desc
   }
 }
 
+void component_usb_descriptors_usb_interface_free(component_usb_descriptors_usb_interface_t *ptr) {
+  component_usb_descriptors_list_interface_descriptor_free(&ptr->alt_settings);
+}
+
+void component_usb_descriptors_list_usb_interface_free(component_usb_descriptors_list_usb_interface_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_descriptors_usb_interface_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+      component_usb_descriptors_usb_interface_free(&list_ptr[i]);
+    }
+    free(list_ptr);
+  }
+}
+
 void component_usb_descriptors_configuration_descriptor_free(component_usb_descriptors_configuration_descriptor_t *ptr) {
-  component_usb_descriptors_list_interface_descriptor_free(&ptr->interfaces);
+  component_usb_descriptors_list_usb_interface_free(&ptr->interfaces);
+  cguest_list_u8_free(&ptr->extra);
+}
+
+void cguest_list_u32_free(cguest_list_u32_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    uint32_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_descriptors_superspeed_plus_capability_free(component_usb_descriptors_superspeed_plus_capability_t *ptr) {
+  cguest_list_u32_free(&ptr->sublink_speed_attributes);
+}
+
+void component_usb_descriptors_list_ms_os20_descriptor_set_free(component_usb_descriptors_list_ms_os20_descriptor_set_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_descriptors_ms_os20_descriptor_set_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_descriptors_platform_kind_free(component_usb_descriptors_platform_kind_t *ptr) {
+  switch ((int32_t) ptr->tag) {
+    case 0: {
+      break;
+    }
+    case 1: {
+      component_usb_descriptors_list_ms_os20_descriptor_set_free(&ptr->val.ms_os20);
+      break;
+    }
+  }
+}
+
+void component_usb_descriptors_platform_capability_free(component_usb_descriptors_platform_capability_t *ptr) {
+  cguest_list_u8_free(&ptr->uuid);
+  cguest_list_u8_free(&ptr->data);
+  component_usb_descriptors_platform_kind_free(&ptr->kind);
+}
+
+void component_usb_descriptors_unknown_capability_free(component_usb_descriptors_unknown_capability_t *ptr) {
+  cguest_list_u8_free(&ptr->data);
+}
+
+void component_usb_descriptors_device_capability_free(component_usb_descriptors_device_capability_t *ptr) {
+  switch ((int32_t) ptr->tag) {
+    case 0: {
+      break;
+    }
+    case 1: {
+      break;
+    }
+    case 2: {
+      component_usb_descriptors_superspeed_plus_capability_free(&ptr->val.superspeed_plus);
+      break;
+    }
+    case 3: {
+      cguest_list_u8_free(&ptr->val.container_id);
+      break;
+    }
+    case 4: {
+      component_usb_descriptors_platform_capability_free(&ptr->val.platform);
+      break;
+    }
+    case 5: {
+      component_usb_descriptors_unknown_capability_free(&ptr->val.other);
+      break;
+    }
+  }
+}
+
+void component_usb_descriptors_list_device_capability_free(component_usb_descriptors_list_device_capability_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_descriptors_device_capability_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+      component_usb_descriptors_device_capability_free(&list_ptr[i]);
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_descriptors_bos_descriptor_free(component_usb_descriptors_bos_descriptor_t *ptr) {
+  component_usb_descriptors_list_device_capability_free(&ptr->capabilities);
+}
+
+__attribute__((__import_module__("wasi:io/poll@0.2.5"), __import_name__("[resource-drop]pollable")))
+extern void __wasm_import_wasi_io_poll_pollable_drop(int32_t handle);
+
+void wasi_io_poll_pollable_drop_own(wasi_io_poll_own_pollable_t handle) {
+  __wasm_import_wasi_io_poll_pollable_drop(handle.__handle);
+}
+
+wasi_io_poll_borrow_pollable_t wasi_io_poll_borrow_pollable(wasi_io_poll_own_pollable_t arg) {
+  return (wasi_io_poll_borrow_pollable_t) { arg.__handle };
+}
+
+void wasi_io_poll_list_borrow_pollable_free(wasi_io_poll_list_borrow_pollable_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    wasi_io_poll_borrow_pollable_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+    }
+    free(list_ptr);
+  }
 }
 
 void component_usb_device_config_value_free(component_usb_device_config_value_t *ptr) {
@@ -182,7 +480,15 @@ void component_usb_device_interface_descriptor_free(component_usb_device_interfa
   component_usb_descriptors_interface_descriptor_free(ptr);
 }
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[resource-drop]usb-device")))
+void component_usb_device_endpoint_descriptor_free(component_usb_device_endpoint_descriptor_t *ptr) {
+  component_usb_descriptors_endpoint_descriptor_free(ptr);
+}
+
+void component_usb_device_bos_descriptor_free(component_usb_device_bos_descriptor_t *ptr) {
+  component_usb_descriptors_bos_descriptor_free(ptr);
+}
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[resource-drop]usb-device")))
 extern void __wasm_import_component_usb_device_usb_device_drop(int32_t handle);
 
 void component_usb_device_usb_device_drop_own(component_usb_device_own_usb_device_t handle) {
@@ -193,7 +499,7 @@ component_usb_device_borrow_usb_device_t component_usb_device_borrow_usb_device(
   return (component_usb_device_borrow_usb_device_t) { arg.__handle };
 }
 
-__attribute__((__import_module__("component:usb/device@0.2.1"), __import_name__("[resource-drop]device-handle")))
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[resource-drop]device-handle")))
 extern void __wasm_import_component_usb_device_device_handle_drop(int32_t handle);
 
 void component_usb_device_device_handle_drop_own(component_usb_device_own_device_handle_t handle) {
@@ -204,12 +510,98 @@ component_usb_device_borrow_device_handle_t component_usb_device_borrow_device_h
   return (component_usb_device_borrow_device_handle_t) { arg.__handle };
 }
 
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[resource-drop]claimed-interface")))
+extern void __wasm_import_component_usb_device_claimed_interface_drop(int32_t handle);
+
+void component_usb_device_claimed_interface_drop_own(component_usb_device_own_claimed_interface_t handle) {
+  __wasm_import_component_usb_device_claimed_interface_drop(handle.__handle);
+}
+
+component_usb_device_borrow_claimed_interface_t component_usb_device_borrow_claimed_interface(component_usb_device_own_claimed_interface_t arg) {
+  return (component_usb_device_borrow_claimed_interface_t) { arg.__handle };
+}
+
+__attribute__((__import_module__("component:usb/device@0.3.0"), __import_name__("[resource-drop]endpoint")))
+extern void __wasm_import_component_usb_device_endpoint_drop(int32_t handle);
+
+void component_usb_device_endpoint_drop_own(component_usb_device_own_endpoint_t handle) {
+  __wasm_import_component_usb_device_endpoint_drop(handle.__handle);
+}
+
+component_usb_device_borrow_endpoint_t component_usb_device_borrow_endpoint(component_usb_device_own_endpoint_t arg) {
+  return (component_usb_device_borrow_endpoint_t) { arg.__handle };
+}
+
+void cguest_option_string_free(cguest_option_string_t *ptr) {
+  if (ptr->is_some) {
+    cguest_string_free(&ptr->val);
+  }
+}
+
+void component_usb_device_interface_metadata_free(component_usb_device_interface_metadata_t *ptr) {
+  cguest_option_string_free(&ptr->driver);
+}
+
+void component_usb_device_list_interface_metadata_free(component_usb_device_list_interface_metadata_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_device_interface_metadata_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+      component_usb_device_interface_metadata_free(&list_ptr[i]);
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_device_device_metadata_free(component_usb_device_device_metadata_t *ptr) {
+  cguest_option_string_free(&ptr->manufacturer);
+  cguest_option_string_free(&ptr->product);
+  cguest_option_string_free(&ptr->serial_number);
+  component_usb_device_list_interface_metadata_free(&ptr->interfaces);
+}
+
+void cguest_option_u16_free(cguest_option_u16_t *ptr) {
+  if (ptr->is_some) {
+  }
+}
+
+void cguest_option_u8_free(cguest_option_u8_t *ptr) {
+  if (ptr->is_some) {
+  }
+}
+
+void cguest_option_list_u8_free(cguest_option_list_u8_t *ptr) {
+  if (ptr->is_some) {
+    cguest_list_u8_free(&ptr->val);
+  }
+}
+
+void component_usb_device_device_filter_free(component_usb_device_device_filter_t *ptr) {
+  cguest_option_u16_free(&ptr->vendor_id);
+  cguest_option_u16_free(&ptr->product_id);
+  cguest_option_u8_free(&ptr->class_);
+  cguest_option_u8_free(&ptr->bus);
+  cguest_option_list_u8_free(&ptr->port_path);
+  cguest_option_string_free(&ptr->serial_number);
+}
+
+void component_usb_device_backend_capabilities_free(component_usb_device_backend_capabilities_t *ptr) {
+  cguest_string_free(&ptr->backend);
+  cguest_string_free(&ptr->version);
+}
+
 void component_usb_device_result_own_device_handle_libusb_error_free(component_usb_device_result_own_device_handle_libusb_error_t *ptr) {
   if (!ptr->is_err) {
   } else {
   }
 }
 
+void component_usb_device_result_device_descriptor_libusb_error_free(component_usb_device_result_device_descriptor_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
+
 void component_usb_device_result_configuration_descriptor_libusb_error_free(component_usb_device_result_configuration_descriptor_libusb_error_t *ptr) {
   if (!ptr->is_err) {
     component_usb_device_configuration_descriptor_free(&ptr->val.ok);
@@ -217,6 +609,25 @@ void component_usb_device_result_configuration_descriptor_libusb_error_free(comp
   }
 }
 
+void component_usb_device_result_list_u8_libusb_error_free(component_usb_device_result_list_u8_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+    cguest_list_u8_free(&ptr->val.ok);
+  } else {
+  }
+}
+
+void component_usb_device_result_device_metadata_libusb_error_free(component_usb_device_result_device_metadata_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+    component_usb_device_device_metadata_free(&ptr->val.ok);
+  } else {
+  }
+}
+
+void component_usb_device_option_own_usb_device_free(component_usb_device_option_own_usb_device_t *ptr) {
+  if (ptr->is_some) {
+  }
+}
+
 void component_usb_device_result_u8_libusb_error_free(component_usb_device_result_u8_libusb_error_t *ptr) {
   if (!ptr->is_err) {
   } else {
@@ -229,64 +640,924 @@ void component_usb_device_result_void_libusb_error_free(component_usb_device_res
   }
 }
 
+void component_usb_device_result_own_claimed_interface_libusb_error_free(component_usb_device_result_own_claimed_interface_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
+
 void component_usb_device_result_bool_libusb_error_free(component_usb_device_result_bool_libusb_error_t *ptr) {
   if (!ptr->is_err) {
   } else {
   }
 }
 
-void component_usb_device_result_own_transfer_libusb_error_free(component_usb_device_result_own_transfer_libusb_error_t *ptr) {
+void component_usb_device_result_string_libusb_error_free(component_usb_device_result_string_libusb_error_t *ptr) {
   if (!ptr->is_err) {
+    cguest_string_free(&ptr->val.ok);
   } else {
   }
 }
 
-void component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_free(component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t *ptr) {
+void cguest_list_u16_free(cguest_list_u16_t *ptr) {
   size_t list_len = ptr->len;
   if (list_len > 0) {
-    component_usb_device_tuple3_own_usb_device_device_descriptor_device_location_t *list_ptr = ptr->ptr;
+    uint16_t *list_ptr = ptr->ptr;
     for (size_t i = 0; i < list_len; i++) {
     }
     free(list_ptr);
   }
 }
 
-void component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_free(component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_t *ptr) {
+void component_usb_device_result_list_u16_libusb_error_free(component_usb_device_result_list_u16_libusb_error_t *ptr) {
   if (!ptr->is_err) {
-    component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_free(&ptr->val.ok);
+    cguest_list_u16_free(&ptr->val.ok);
   } else {
   }
 }
 
-void component_usb_usb_hotplug_result_void_libusb_error_free(component_usb_usb_hotplug_result_void_libusb_error_t *ptr) {
+void component_usb_device_result_option_string_libusb_error_free(component_usb_device_result_option_string_libusb_error_t *ptr) {
   if (!ptr->is_err) {
+    cguest_option_string_free(&ptr->val.ok);
   } else {
   }
 }
 
-void component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_free(component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_t *ptr) {
+void component_usb_device_result_bos_descriptor_libusb_error_free(component_usb_device_result_bos_descriptor_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+    component_usb_device_bos_descriptor_free(&ptr->val.ok);
+  } else {
+  }
+}
+
+void component_usb_device_result_own_transfer_libusb_error_free(component_usb_device_result_own_transfer_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
+
+void component_usb_device_list_own_endpoint_free(component_usb_device_list_own_endpoint_t *ptr) {
   size_t list_len = ptr->len;
   if (list_len > 0) {
-    component_usb_usb_hotplug_tuple3_event_info_own_usb_device_t *list_ptr = ptr->ptr;
+    component_usb_device_own_endpoint_t *list_ptr = ptr->ptr;
     for (size_t i = 0; i < list_len; i++) {
     }
     free(list_ptr);
   }
 }
 
-void exports_wasi_cli_run_result_void_void_free(exports_wasi_cli_run_result_void_void_t *ptr) {
+void component_usb_device_result_list_own_endpoint_libusb_error_free(component_usb_device_result_list_own_endpoint_libusb_error_t *ptr) {
   if (!ptr->is_err) {
+    component_usb_device_list_own_endpoint_free(&ptr->val.ok);
+  } else {
   }
 }
 
-// Component Adapters
+void component_usb_device_result_own_endpoint_libusb_error_free(component_usb_device_result_own_endpoint_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
 
-bool component_usb_transfers_method_transfer_submit_transfer(component_usb_transfers_borrow_transfer_t self, cguest_list_u8_t *data, component_usb_transfers_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
-  uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_transfers_method_transfer_submit_transfer((self).__handle, (uint8_t *) (*data).ptr, (*data).len, ptr);
-  component_usb_transfers_result_void_libusb_error_t result;
+void component_usb_device_option_device_filter_free(component_usb_device_option_device_filter_t *ptr) {
+  if (ptr->is_some) {
+    component_usb_device_device_filter_free(&ptr->val);
+  }
+}
+
+void component_usb_device_option_device_metadata_free(component_usb_device_option_device_metadata_t *ptr) {
+  if (ptr->is_some) {
+    component_usb_device_device_metadata_free(&ptr->val);
+  }
+}
+
+void component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ptr) {
+  component_usb_device_option_device_metadata_free(&ptr->f3);
+}
+
+void component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+      component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(&list_ptr[i]);
+    }
+    free(list_ptr);
+  }
+}
+
+void component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_free(component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+    component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(&ptr->val.ok);
+  } else {
+  }
+}
+
+void component_usb_usb_hotplug_result_void_libusb_error_free(component_usb_usb_hotplug_result_void_libusb_error_t *ptr) {
+  if (!ptr->is_err) {
+  } else {
+  }
+}
+
+void component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_free(component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_t *ptr) {
+  size_t list_len = ptr->len;
+  if (list_len > 0) {
+    component_usb_usb_hotplug_tuple3_event_info_own_usb_device_t *list_ptr = ptr->ptr;
+    for (size_t i = 0; i < list_len; i++) {
+    }
+    free(list_ptr);
+  }
+}
+
+void exports_wasi_cli_run_result_void_void_free(exports_wasi_cli_run_result_void_void_t *ptr) {
+  if (!ptr->is_err) {
+  }
+}
+
+void cguest_string_set(cguest_string_t *ret, const char*s) {
+  ret->ptr = (uint8_t*) s;
+  ret->len = strlen(s);
+}
+
+void cguest_string_dup(cguest_string_t *ret, const char*s) {
+  ret->len = strlen(s);
+  ret->ptr = (uint8_t*) cabi_realloc(NULL, 0, 1, ret->len * 1);
+  memcpy(ret->ptr, s, ret->len * 1);
+}
+
+void cguest_string_free(cguest_string_t *ret) {
+  if (ret->len > 0) {
+    free(ret->ptr);
+  }
+  ret->ptr = NULL;
+  ret->len = 0;
+}
+
+// Component Adapters
+
+bool component_usb_transfers_method_transfer_submit_transfer(component_usb_transfers_borrow_transfer_t self, cguest_list_u8_t *data, component_usb_transfers_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_transfers_method_transfer_submit_transfer((self).__handle, (uint8_t *) (*data).ptr, (*data).len, ptr);
+  component_usb_transfers_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_transfers_method_transfer_cancel_transfer(component_usb_transfers_borrow_transfer_t self, component_usb_transfers_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_transfers_method_transfer_cancel_transfer((self).__handle, ptr);
+  component_usb_transfers_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_transfers_await_transfer(component_usb_transfers_own_transfer_t xfer, cguest_list_u8_t *ret, component_usb_transfers_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_transfers_await_transfer((xfer).__handle, ptr);
+  component_usb_transfers_result_list_u8_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_transfers_submit_batch(component_usb_transfers_list_borrow_transfer_t *xfers, cguest_list_list_u8_t *data, component_usb_transfers_batch_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[12];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_transfers_submit_batch((uint8_t *) (*xfers).ptr, (*xfers).len, (uint8_t *) (*data).ptr, (*data).len, ptr);
+  component_usb_transfers_result_void_batch_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (component_usb_transfers_batch_error_t) {
+        (uint32_t) (uint32_t) (*((int32_t*) (ptr + 4))),
+        (component_usb_transfers_libusb_error_t) (int32_t) *((uint8_t*) (ptr + 8)),
+      };
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_transfers_await_any(component_usb_transfers_list_borrow_transfer_t *xfers, uint32_t timeout_ms, component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t *ret, component_usb_transfers_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(5*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_transfers_await_any((uint8_t *) (*xfers).ptr, (*xfers).len, (int32_t) (timeout_ms), ptr);
+  component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_t result0;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result0.is_err = false;
+      component_usb_transfers_result_list_u8_libusb_error_t result;
+      switch ((int32_t) *((uint8_t*) (ptr + (2*sizeof(void*))))) {
+        case 0: {
+          result.is_err = false;
+          result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + (3*sizeof(void*))))), (*((size_t*) (ptr + (4*sizeof(void*))))) };
+          break;
+        }
+        case 1: {
+          result.is_err = true;
+          result.val.err = (int32_t) *((uint8_t*) (ptr + (3*sizeof(void*))));
+          break;
+        }
+      }
+
+      result0.val.ok = (component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t) {
+        (uint32_t) (uint32_t) (*((int32_t*) (ptr + sizeof(void*)))),
+        (component_usb_transfers_result_list_u8_libusb_error_t) result,
+      };
+      break;
+    }
+    case 1: {
+      result0.is_err = true;
+      result0.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result0.is_err) {
+    *ret = result0.val.ok;
+    return 1;
+  } else {
+    *err = result0.val.err;
+    return 0;
+  }
+}
+
+bool wasi_io_poll_method_pollable_ready(wasi_io_poll_borrow_pollable_t self) {
+  int32_t ret = __wasm_import_wasi_io_poll_method_pollable_ready((self).__handle);
+  return ret;
+}
+
+void wasi_io_poll_method_pollable_block(wasi_io_poll_borrow_pollable_t self) {
+  __wasm_import_wasi_io_poll_method_pollable_block((self).__handle);
+}
+
+void wasi_io_poll_poll(wasi_io_poll_list_borrow_pollable_t *in, cguest_list_u32_t *ret) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(2*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_wasi_io_poll_poll((uint8_t *) (*in).ptr, (*in).len, ptr);
+  *ret = (cguest_list_u32_t) { (uint32_t*)(*((uint8_t **) (ptr + 0))), (*((size_t*) (ptr + sizeof(void*)))) };
+}
+
+bool component_usb_device_method_usb_device_open(component_usb_device_borrow_usb_device_t self, component_usb_device_own_device_handle_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_open((self).__handle, ptr);
+  component_usb_device_result_own_device_handle_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_device_own_device_handle_t) { *((int32_t*) (ptr + 4)) };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+uint64_t component_usb_device_method_usb_device_id(component_usb_device_borrow_usb_device_t self) {
+  int64_t ret = __wasm_import_component_usb_device_method_usb_device_id((self).__handle);
+  return (uint64_t) (ret);
+}
+
+bool component_usb_device_method_usb_device_get_device_descriptor(component_usb_device_borrow_usb_device_t self, component_usb_device_device_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(2)))
+  uint8_t ret_area[20];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_device_descriptor((self).__handle, ptr);
+  component_usb_device_result_device_descriptor_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_descriptors_device_descriptor_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 2))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 3))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + 4))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 6))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 7))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 8))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 9))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + 10))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + 12))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + 14))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 16))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 17))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 18))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 19))),
+      };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 2));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_usb_device_get_configuration_descriptor(component_usb_device_borrow_usb_device_t self, uint8_t config_index, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(7*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor((self).__handle, (int32_t) (config_index), ptr);
+  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
+        (component_usb_descriptors_list_usb_interface_t) (component_usb_descriptors_list_usb_interface_t) { (component_usb_descriptors_usb_interface_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
+        (cguest_list_u8_t) (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + (5*sizeof(void*))))), (*((size_t*) (ptr + (6*sizeof(void*))))) },
+      };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_usb_device_get_configuration_descriptor_by_value(component_usb_device_borrow_usb_device_t self, uint8_t config_value, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(7*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor_by_value((self).__handle, (int32_t) (config_value), ptr);
+  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
+        (component_usb_descriptors_list_usb_interface_t) (component_usb_descriptors_list_usb_interface_t) { (component_usb_descriptors_usb_interface_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
+        (cguest_list_u8_t) (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + (5*sizeof(void*))))), (*((size_t*) (ptr + (6*sizeof(void*))))) },
+      };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_usb_device_get_active_configuration_descriptor(component_usb_device_borrow_usb_device_t self, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(7*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_active_configuration_descriptor((self).__handle, ptr);
+  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
+        (component_usb_descriptors_list_usb_interface_t) (component_usb_descriptors_list_usb_interface_t) { (component_usb_descriptors_usb_interface_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
+        (cguest_list_u8_t) (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + (5*sizeof(void*))))), (*((size_t*) (ptr + (6*sizeof(void*))))) },
+      };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+void component_usb_device_method_usb_device_get_location(component_usb_device_borrow_usb_device_t self, component_usb_device_device_location_t *ret) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[4];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_location((self).__handle, ptr);
+  *ret = (component_usb_device_device_location_t) {
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 0))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 1))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 2))),
+    (component_usb_device_usb_speed_t) (int32_t) *((uint8_t*) (ptr + 3)),
+  };
+}
+
+component_usb_device_usb_speed_t component_usb_device_method_usb_device_get_speed(component_usb_device_borrow_usb_device_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_usb_device_get_speed((self).__handle);
+  return ret;
+}
+
+bool component_usb_device_method_usb_device_get_port_path(component_usb_device_borrow_usb_device_t self, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_port_path((self).__handle, ptr);
+  component_usb_device_result_list_u8_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_usb_device_get_metadata(component_usb_device_borrow_usb_device_t self, component_usb_device_device_metadata_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(12*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_metadata((self).__handle, ptr);
+  component_usb_device_result_device_metadata_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      cguest_option_string_t option;
+      switch ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))) {
+        case 0: {
+          option.is_some = false;
+          break;
+        }
+        case 1: {
+          option.is_some = true;
+          option.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) };
+          break;
+        }
+      }
+      cguest_option_string_t option0;
+      switch ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))) {
+        case 0: {
+          option0.is_some = false;
+          break;
+        }
+        case 1: {
+          option0.is_some = true;
+          option0.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (5*sizeof(void*))))), (*((size_t*) (ptr + (6*sizeof(void*))))) };
+          break;
+        }
+      }
+      cguest_option_string_t option1;
+      switch ((int32_t) *((uint8_t*) (ptr + (7*sizeof(void*))))) {
+        case 0: {
+          option1.is_some = false;
+          break;
+        }
+        case 1: {
+          option1.is_some = true;
+          option1.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (8*sizeof(void*))))), (*((size_t*) (ptr + (9*sizeof(void*))))) };
+          break;
+        }
+      }
+
+      result.val.ok = (component_usb_device_device_metadata_t) {
+        (cguest_option_string_t) option,
+        (cguest_option_string_t) option0,
+        (cguest_option_string_t) option1,
+        (component_usb_device_list_interface_metadata_t) (component_usb_device_list_interface_metadata_t) { (component_usb_device_interface_metadata_t*)(*((uint8_t **) (ptr + (10*sizeof(void*))))), (*((size_t*) (ptr + (11*sizeof(void*))))) },
+      };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_usb_device_get_parent(component_usb_device_borrow_usb_device_t self, component_usb_device_own_usb_device_t *ret) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_usb_device_get_parent((self).__handle, ptr);
+  component_usb_device_option_own_usb_device_t option;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      option.is_some = false;
+      break;
+    }
+    case 1: {
+      option.is_some = true;
+      option.val = (component_usb_device_own_usb_device_t) { *((int32_t*) (ptr + 4)) };
+      break;
+    }
+  }
+  *ret = option.val;
+  return option.is_some;
+}
+
+bool component_usb_device_method_device_handle_get_configuration(component_usb_device_borrow_device_handle_t self, uint8_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_get_configuration((self).__handle, ptr);
+  component_usb_device_result_u8_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (uint8_t) ((int32_t) *((uint8_t*) (ptr + 1)));
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_set_configuration(component_usb_device_borrow_device_handle_t self, component_usb_device_config_value_t *config, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  int32_t variant;
+  int32_t variant1;
+  switch ((int32_t) (*config).tag) {
+    case 0: {
+      variant = 0;
+      variant1 = 0;
+      break;
+    }
+    case 1: {
+      const uint8_t *payload0 = &(*config).val.value;
+      variant = 1;
+      variant1 = (int32_t) (*payload0);
+      break;
+    }
+  }
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_set_configuration((self).__handle, variant, variant1, ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_claim_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_own_claimed_interface_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_claim_interface((self).__handle, (int32_t) (ifac), ptr);
+  component_usb_device_result_own_claimed_interface_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (component_usb_device_own_claimed_interface_t) { *((int32_t*) (ptr + 4)) };
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_clear_halt(component_usb_device_borrow_device_handle_t self, uint8_t endpoint, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_clear_halt((self).__handle, (int32_t) (endpoint), ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_reset_device(component_usb_device_borrow_device_handle_t self, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_reset_device((self).__handle, ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_alloc_streams(component_usb_device_borrow_device_handle_t self, uint32_t num_streams, cguest_list_u8_t *endpoints, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_alloc_streams((self).__handle, (int32_t) (num_streams), (uint8_t *) (*endpoints).ptr, (*endpoints).len, ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_free_streams(component_usb_device_borrow_device_handle_t self, cguest_list_u8_t *endpoints, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_free_streams((self).__handle, (uint8_t *) (*endpoints).ptr, (*endpoints).len, ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_kernel_driver_active(component_usb_device_borrow_device_handle_t self, uint8_t ifac, bool *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_kernel_driver_active((self).__handle, (int32_t) (ifac), ptr);
+  component_usb_device_result_bool_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      result.val.ok = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_detach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_detach_kernel_driver((self).__handle, (int32_t) (ifac), ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_set_auto_detach_kernel_driver(component_usb_device_borrow_device_handle_t self, bool enable, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_set_auto_detach_kernel_driver((self).__handle, enable, ptr);
+  component_usb_device_result_void_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
+    case 0: {
+      result.is_err = false;
+      break;
+    }
+    case 1: {
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      break;
+    }
+  }
+  if (!result.is_err) {
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_attach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_attach_kernel_driver((self).__handle, (int32_t) (ifac), ptr);
+  component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
@@ -306,24 +1577,26 @@ bool component_usb_transfers_method_transfer_submit_transfer(component_usb_trans
   }
 }
 
-bool component_usb_transfers_method_transfer_cancel_transfer(component_usb_transfers_borrow_transfer_t self, component_usb_transfers_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_device_handle_get_string_descriptor(component_usb_device_borrow_device_handle_t self, uint8_t index, uint16_t langid, cguest_string_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_transfers_method_transfer_cancel_transfer((self).__handle, ptr);
-  component_usb_transfers_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_string_descriptor((self).__handle, (int32_t) (index), (int32_t) (langid), ptr);
+  component_usb_device_result_string_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -331,16 +1604,16 @@ bool component_usb_transfers_method_transfer_cancel_transfer(component_usb_trans
   }
 }
 
-bool component_usb_transfers_await_transfer(component_usb_transfers_own_transfer_t xfer, cguest_list_u8_t *ret, component_usb_transfers_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_get_supported_languages(component_usb_device_borrow_device_handle_t self, cguest_list_u16_t *ret, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(sizeof(void*))))
   uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_transfers_await_transfer((xfer).__handle, ptr);
-  component_usb_transfers_result_list_u8_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_supported_languages((self).__handle, ptr);
+  component_usb_device_result_list_u16_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
+      result.val.ok = (cguest_list_u16_t) { (uint16_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
@@ -358,21 +1631,34 @@ bool component_usb_transfers_await_transfer(component_usb_transfers_own_transfer
   }
 }
 
-bool component_usb_device_method_usb_device_open(component_usb_device_borrow_usb_device_t self, component_usb_device_own_device_handle_t *ret, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(4)))
-  uint8_t ret_area[8];
+bool component_usb_device_method_device_handle_get_manufacturer(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(4*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_usb_device_open((self).__handle, ptr);
-  component_usb_device_result_own_device_handle_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_manufacturer((self).__handle, ptr);
+  component_usb_device_result_option_string_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_device_own_device_handle_t) { *((int32_t*) (ptr + 4)) };
+      cguest_option_string_t option;
+      switch ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))) {
+        case 0: {
+          option.is_some = false;
+          break;
+        }
+        case 1: {
+          option.is_some = true;
+          option.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) };
+          break;
+        }
+      }
+
+      result.val.ok = option;
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
@@ -385,25 +1671,29 @@ bool component_usb_device_method_usb_device_open(component_usb_device_borrow_usb
   }
 }
 
-bool component_usb_device_method_usb_device_get_configuration_descriptor(component_usb_device_borrow_usb_device_t self, uint8_t config_index, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_get_product(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(sizeof(void*))))
-  uint8_t ret_area[(5*sizeof(void*))];
+  uint8_t ret_area[(4*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor((self).__handle, (int32_t) (config_index), ptr);
-  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_product((self).__handle, ptr);
+  component_usb_device_result_option_string_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
-        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
-        (component_usb_descriptors_list_interface_descriptor_t) (component_usb_descriptors_list_interface_descriptor_t) { (component_usb_descriptors_interface_descriptor_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
-      };
+      cguest_option_string_t option;
+      switch ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))) {
+        case 0: {
+          option.is_some = false;
+          break;
+        }
+        case 1: {
+          option.is_some = true;
+          option.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) };
+          break;
+        }
+      }
+
+      result.val.ok = option;
       break;
     }
     case 1: {
@@ -421,25 +1711,29 @@ bool component_usb_device_method_usb_device_get_configuration_descriptor(compone
   }
 }
 
-bool component_usb_device_method_usb_device_get_configuration_descriptor_by_value(component_usb_device_borrow_usb_device_t self, uint8_t config_value, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_get_serial_number(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(sizeof(void*))))
-  uint8_t ret_area[(5*sizeof(void*))];
+  uint8_t ret_area[(4*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_usb_device_get_configuration_descriptor_by_value((self).__handle, (int32_t) (config_value), ptr);
-  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_serial_number((self).__handle, ptr);
+  component_usb_device_result_option_string_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
-        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
-        (component_usb_descriptors_list_interface_descriptor_t) (component_usb_descriptors_list_interface_descriptor_t) { (component_usb_descriptors_interface_descriptor_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
-      };
+      cguest_option_string_t option;
+      switch ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))) {
+        case 0: {
+          option.is_some = false;
+          break;
+        }
+        case 1: {
+          option.is_some = true;
+          option.val = (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) };
+          break;
+        }
+      }
+
+      result.val.ok = option;
       break;
     }
     case 1: {
@@ -457,25 +1751,16 @@ bool component_usb_device_method_usb_device_get_configuration_descriptor_by_valu
   }
 }
 
-bool component_usb_device_method_usb_device_get_active_configuration_descriptor(component_usb_device_borrow_usb_device_t self, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_get_raw_configuration_descriptor(component_usb_device_borrow_device_handle_t self, uint8_t config_index, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(sizeof(void*))))
-  uint8_t ret_area[(5*sizeof(void*))];
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_usb_device_get_active_configuration_descriptor((self).__handle, ptr);
-  component_usb_device_result_configuration_descriptor_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_raw_configuration_descriptor((self).__handle, (int32_t) (config_index), ptr);
+  component_usb_device_result_list_u8_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_descriptors_configuration_descriptor_t) {
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
-        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
-        (component_usb_descriptors_list_interface_descriptor_t) (component_usb_descriptors_list_interface_descriptor_t) { (component_usb_descriptors_interface_descriptor_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (2+4*sizeof(void*))))),
-        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (3+4*sizeof(void*))))),
-      };
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
@@ -493,21 +1778,26 @@ bool component_usb_device_method_usb_device_get_active_configuration_descriptor(
   }
 }
 
-bool component_usb_device_method_device_handle_get_configuration(component_usb_device_borrow_device_handle_t self, uint8_t *ret, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_device_handle_get_bos_descriptor(component_usb_device_borrow_device_handle_t self, component_usb_device_bos_descriptor_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(4*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_get_configuration((self).__handle, ptr);
-  component_usb_device_result_u8_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_get_bos_descriptor((self).__handle, ptr);
+  component_usb_device_result_bos_descriptor_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (uint8_t) ((int32_t) *((uint8_t*) (ptr + 1)));
+      result.val.ok = (component_usb_descriptors_bos_descriptor_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + sizeof(void*)))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (1+1*sizeof(void*))))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (2+1*sizeof(void*))))),
+        (component_usb_descriptors_list_device_capability_t) (component_usb_descriptors_list_device_capability_t) { (component_usb_descriptors_device_capability_t*)(*((uint8_t **) (ptr + (2*sizeof(void*))))), (*((size_t*) (ptr + (3*sizeof(void*))))) },
+      };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
@@ -520,39 +1810,53 @@ bool component_usb_device_method_device_handle_get_configuration(component_usb_d
   }
 }
 
-bool component_usb_device_method_device_handle_set_configuration(component_usb_device_borrow_device_handle_t self, component_usb_device_config_value_t *config, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
-  int32_t variant;
-  int32_t variant1;
-  switch ((int32_t) (*config).tag) {
+bool component_usb_device_method_device_handle_new_control_transfer(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, uint32_t buf_size, uint32_t timeout_ms, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_device_handle_new_control_transfer((self).__handle, (int32_t) ((*setup).bm_request_type), (int32_t) ((*setup).b_request), (int32_t) ((*setup).w_value), (int32_t) ((*setup).w_index), (int32_t) (buf_size), (int32_t) (timeout_ms), ptr);
+  component_usb_device_result_own_transfer_libusb_error_t result;
+  switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
-      variant = 0;
-      variant1 = 0;
+      result.is_err = false;
+      result.val.ok = (component_usb_device_own_transfer_t) { *((int32_t*) (ptr + 4)) };
       break;
     }
     case 1: {
-      const uint8_t *payload0 = &(*config).val.value;
-      variant = 1;
-      variant1 = (int32_t) (*payload0);
+      result.is_err = true;
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
       break;
     }
   }
+  if (!result.is_err) {
+    *ret = result.val.ok;
+    return 1;
+  } else {
+    *err = result.val.err;
+    return 0;
+  }
+}
+
+bool component_usb_device_method_device_handle_control_in(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, uint16_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_set_configuration((self).__handle, variant, variant1, ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_control_in((self).__handle, (int32_t) ((*setup).bm_request_type), (int32_t) ((*setup).b_request), (int32_t) ((*setup).w_value), (int32_t) ((*setup).w_index), (int32_t) (length), (int32_t) (timeout_ms), ptr);
+  component_usb_device_result_list_u8_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -560,11 +1864,11 @@ bool component_usb_device_method_device_handle_set_configuration(component_usb_d
   }
 }
 
-bool component_usb_device_method_device_handle_claim_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_control_out(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_claim_interface((self).__handle, (int32_t) (ifac), ptr);
+  __wasm_import_component_usb_device_method_device_handle_control_out((self).__handle, (int32_t) ((*setup).bm_request_type), (int32_t) ((*setup).b_request), (int32_t) ((*setup).w_value), (int32_t) ((*setup).w_index), (uint8_t *) (*data).ptr, (*data).len, (int32_t) (timeout_ms), ptr);
   component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
@@ -585,24 +1889,26 @@ bool component_usb_device_method_device_handle_claim_interface(component_usb_dev
   }
 }
 
-bool component_usb_device_method_device_handle_release_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_device_handle_bulk_read(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, uint32_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_release_interface((self).__handle, (int32_t) (ifac), ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_bulk_read((self).__handle, (ep).__handle, (int32_t) (length), (int32_t) (timeout_ms), ptr);
+  component_usb_device_result_list_u8_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -610,11 +1916,11 @@ bool component_usb_device_method_device_handle_release_interface(component_usb_d
   }
 }
 
-bool component_usb_device_method_device_handle_set_interface_altsetting(component_usb_device_borrow_device_handle_t self, uint8_t ifac, uint8_t alt_setting, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_bulk_write(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_set_interface_altsetting((self).__handle, (int32_t) (ifac), (int32_t) (alt_setting), ptr);
+  __wasm_import_component_usb_device_method_device_handle_bulk_write((self).__handle, (ep).__handle, (uint8_t *) (*data).ptr, (*data).len, (int32_t) (timeout_ms), ptr);
   component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
@@ -635,24 +1941,26 @@ bool component_usb_device_method_device_handle_set_interface_altsetting(componen
   }
 }
 
-bool component_usb_device_method_device_handle_clear_halt(component_usb_device_borrow_device_handle_t self, uint8_t endpoint, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_device_handle_interrupt_read(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, uint32_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_clear_halt((self).__handle, (int32_t) (endpoint), ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_device_handle_interrupt_read((self).__handle, (ep).__handle, (int32_t) (length), (int32_t) (timeout_ms), ptr);
+  component_usb_device_result_list_u8_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -660,11 +1968,11 @@ bool component_usb_device_method_device_handle_clear_halt(component_usb_device_b
   }
 }
 
-bool component_usb_device_method_device_handle_reset_device(component_usb_device_borrow_device_handle_t self, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_device_handle_interrupt_write(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_reset_device((self).__handle, ptr);
+  __wasm_import_component_usb_device_method_device_handle_interrupt_write((self).__handle, (ep).__handle, (uint8_t *) (*data).ptr, (*data).len, (int32_t) (timeout_ms), ptr);
   component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
@@ -685,11 +1993,35 @@ bool component_usb_device_method_device_handle_reset_device(component_usb_device
   }
 }
 
-bool component_usb_device_method_device_handle_alloc_streams(component_usb_device_borrow_device_handle_t self, uint32_t num_streams, cguest_list_u8_t *endpoints, component_usb_device_libusb_error_t *err) {
+uint64_t component_usb_device_method_device_handle_device_id(component_usb_device_borrow_device_handle_t self) {
+  int64_t ret = __wasm_import_component_usb_device_method_device_handle_device_id((self).__handle);
+  return (uint64_t) (ret);
+}
+
+component_usb_device_own_pollable_t component_usb_device_method_device_handle_on_disconnect(component_usb_device_borrow_device_handle_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_device_handle_on_disconnect((self).__handle);
+  return (component_usb_device_own_pollable_t) { ret };
+}
+
+void component_usb_device_method_device_handle_close(component_usb_device_borrow_device_handle_t self) {
+  __wasm_import_component_usb_device_method_device_handle_close((self).__handle);
+}
+
+uint8_t component_usb_device_method_claimed_interface_interface_number(component_usb_device_borrow_claimed_interface_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_claimed_interface_interface_number((self).__handle);
+  return (uint8_t) (ret);
+}
+
+uint8_t component_usb_device_method_claimed_interface_alt_setting(component_usb_device_borrow_claimed_interface_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_claimed_interface_alt_setting((self).__handle);
+  return (uint8_t) (ret);
+}
+
+bool component_usb_device_method_claimed_interface_set_alt_setting(component_usb_device_borrow_claimed_interface_t self, uint8_t alt_setting, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_alloc_streams((self).__handle, (int32_t) (num_streams), (uint8_t *) (*endpoints).ptr, (*endpoints).len, ptr);
+  __wasm_import_component_usb_device_method_claimed_interface_set_alt_setting((self).__handle, (int32_t) (alt_setting), ptr);
   component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
@@ -710,24 +2042,26 @@ bool component_usb_device_method_device_handle_alloc_streams(component_usb_devic
   }
 }
 
-bool component_usb_device_method_device_handle_free_streams(component_usb_device_borrow_device_handle_t self, cguest_list_u8_t *endpoints, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_claimed_interface_endpoints(component_usb_device_borrow_claimed_interface_t self, component_usb_device_list_own_endpoint_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_free_streams((self).__handle, (uint8_t *) (*endpoints).ptr, (*endpoints).len, ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_claimed_interface_endpoints((self).__handle, ptr);
+  component_usb_device_result_list_own_endpoint_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (component_usb_device_list_own_endpoint_t) { (component_usb_device_own_endpoint_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -735,21 +2069,21 @@ bool component_usb_device_method_device_handle_free_streams(component_usb_device
   }
 }
 
-bool component_usb_device_method_device_handle_kernel_driver_active(component_usb_device_borrow_device_handle_t self, uint8_t ifac, bool *ret, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_method_claimed_interface_endpoint(component_usb_device_borrow_claimed_interface_t self, uint8_t address, component_usb_device_own_endpoint_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_kernel_driver_active((self).__handle, (int32_t) (ifac), ptr);
-  component_usb_device_result_bool_libusb_error_t result;
+  __wasm_import_component_usb_device_method_claimed_interface_endpoint((self).__handle, (int32_t) (address), ptr);
+  component_usb_device_result_own_endpoint_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.ok = (component_usb_device_own_endpoint_t) { *((int32_t*) (ptr + 4)) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
       break;
     }
   }
@@ -762,24 +2096,84 @@ bool component_usb_device_method_device_handle_kernel_driver_active(component_us
   }
 }
 
-bool component_usb_device_method_device_handle_detach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+uint8_t component_usb_device_method_endpoint_address(component_usb_device_borrow_endpoint_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_endpoint_address((self).__handle);
+  return (uint8_t) (ret);
+}
+
+component_usb_device_direction_t component_usb_device_method_endpoint_direction(component_usb_device_borrow_endpoint_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_endpoint_direction((self).__handle);
+  return ret;
+}
+
+component_usb_device_transfer_type_t component_usb_device_method_endpoint_transfer_type(component_usb_device_borrow_endpoint_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_endpoint_transfer_type((self).__handle);
+  return ret;
+}
+
+uint16_t component_usb_device_method_endpoint_max_packet_size(component_usb_device_borrow_endpoint_t self) {
+  int32_t ret = __wasm_import_component_usb_device_method_endpoint_max_packet_size((self).__handle);
+  return (uint16_t) (ret);
+}
+
+void component_usb_device_method_endpoint_descriptor(component_usb_device_borrow_endpoint_t self, component_usb_device_endpoint_descriptor_t *ret) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(24+3*sizeof(void*))];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_detach_kernel_driver((self).__handle, (int32_t) (ifac), ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_method_endpoint_descriptor((self).__handle, ptr);
+  component_usb_descriptors_option_ss_endpoint_companion_t option;
+  switch ((int32_t) *((uint8_t*) (ptr + (8+3*sizeof(void*))))) {
+    case 0: {
+      option.is_some = false;
+      break;
+    }
+    case 1: {
+      option.is_some = true;
+      option.val = (component_usb_descriptors_ss_endpoint_companion_t) {
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (12+3*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (13+3*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (14+3*sizeof(void*))))),
+        (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + (15+3*sizeof(void*))))),
+        (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + (16+3*sizeof(void*))))),
+        (uint32_t) (uint32_t) (*((int32_t*) (ptr + (20+3*sizeof(void*))))),
+      };
+      break;
+    }
+  }
+  *ret = (component_usb_descriptors_endpoint_descriptor_t) {
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 0))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 1))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 2))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 3))),
+    (uint16_t) (uint16_t) ((int32_t) *((uint16_t*) (ptr + 4))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 6))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 7))),
+    (uint8_t) (uint8_t) ((int32_t) *((uint8_t*) (ptr + 8))),
+    (cguest_list_u8_t) (cguest_list_u8_t) { (uint8_t*)(*((uint8_t **) (ptr + (8+1*sizeof(void*))))), (*((size_t*) (ptr + (8+2*sizeof(void*))))) },
+    (component_usb_descriptors_option_ss_endpoint_companion_t) option,
+  };
+}
+
+bool component_usb_device_method_endpoint_new_transfer(component_usb_device_borrow_endpoint_t self, uint32_t buf_size, component_usb_device_transfer_options_t *opts, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_method_endpoint_new_transfer((self).__handle, (int32_t) (buf_size), (int32_t) ((*opts).timeout_ms), (int32_t) ((*opts).stream_id), (int32_t) ((*opts).iso_packets), ptr);
+  component_usb_device_result_own_transfer_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (component_usb_device_own_transfer_t) { *((int32_t*) (ptr + 4)) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -787,11 +2181,11 @@ bool component_usb_device_method_device_handle_detach_kernel_driver(component_us
   }
 }
 
-bool component_usb_device_method_device_handle_attach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err) {
+bool component_usb_device_method_endpoint_clear_halt(component_usb_device_borrow_endpoint_t self, component_usb_device_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_attach_kernel_driver((self).__handle, (int32_t) (ifac), ptr);
+  __wasm_import_component_usb_device_method_endpoint_clear_halt((self).__handle, ptr);
   component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
@@ -812,26 +2206,24 @@ bool component_usb_device_method_device_handle_attach_kernel_driver(component_us
   }
 }
 
-bool component_usb_device_method_device_handle_new_transfer(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_type_t xfer_type, component_usb_device_transfer_setup_t *setup, uint32_t buf_size, component_usb_device_transfer_options_t *opts, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(4)))
-  uint8_t ret_area[8];
+bool component_usb_device_init(component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(1)))
+  uint8_t ret_area[2];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_method_device_handle_new_transfer((self).__handle, (int32_t) xfer_type, (int32_t) ((*setup).bm_request_type), (int32_t) ((*setup).b_request), (int32_t) ((*setup).w_value), (int32_t) ((*setup).w_index), (int32_t) (buf_size), (int32_t) ((*opts).endpoint), (int32_t) ((*opts).timeout_ms), (int32_t) ((*opts).stream_id), (int32_t) ((*opts).iso_packets), ptr);
-  component_usb_device_result_own_transfer_libusb_error_t result;
+  __wasm_import_component_usb_device_init(ptr);
+  component_usb_device_result_void_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_device_own_transfer_t) { *((int32_t*) (ptr + 4)) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
       break;
     }
   }
   if (!result.is_err) {
-    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -839,28 +2231,145 @@ bool component_usb_device_method_device_handle_new_transfer(component_usb_device
   }
 }
 
-void component_usb_device_method_device_handle_close(component_usb_device_borrow_device_handle_t self) {
-  __wasm_import_component_usb_device_method_device_handle_close((self).__handle);
-}
-
-bool component_usb_device_init(component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(1)))
-  uint8_t ret_area[2];
+bool component_usb_device_list_devices(component_usb_device_device_filter_t *maybe_filter, component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(3*sizeof(void*))];
+  component_usb_device_option_device_filter_t filter;
+  filter.is_some = maybe_filter != NULL;if (maybe_filter) {
+    filter.val = *maybe_filter;
+  }
+  int32_t option26;
+  int32_t option27;
+  int32_t option28;
+  int32_t option29;
+  int32_t option30;
+  int32_t option31;
+  int32_t option32;
+  int32_t option33;
+  int32_t option34;
+  int32_t option35;
+  uint8_t * option36;
+  size_t option37;
+  int32_t option38;
+  uint8_t * option39;
+  size_t option40;
+  if ((filter).is_some) {
+    const component_usb_device_device_filter_t *payload0 = &(filter).val;
+    int32_t option;
+    int32_t option3;
+    if (((*payload0).vendor_id).is_some) {
+      const uint16_t *payload2 = &((*payload0).vendor_id).val;
+      option = 1;
+      option3 = (int32_t) (*payload2);
+    } else {
+      option = 0;
+      option3 = 0;
+    }
+    int32_t option6;
+    int32_t option7;
+    if (((*payload0).product_id).is_some) {
+      const uint16_t *payload5 = &((*payload0).product_id).val;
+      option6 = 1;
+      option7 = (int32_t) (*payload5);
+    } else {
+      option6 = 0;
+      option7 = 0;
+    }
+    int32_t option10;
+    int32_t option11;
+    if (((*payload0).class_).is_some) {
+      const uint8_t *payload9 = &((*payload0).class_).val;
+      option10 = 1;
+      option11 = (int32_t) (*payload9);
+    } else {
+      option10 = 0;
+      option11 = 0;
+    }
+    int32_t option14;
+    int32_t option15;
+    if (((*payload0).bus).is_some) {
+      const uint8_t *payload13 = &((*payload0).bus).val;
+      option14 = 1;
+      option15 = (int32_t) (*payload13);
+    } else {
+      option14 = 0;
+      option15 = 0;
+    }
+    int32_t option18;
+    uint8_t * option19;
+    size_t option20;
+    if (((*payload0).port_path).is_some) {
+      const cguest_list_u8_t *payload17 = &((*payload0).port_path).val;
+      option18 = 1;
+      option19 = (uint8_t *) (*payload17).ptr;
+      option20 = (*payload17).len;
+    } else {
+      option18 = 0;
+      option19 = 0;
+      option20 = 0;
+    }
+    int32_t option23;
+    uint8_t * option24;
+    size_t option25;
+    if (((*payload0).serial_number).is_some) {
+      const cguest_string_t *payload22 = &((*payload0).serial_number).val;
+      option23 = 1;
+      option24 = (uint8_t *) (*payload22).ptr;
+      option25 = (*payload22).len;
+    } else {
+      option23 = 0;
+      option24 = 0;
+      option25 = 0;
+    }
+    option26 = 1;
+    option27 = option;
+    option28 = option3;
+    option29 = option6;
+    option30 = option7;
+    option31 = option10;
+    option32 = option11;
+    option33 = option14;
+    option34 = option15;
+    option35 = option18;
+    option36 = option19;
+    option37 = option20;
+    option38 = option23;
+    option39 = option24;
+    option40 = option25;
+  } else {
+    option26 = 0;
+    option27 = 0;
+    option28 = 0;
+    option29 = 0;
+    option30 = 0;
+    option31 = 0;
+    option32 = 0;
+    option33 = 0;
+    option34 = 0;
+    option35 = 0;
+    option36 = 0;
+    option37 = 0;
+    option38 = 0;
+    option39 = 0;
+    option40 = 0;
+  }
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_init(ptr);
-  component_usb_device_result_void_libusb_error_t result;
+  __wasm_import_component_usb_device_list_devices(option26, option27, option28, option29, option30, option31, option32, option33, option34, option35, option36, option37, option38, option39, option40, ptr);
+  component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
+      result.val.ok = (component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t) { (component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + 1));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
       break;
     }
   }
   if (!result.is_err) {
+    *ret = result.val.ok;
     return 1;
   } else {
     *err = result.val.err;
@@ -868,21 +2377,21 @@ bool component_usb_device_init(component_usb_device_libusb_error_t *err) {
   }
 }
 
-bool component_usb_device_list_devices(component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t *ret, component_usb_device_libusb_error_t *err) {
-  __attribute__((__aligned__(sizeof(void*))))
-  uint8_t ret_area[(3*sizeof(void*))];
+bool component_usb_device_open_device_with_vid_pid(uint16_t vendor_id, uint16_t product_id, component_usb_device_own_device_handle_t *ret, component_usb_device_libusb_error_t *err) {
+  __attribute__((__aligned__(4)))
+  uint8_t ret_area[8];
   uint8_t *ptr = (uint8_t *) &ret_area;
-  __wasm_import_component_usb_device_list_devices(ptr);
-  component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_t result;
+  __wasm_import_component_usb_device_open_device_with_vid_pid((int32_t) (vendor_id), (int32_t) (product_id), ptr);
+  component_usb_device_result_own_device_handle_libusb_error_t result;
   switch ((int32_t) *((uint8_t*) (ptr + 0))) {
     case 0: {
       result.is_err = false;
-      result.val.ok = (component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t) { (component_usb_device_tuple3_own_usb_device_device_descriptor_device_location_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) };
+      result.val.ok = (component_usb_device_own_device_handle_t) { *((int32_t*) (ptr + 4)) };
       break;
     }
     case 1: {
       result.is_err = true;
-      result.val.err = (int32_t) *((uint8_t*) (ptr + sizeof(void*)));
+      result.val.err = (int32_t) *((uint8_t*) (ptr + 4));
       break;
     }
   }
@@ -895,6 +2404,20 @@ bool component_usb_device_list_devices(component_usb_device_list_tuple3_own_usb_
   }
 }
 
+void component_usb_device_capabilities(component_usb_device_backend_capabilities_t *ret) {
+  __attribute__((__aligned__(sizeof(void*))))
+  uint8_t ret_area[(5*sizeof(void*))];
+  uint8_t *ptr = (uint8_t *) &ret_area;
+  __wasm_import_component_usb_device_capabilities(ptr);
+  *ret = (component_usb_device_backend_capabilities_t) {
+    (bool) (int32_t) *((uint8_t*) (ptr + 0)),
+    (bool) (int32_t) *((uint8_t*) (ptr + 1)),
+    (bool) (int32_t) *((uint8_t*) (ptr + 2)),
+    (cguest_string_t) (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + sizeof(void*)))), (*((size_t*) (ptr + (2*sizeof(void*))))) },
+    (cguest_string_t) (cguest_string_t) { (uint8_t*)(*((uint8_t **) (ptr + (3*sizeof(void*))))), (*((size_t*) (ptr + (4*sizeof(void*))))) },
+  };
+}
+
 bool component_usb_usb_hotplug_enable_hotplug(component_usb_usb_hotplug_libusb_error_t *err) {
   __attribute__((__aligned__(1)))
   uint8_t ret_area[2];
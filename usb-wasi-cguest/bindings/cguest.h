@@ -9,6 +9,11 @@ extern "C" {
 #include <stdbool.h>
 #include <stddef.h>
 
+typedef struct cguest_string_t {
+  uint8_t*ptr;
+  size_t len;
+} cguest_string_t;
+
 // Libusb error codes (negative values indicate errors)
 typedef uint8_t component_usb_errors_libusb_error_t;
 
@@ -64,8 +69,6 @@ typedef struct component_usb_transfers_transfer_setup_t {
 
 // USB transfer options
 typedef struct component_usb_transfers_transfer_options_t {
-  uint8_t   endpoint;
-  // Endpoint address (number + direction)
   uint32_t   timeout_ms;
   // Timeout in milliseconds
   uint32_t   stream_id;
@@ -81,6 +84,12 @@ typedef struct component_usb_transfers_borrow_transfer_t {
   int32_t __handle;
 } component_usb_transfers_borrow_transfer_t;
 
+// Why `submit-batch` stopped: the transfer at `index` failed to submit with `error`.
+typedef struct component_usb_transfers_batch_error_t {
+  uint32_t   index;
+  component_usb_transfers_libusb_error_t   error;
+} component_usb_transfers_batch_error_t;
+
 typedef struct {
   uint8_t *ptr;
   size_t len;
@@ -101,6 +110,36 @@ typedef struct {
   } val;
 } component_usb_transfers_result_list_u8_libusb_error_t;
 
+typedef struct {
+  component_usb_transfers_borrow_transfer_t *ptr;
+  size_t len;
+} component_usb_transfers_list_borrow_transfer_t;
+
+typedef struct {
+  cguest_list_u8_t *ptr;
+  size_t len;
+} cguest_list_list_u8_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_transfers_batch_error_t err;
+  } val;
+} component_usb_transfers_result_void_batch_error_t;
+
+typedef struct {
+  uint32_t f0;
+  component_usb_transfers_result_list_u8_libusb_error_t f1;
+} component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t ok;
+    component_usb_transfers_libusb_error_t err;
+  } val;
+} component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_t;
+
 // ConfigValue is used to specify a configuration or an unconfigured state.
 typedef struct component_usb_configuration_config_value_t {
   uint8_t tag;
@@ -146,6 +185,26 @@ typedef struct component_usb_descriptors_device_descriptor_t {
   uint8_t   num_configurations;
 } component_usb_descriptors_device_descriptor_t;
 
+// SuperSpeed Endpoint Companion Descriptor (6 bytes)
+typedef struct component_usb_descriptors_ss_endpoint_companion_t {
+  uint8_t   length;
+  // Size of this descriptor in bytes (should be 6)
+  uint8_t   descriptor_type;
+  // SS_ENDPOINT_COMPANION descriptor type (0x30)
+  uint8_t   max_burst;
+  // Packets the endpoint can send or receive in a burst, minus one
+  uint8_t   attributes;
+  // Bulk: MaxStreams in bits 0-4, isochronous: Mult in bits 0-1
+  uint16_t   bytes_per_interval;
+  // (Periodic) Bytes transferred per service interval
+  uint32_t   max_streams;
+} component_usb_descriptors_ss_endpoint_companion_t;
+
+typedef struct {
+  bool is_some;
+  component_usb_descriptors_ss_endpoint_companion_t val;
+} component_usb_descriptors_option_ss_endpoint_companion_t;
+
 // USB Endpoint Descriptor (7+ bytes)
 typedef struct component_usb_descriptors_endpoint_descriptor_t {
   uint8_t   length;
@@ -163,6 +222,10 @@ typedef struct component_usb_descriptors_endpoint_descriptor_t {
   uint8_t   refresh;
   // (Isochronous) Data rate refresh interval
   uint8_t   synch_address;
+  // (Isochronous) Sync endpoint address
+  cguest_list_u8_t   extra;
+  // Descriptors following the endpoint, e.g. the SuperSpeed endpoint companion
+  component_usb_descriptors_option_ss_endpoint_companion_t   ss_endpoint_companion;
 } component_usb_descriptors_endpoint_descriptor_t;
 
 typedef struct {
@@ -189,6 +252,8 @@ typedef struct component_usb_descriptors_interface_descriptor_t {
   uint8_t   interface_protocol;
   // Protocol code
   uint8_t   interface_index;
+  // Index of string descriptor describing this interface
+  cguest_list_u8_t   extra;
 } component_usb_descriptors_interface_descriptor_t;
 
 typedef struct {
@@ -196,6 +261,18 @@ typedef struct {
   size_t len;
 } component_usb_descriptors_list_interface_descriptor_t;
 
+// An interface of a configuration with all of its alternate settings
+typedef struct component_usb_descriptors_usb_interface_t {
+  uint8_t   interface_number;
+  // Number of this interface
+  component_usb_descriptors_list_interface_descriptor_t   alt_settings;
+} component_usb_descriptors_usb_interface_t;
+
+typedef struct {
+  component_usb_descriptors_usb_interface_t *ptr;
+  size_t len;
+} component_usb_descriptors_list_usb_interface_t;
+
 // USB Configuration Descriptor (9 bytes)
 typedef struct component_usb_descriptors_configuration_descriptor_t {
   uint8_t   length;
@@ -204,7 +281,7 @@ typedef struct component_usb_descriptors_configuration_descriptor_t {
   // CONFIGURATION descriptor type (2)
   uint16_t   total_length;
   // Total length of data including all sub-descriptors
-  component_usb_descriptors_list_interface_descriptor_t   interfaces;
+  component_usb_descriptors_list_usb_interface_t   interfaces;
   // List of interfaces in this configuration
   uint8_t   configuration_value;
   // Value to use for SetConfiguration
@@ -213,8 +290,147 @@ typedef struct component_usb_descriptors_configuration_descriptor_t {
   uint8_t   attributes;
   // Bitmap: self/bus powered, remote wakeup
   uint8_t   max_power;
+  // Max power consumption (in 2mA units)
+  cguest_list_u8_t   extra;
 } component_usb_descriptors_configuration_descriptor_t;
 
+// USB 2.0 Extension capability
+typedef struct component_usb_descriptors_usb2_extension_capability_t {
+  uint32_t   attributes;
+  // Bitmap: bit 1 LPM, bits 2-3 BESL support
+  bool   lpm_supported;
+} component_usb_descriptors_usb2_extension_capability_t;
+
+// SuperSpeed USB Device capability
+typedef struct component_usb_descriptors_superspeed_capability_t {
+  uint8_t   attributes;
+  // Bitmap: bit 1 latency tolerance messages
+  uint16_t   speeds_supported;
+  // Bitmap: low, full, high, 5 Gbps
+  uint8_t   functionality_support;
+  // Lowest speed at which all functionality is available
+  uint8_t   u1_exit_latency;
+  // U1 device exit latency in microseconds
+  uint16_t   u2_exit_latency;
+} component_usb_descriptors_superspeed_capability_t;
+
+typedef struct {
+  uint32_t *ptr;
+  size_t len;
+} cguest_list_u32_t;
+
+// SuperSpeedPlus USB capability
+typedef struct component_usb_descriptors_superspeed_plus_capability_t {
+  uint32_t   attributes;
+  // Bitmap: sublink speed attribute and ID counts
+  uint16_t   functionality_support;
+  // Minimum sublink speed attribute ID and lane counts
+  cguest_list_u32_t   sublink_speed_attributes;
+} component_usb_descriptors_superspeed_plus_capability_t;
+
+// WebUSB platform capability
+typedef struct component_usb_descriptors_webusb_platform_t {
+  uint16_t   version_bcd;
+  // WebUSB version (bcdVersion)
+  uint8_t   vendor_code;
+  // bRequest for WebUSB requests
+  uint8_t   landing_page_index;
+} component_usb_descriptors_webusb_platform_t;
+
+// Microsoft OS 2.0 descriptor set information
+typedef struct component_usb_descriptors_ms_os20_descriptor_set_t {
+  uint32_t   windows_version;
+  // Minimum Windows version the set applies to
+  uint16_t   total_length;
+  // Length of the descriptor set
+  uint8_t   vendor_code;
+  // bRequest to retrieve the descriptor set
+  uint8_t   alt_enum_code;
+} component_usb_descriptors_ms_os20_descriptor_set_t;
+
+typedef struct {
+  component_usb_descriptors_ms_os20_descriptor_set_t *ptr;
+  size_t len;
+} component_usb_descriptors_list_ms_os20_descriptor_set_t;
+
+typedef struct component_usb_descriptors_platform_kind_t {
+  uint8_t tag;
+  union {
+    component_usb_descriptors_webusb_platform_t     webusb;
+    component_usb_descriptors_list_ms_os20_descriptor_set_t     ms_os20;
+  } val;
+} component_usb_descriptors_platform_kind_t;
+
+#define COMPONENT_USB_DESCRIPTORS_PLATFORM_KIND_WEBUSB 0
+#define COMPONENT_USB_DESCRIPTORS_PLATFORM_KIND_MS_OS20 1
+#define COMPONENT_USB_DESCRIPTORS_PLATFORM_KIND_UNKNOWN 2
+
+// Platform capability, identified by its UUID
+typedef struct component_usb_descriptors_platform_capability_t {
+  cguest_list_u8_t   uuid;
+  // 16-byte PlatformCapabilityUUID as sent by the device
+  cguest_list_u8_t   data;
+  // CapabilityData following the UUID
+  component_usb_descriptors_platform_kind_t   kind;
+} component_usb_descriptors_platform_capability_t;
+
+// A device capability the host does not decode
+typedef struct component_usb_descriptors_unknown_capability_t {
+  uint8_t   capability_type;
+  // bDevCapabilityType
+  cguest_list_u8_t   data;
+} component_usb_descriptors_unknown_capability_t;
+
+// A device capability of the BOS descriptor
+typedef struct component_usb_descriptors_device_capability_t {
+  uint8_t tag;
+  union {
+    component_usb_descriptors_usb2_extension_capability_t     usb2_extension;
+    component_usb_descriptors_superspeed_capability_t     superspeed;
+    component_usb_descriptors_superspeed_plus_capability_t     superspeed_plus;
+    cguest_list_u8_t     container_id;
+    component_usb_descriptors_platform_capability_t     platform;
+    component_usb_descriptors_unknown_capability_t     other;
+  } val;
+} component_usb_descriptors_device_capability_t;
+
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_USB2_EXTENSION 0
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_SUPERSPEED 1
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_SUPERSPEED_PLUS 2
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_CONTAINER_ID 3
+// 16-byte UUID identifying the device across all the speeds it connects at
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_PLATFORM 4
+#define COMPONENT_USB_DESCRIPTORS_DEVICE_CAPABILITY_OTHER 5
+
+typedef struct {
+  component_usb_descriptors_device_capability_t *ptr;
+  size_t len;
+} component_usb_descriptors_list_device_capability_t;
+
+// Binary Device Object Store descriptor, describing the device capabilities of USB 2.1+ devices
+typedef struct component_usb_descriptors_bos_descriptor_t {
+  uint8_t   length;
+  // Size of this descriptor in bytes (should be 5)
+  uint8_t   descriptor_type;
+  // BOS descriptor type (0x0f)
+  uint16_t   total_length;
+  // Total length of data including all capabilities
+  component_usb_descriptors_list_device_capability_t   capabilities;
+} component_usb_descriptors_bos_descriptor_t;
+
+typedef struct wasi_io_poll_own_pollable_t {
+  int32_t __handle;
+} wasi_io_poll_own_pollable_t;
+
+typedef struct wasi_io_poll_borrow_pollable_t {
+  int32_t __handle;
+} wasi_io_poll_borrow_pollable_t;
+
+typedef struct {
+  wasi_io_poll_borrow_pollable_t *ptr;
+  size_t len;
+} wasi_io_poll_list_borrow_pollable_t;
+
 typedef component_usb_errors_libusb_error_t component_usb_device_libusb_error_t;
 
 typedef component_usb_configuration_config_value_t component_usb_device_config_value_t;
@@ -227,6 +443,8 @@ typedef component_usb_descriptors_interface_descriptor_t component_usb_device_in
 
 typedef component_usb_descriptors_endpoint_descriptor_t component_usb_device_endpoint_descriptor_t;
 
+typedef component_usb_descriptors_bos_descriptor_t component_usb_device_bos_descriptor_t;
+
 typedef component_usb_transfers_transfer_type_t component_usb_device_transfer_type_t;
 
 typedef component_usb_transfers_transfer_setup_t component_usb_device_transfer_setup_t;
@@ -249,6 +467,93 @@ typedef struct component_usb_device_borrow_device_handle_t {
   int32_t __handle;
 } component_usb_device_borrow_device_handle_t;
 
+typedef struct component_usb_device_own_claimed_interface_t {
+  int32_t __handle;
+} component_usb_device_own_claimed_interface_t;
+
+typedef struct component_usb_device_borrow_claimed_interface_t {
+  int32_t __handle;
+} component_usb_device_borrow_claimed_interface_t;
+
+// Direction of an endpoint, as seen from the host.
+typedef uint8_t component_usb_device_direction_t;
+
+#define COMPONENT_USB_DEVICE_DIRECTION_IN 0
+#define COMPONENT_USB_DEVICE_DIRECTION_OUT 1
+
+typedef struct component_usb_device_own_endpoint_t {
+  int32_t __handle;
+} component_usb_device_own_endpoint_t;
+
+typedef struct component_usb_device_borrow_endpoint_t {
+  int32_t __handle;
+} component_usb_device_borrow_endpoint_t;
+
+typedef struct {
+  bool is_some;
+  cguest_string_t val;
+} cguest_option_string_t;
+
+// An interface of the active configuration as sysfs lists it.
+typedef struct component_usb_device_interface_metadata_t {
+  uint8_t   interface_number;
+  uint8_t   interface_class;
+  // Name of the kernel driver bound to the interface, none if no driver is bound.
+  cguest_option_string_t   driver;
+} component_usb_device_interface_metadata_t;
+
+typedef struct {
+  component_usb_device_interface_metadata_t *ptr;
+  size_t len;
+} component_usb_device_list_interface_metadata_t;
+
+// What `usb-device.get-metadata` reads from sysfs.
+typedef struct component_usb_device_device_metadata_t {
+  cguest_option_string_t   manufacturer;
+  cguest_option_string_t   product;
+  cguest_option_string_t   serial_number;
+  component_usb_device_list_interface_metadata_t   interfaces;
+} component_usb_device_device_metadata_t;
+
+typedef struct {
+  bool is_some;
+  uint16_t val;
+} cguest_option_u16_t;
+
+typedef struct {
+  bool is_some;
+  uint8_t val;
+} cguest_option_u8_t;
+
+typedef struct {
+  bool is_some;
+  cguest_list_u8_t val;
+} cguest_option_list_u8_t;
+
+// Criteria for `list-devices`, fields that are none match any device.
+typedef struct component_usb_device_device_filter_t {
+  cguest_option_u16_t   vendor_id;
+  cguest_option_u16_t   product_id;
+  // Matches the device class and the class of every interface of the active configuration.
+  cguest_option_u8_t   class_;
+  cguest_option_u8_t   bus;
+  // Port numbers from the root hub down to the device, as `usb-device.get-port-path` returns them.
+  cguest_option_list_u8_t   port_path;
+  // Needs the device to be opened, so it only matches devices the host has access to.
+  cguest_option_string_t   serial_number;
+} component_usb_device_device_filter_t;
+
+// What the USB backend of the host supports.
+typedef struct component_usb_device_backend_capabilities_t {
+  bool   has_hotplug;
+  bool   supports_detach_kernel_driver;
+  bool   has_hid_access;
+  // Name of the backend, e.g. "libusb".
+  cguest_string_t   backend;
+  // Version of the backend, e.g. "1.0.27".
+  cguest_string_t   version;
+} component_usb_device_backend_capabilities_t;
+
 // Enum representing USB speeds.
 typedef uint8_t component_usb_device_usb_speed_t;
 
@@ -286,6 +591,14 @@ typedef struct {
   } val;
 } component_usb_device_result_own_device_handle_libusb_error_t;
 
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_device_descriptor_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_device_descriptor_libusb_error_t;
+
 typedef struct {
   bool is_err;
   union {
@@ -294,6 +607,27 @@ typedef struct {
   } val;
 } component_usb_device_result_configuration_descriptor_libusb_error_t;
 
+typedef struct {
+  bool is_err;
+  union {
+    cguest_list_u8_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_list_u8_libusb_error_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_device_metadata_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_device_metadata_libusb_error_t;
+
+typedef struct {
+  bool is_some;
+  component_usb_device_own_usb_device_t val;
+} component_usb_device_option_own_usb_device_t;
+
 typedef struct {
   bool is_err;
   union {
@@ -309,6 +643,14 @@ typedef struct {
   } val;
 } component_usb_device_result_void_libusb_error_t;
 
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_own_claimed_interface_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_own_claimed_interface_libusb_error_t;
+
 typedef struct {
   bool is_err;
   union {
@@ -317,6 +659,43 @@ typedef struct {
   } val;
 } component_usb_device_result_bool_libusb_error_t;
 
+typedef struct {
+  bool is_err;
+  union {
+    cguest_string_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_string_libusb_error_t;
+
+typedef struct {
+  uint16_t *ptr;
+  size_t len;
+} cguest_list_u16_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    cguest_list_u16_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_list_u16_libusb_error_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    cguest_option_string_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_option_string_libusb_error_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_bos_descriptor_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_bos_descriptor_libusb_error_t;
+
 typedef component_usb_transfers_own_transfer_t component_usb_device_own_transfer_t;
 
 typedef struct {
@@ -327,33 +706,73 @@ typedef struct {
   } val;
 } component_usb_device_result_own_transfer_libusb_error_t;
 
+typedef wasi_io_poll_own_pollable_t component_usb_device_own_pollable_t;
+
+typedef struct {
+  component_usb_device_own_endpoint_t *ptr;
+  size_t len;
+} component_usb_device_list_own_endpoint_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_list_own_endpoint_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_list_own_endpoint_libusb_error_t;
+
+typedef struct {
+  bool is_err;
+  union {
+    component_usb_device_own_endpoint_t ok;
+    component_usb_device_libusb_error_t err;
+  } val;
+} component_usb_device_result_own_endpoint_libusb_error_t;
+
+typedef struct {
+  bool is_some;
+  component_usb_device_device_filter_t val;
+} component_usb_device_option_device_filter_t;
+
+typedef struct {
+  bool is_some;
+  component_usb_device_device_metadata_t val;
+} component_usb_device_option_device_metadata_t;
+
 typedef struct {
   component_usb_device_own_usb_device_t f0;
   component_usb_device_device_descriptor_t f1;
   component_usb_device_device_location_t f2;
-} component_usb_device_tuple3_own_usb_device_device_descriptor_device_location_t;
+  component_usb_device_option_device_metadata_t f3;
+} component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t;
 
 typedef struct {
-  component_usb_device_tuple3_own_usb_device_device_descriptor_device_location_t *ptr;
+  component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ptr;
   size_t len;
-} component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t;
+} component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t;
 
 typedef struct {
   bool is_err;
   union {
-    component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t ok;
+    component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t ok;
     component_usb_device_libusb_error_t err;
   } val;
-} component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_t;
+} component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_t;
 
 typedef component_usb_errors_libusb_error_t component_usb_usb_hotplug_libusb_error_t;
 
+// `reenumerated` is raised by `device-handle.reset-device` when the device came back from the
+// reset with other descriptors. It carries the new device, the old handle no longer works.
 typedef uint8_t component_usb_usb_hotplug_event_t;
 
 #define COMPONENT_USB_USB_HOTPLUG_EVENT_ARRIVED (1 << 0)
 #define COMPONENT_USB_USB_HOTPLUG_EVENT_LEFT (1 << 1)
+#define COMPONENT_USB_USB_HOTPLUG_EVENT_REENUMERATED (1 << 2)
 
 typedef struct component_usb_usb_hotplug_info_t {
+  // The same id `usb-device.id` and `device-handle.device-id` return for the device,
+  // so a `left` event can be matched to the handles open on it.
+  uint64_t   id;
   uint8_t   bus;
   uint8_t   address;
   uint16_t   vendor;
@@ -384,7 +803,7 @@ typedef struct {
   bool is_err;
 } exports_wasi_cli_run_result_void_void_t;
 
-// Imported Functions from `component:usb/transfers@0.2.1`
+// Imported Functions from `component:usb/transfers@0.3.0`
 // Submit a transfer to the USB device with the data
 // The transfer is submitted to the device and will be processed asynchronously.
 extern bool component_usb_transfers_method_transfer_submit_transfer(component_usb_transfers_borrow_transfer_t self, cguest_list_u8_t *data, component_usb_transfers_libusb_error_t *err);
@@ -395,18 +814,51 @@ extern bool component_usb_transfers_method_transfer_cancel_transfer(component_us
 // Returns Ok(data) if the transfer completed successfully, or an error code if it failed.
 // The data returned is the data received from the device (if applicable).
 extern bool component_usb_transfers_await_transfer(component_usb_transfers_own_transfer_t xfer, cguest_list_u8_t *ret, component_usb_transfers_libusb_error_t *err);
-
-// Imported Functions from `component:usb/device@0.2.1`
+// Submit many transfers in one call, `data` holds the data for the transfer at the same index
+// (empty for IN transfers). Submission stops at the first failure, the transfers before it
+// are submitted and the ones after it are not. If the lists differ in length nothing is
+// submitted and the error is invalid-param at index 0.
+extern bool component_usb_transfers_submit_batch(component_usb_transfers_list_borrow_transfer_t *xfers, cguest_list_list_u8_t *data, component_usb_transfers_batch_error_t *err);
+// Wait until the first of the submitted `xfers` completes, for at most `timeout-ms` (0 waits forever).
+// Returns its index in `xfers` and its result, as `await-transfer` would. The other transfers
+// stay pending and can be awaited again, the completed one only has to be dropped.
+// Errors: timeout if none completed in time, invalid-param if `xfers` is empty or holds a
+// transfer that is not pending.
+extern bool component_usb_transfers_await_any(component_usb_transfers_list_borrow_transfer_t *xfers, uint32_t timeout_ms, component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t *ret, component_usb_transfers_libusb_error_t *err);
+
+// Imported Functions from `wasi:io/poll@0.2.5`
+extern bool wasi_io_poll_method_pollable_ready(wasi_io_poll_borrow_pollable_t self);
+extern void wasi_io_poll_method_pollable_block(wasi_io_poll_borrow_pollable_t self);
+extern void wasi_io_poll_poll(wasi_io_poll_list_borrow_pollable_t *in, cguest_list_u32_t *ret);
+
+// Imported Functions from `component:usb/device@0.3.0`
 // Open a device and return a handle for I/O operations.
 // The `device` must be one from the list_devices result.
 // On success, returns a device-handle for the open device.
 // Errors: access (permission denied), no_device (device disconnected), etc.
 extern bool component_usb_device_method_usb_device_open(component_usb_device_borrow_usb_device_t self, component_usb_device_own_device_handle_t *ret, component_usb_device_libusb_error_t *err);
+// Identifies the device while it is attached. Every usb-device resource and handle of the
+// same device has the same id, a device that is plugged in again gets a new one.
+extern uint64_t component_usb_device_method_usb_device_id(component_usb_device_borrow_usb_device_t self);
 // Functions to retrieve descriptors
-// get-device-descriptor: func() -> result<device-descriptor, libusb-error>;
+// The device descriptor, served from the copy the OS keeps without touching the device.
+extern bool component_usb_device_method_usb_device_get_device_descriptor(component_usb_device_borrow_usb_device_t self, component_usb_device_device_descriptor_t *ret, component_usb_device_libusb_error_t *err);
 extern bool component_usb_device_method_usb_device_get_configuration_descriptor(component_usb_device_borrow_usb_device_t self, uint8_t config_index, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err);
 extern bool component_usb_device_method_usb_device_get_configuration_descriptor_by_value(component_usb_device_borrow_usb_device_t self, uint8_t config_value, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err);
 extern bool component_usb_device_method_usb_device_get_active_configuration_descriptor(component_usb_device_borrow_usb_device_t self, component_usb_device_configuration_descriptor_t *ret, component_usb_device_libusb_error_t *err);
+// Functions to locate the device in the bus topology
+// Bus number, address, port number and speed of the device.
+extern void component_usb_device_method_usb_device_get_location(component_usb_device_borrow_usb_device_t self, component_usb_device_device_location_t *ret);
+// The speed the device negotiated.
+extern component_usb_device_usb_speed_t component_usb_device_method_usb_device_get_speed(component_usb_device_borrow_usb_device_t self);
+// Port numbers from the root hub down to the device, empty for a root hub.
+extern bool component_usb_device_method_usb_device_get_port_path(component_usb_device_borrow_usb_device_t self, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err);
+// Manufacturer, product and serial number strings and the interfaces of the active
+// configuration with their kernel drivers, read from sysfs without opening the device.
+// Errors: not-found where the host has no sysfs entry for the device, e.g. on other platforms.
+extern bool component_usb_device_method_usb_device_get_metadata(component_usb_device_borrow_usb_device_t self, component_usb_device_device_metadata_t *ret, component_usb_device_libusb_error_t *err);
+// The hub the device is connected to, none for a root hub or a hub hidden by the device policy.
+extern bool component_usb_device_method_usb_device_get_parent(component_usb_device_borrow_usb_device_t self, component_usb_device_own_usb_device_t *ret);
 // Get the currently active configuration value of an open device.
 // On success, returns the bConfigurationValue (0 if unconfigured).
 extern bool component_usb_device_method_device_handle_get_configuration(component_usb_device_borrow_device_handle_t self, uint8_t *ret, component_usb_device_libusb_error_t *err);
@@ -414,19 +866,19 @@ extern bool component_usb_device_method_device_handle_get_configuration(componen
 // Use ConfigValue.unconfigured to unconfigure (set config 0), or ConfigValue.value(n) to set configuration 'n'.
 // Returns an error if the device is busy or the configuration is invalid.
 extern bool component_usb_device_method_device_handle_set_configuration(component_usb_device_borrow_device_handle_t self, component_usb_device_config_value_t *config, component_usb_device_libusb_error_t *err);
-// Claim an iface on a device so it can be used by libusb.
+// Claim an interface so that its endpoints can be used.
 // This should prepare the interface for I/O (detach kernel driver if needed, etc.).
-extern bool component_usb_device_method_device_handle_claim_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err);
-// Release a previously claimed interface.
-// This may perform a SET_INTERFACE to reset alt setting to 0.
-extern bool component_usb_device_method_device_handle_release_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err);
-// Set the alternate setting for the specified interface (must be claimed first).
-extern bool component_usb_device_method_device_handle_set_interface_altsetting(component_usb_device_borrow_device_handle_t self, uint8_t ifac, uint8_t alt_setting, component_usb_device_libusb_error_t *err);
+// Claiming an interface the handle already holds returns another resource for it, the
+// interface is released when the last of these resources is dropped.
+extern bool component_usb_device_method_device_handle_claim_interface(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_own_claimed_interface_t *ret, component_usb_device_libusb_error_t *err);
 // Clear the halt/stall condition on the given endpoint (endpoint number with direction bit).
 // After this, the endpoint can be used again for transfers.
 extern bool component_usb_device_method_device_handle_clear_halt(component_usb_device_borrow_device_handle_t self, uint8_t endpoint, component_usb_device_libusb_error_t *err);
 // Perform a USB port reset on the device.
-// If successful, the device will re-enumerate. The handle remains valid if the device can be re-opened.
+// If the descriptors did not change, the active configuration, claimed interfaces and their
+// alternate settings are restored and the handle keeps working.
+// Errors: not-found if the device re-enumerated with other descriptors. The handle is invalid
+// then and `usb-hotplug.poll-events` delivers a reenumerated event with the new device.
 extern bool component_usb_device_method_device_handle_reset_device(component_usb_device_borrow_device_handle_t self, component_usb_device_libusb_error_t *err);
 // Allocate a USB 3 bulk stream
 // This is used for bulk transfers with USB 3.0 devices.
@@ -441,32 +893,104 @@ extern bool component_usb_device_method_device_handle_kernel_driver_active(compo
 // Detach the kernel driver from an interface, if one is active.
 // After this, libusb can claim the interface. Optional; not all platforms support it.
 extern bool component_usb_device_method_device_handle_detach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err);
+// Let claiming an interface detach its kernel driver and releasing it attach the driver again.
+// Errors: not-supported where the platform cannot detach kernel drivers.
+extern bool component_usb_device_method_device_handle_set_auto_detach_kernel_driver(component_usb_device_borrow_device_handle_t self, bool enable, component_usb_device_libusb_error_t *err);
 // Re-attach the kernel driver to an interface. Optional.
 // Should only be called if a driver was previously detached.
 extern bool component_usb_device_method_device_handle_attach_kernel_driver(component_usb_device_borrow_device_handle_t self, uint8_t ifac, component_usb_device_libusb_error_t *err);
-// Submit an asynchronous USB transfer on an open device handle.
-// - `endpoint`: Endpoint address (with direction bit; e.g., 0x81 for IN endpoint 1).
-// - `type`: Type of transfer (control, bulk, etc.). If `control`, the data should begin with the 8-byte setup packet.
-// - `data`: The payload buffer for the transfer. For control OUT, this includes the setup packet and outgoing data; for control IN, include the setup packet and allocate space for response.
-// - `timeout_ms`: Timeout for the transfer in milliseconds (0 for no timeout).
-// On success, returns a Transfer handle representing the in-flight transfer. The actual completion (success or error) will be delivered via the event handling mechanism.
-extern bool component_usb_device_method_device_handle_new_transfer(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_type_t xfer_type, component_usb_device_transfer_setup_t *setup, uint32_t buf_size, component_usb_device_transfer_options_t *opts, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err);
+// Read string descriptor `index` in the language `langid`, decoded from UTF-16LE.
+// Strings are cached per handle, so repeated lookups do not touch the device.
+extern bool component_usb_device_method_device_handle_get_string_descriptor(component_usb_device_borrow_device_handle_t self, uint8_t index, uint16_t langid, cguest_string_t *ret, component_usb_device_libusb_error_t *err);
+// The language IDs the device provides its strings in (string descriptor 0).
+extern bool component_usb_device_method_device_handle_get_supported_languages(component_usb_device_borrow_device_handle_t self, cguest_list_u16_t *ret, component_usb_device_libusb_error_t *err);
+// The iManufacturer string in the first supported language, none if the device has none.
+extern bool component_usb_device_method_device_handle_get_manufacturer(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err);
+// The iProduct string in the first supported language, none if the device has none.
+extern bool component_usb_device_method_device_handle_get_product(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err);
+// The iSerialNumber string in the first supported language, none if the device has none.
+extern bool component_usb_device_method_device_handle_get_serial_number(component_usb_device_borrow_device_handle_t self, cguest_option_string_t *ret, component_usb_device_libusb_error_t *err);
+// Read configuration descriptor `config-index` from the device as the raw bytes of all
+// wTotalLength, for guests that parse descriptors themselves.
+extern bool component_usb_device_method_device_handle_get_raw_configuration_descriptor(component_usb_device_borrow_device_handle_t self, uint8_t config_index, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err);
+// Read the BOS descriptor and its device capabilities.
+// Devices below USB 2.1 have none, for them this fails with pipe or not-found.
+extern bool component_usb_device_method_device_handle_get_bos_descriptor(component_usb_device_borrow_device_handle_t self, component_usb_device_bos_descriptor_t *ret, component_usb_device_libusb_error_t *err);
+// Create a control transfer on endpoint 0, which needs no claimed interface.
+// - `setup`: The setup packet, its wLength is taken from `buf-size`.
+// - `buf-size`: Bytes to read for control IN, or the size of the data for control OUT.
+// - `timeout-ms`: Timeout for the transfer in milliseconds (0 for no timeout).
+// Transfers on other endpoints are created from the `endpoint` resources of a claimed interface.
+extern bool component_usb_device_method_device_handle_new_control_transfer(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, uint32_t buf_size, uint32_t timeout_ms, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err);
+// Synchronous transfers, each is a single call that returns once the transfer completed.
+// They go through the same checks, limits and audit log as transfers created by hand.
+// Control IN request on endpoint 0, returns up to `length` bytes from the device.
+extern bool component_usb_device_method_device_handle_control_in(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, uint16_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err);
+// Control OUT request on endpoint 0 sending `data`.
+extern bool component_usb_device_method_device_handle_control_out(component_usb_device_borrow_device_handle_t self, component_usb_device_transfer_setup_t *setup, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err);
+// Read up to `length` bytes from bulk IN endpoint `ep`.
+// Errors: invalid-param if `ep` is not a bulk IN endpoint of this handle, not-found once its interface is released.
+extern bool component_usb_device_method_device_handle_bulk_read(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, uint32_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err);
+// Write `data` to bulk OUT endpoint `ep`.
+extern bool component_usb_device_method_device_handle_bulk_write(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err);
+// Read up to `length` bytes from interrupt IN endpoint `ep`.
+extern bool component_usb_device_method_device_handle_interrupt_read(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, uint32_t length, uint32_t timeout_ms, cguest_list_u8_t *ret, component_usb_device_libusb_error_t *err);
+// Write `data` to interrupt OUT endpoint `ep`.
+extern bool component_usb_device_method_device_handle_interrupt_write(component_usb_device_borrow_device_handle_t self, component_usb_device_borrow_endpoint_t ep, cguest_list_u8_t *data, uint32_t timeout_ms, component_usb_device_libusb_error_t *err);
+// The `usb-device.id` of the device the handle is open on.
+extern uint64_t component_usb_device_method_device_handle_device_id(component_usb_device_borrow_device_handle_t self);
+// Ready once the device is unplugged. From then on the methods of the handle, its
+// interfaces and endpoints fail with no-device, and transfers on it cannot be submitted.
+extern component_usb_device_own_pollable_t component_usb_device_method_device_handle_on_disconnect(component_usb_device_borrow_device_handle_t self);
 // Close an open device handle. After this, the handle is invalid.
 // This will release any resources allocated for the handle.
 // (No error is returned; if the device was already disconnected,
 // the handle is simply closed.)
 extern void component_usb_device_method_device_handle_close(component_usb_device_borrow_device_handle_t self);
+// The bInterfaceNumber of the interface.
+extern uint8_t component_usb_device_method_claimed_interface_interface_number(component_usb_device_borrow_claimed_interface_t self);
+// The alternate setting selected through this host, 0 right after claiming.
+extern uint8_t component_usb_device_method_claimed_interface_alt_setting(component_usb_device_borrow_claimed_interface_t self);
+// Select an alternate setting, which changes the endpoints the interface has.
+extern bool component_usb_device_method_claimed_interface_set_alt_setting(component_usb_device_borrow_claimed_interface_t self, uint8_t alt_setting, component_usb_device_libusb_error_t *err);
+// The endpoints of the current alternate setting.
+extern bool component_usb_device_method_claimed_interface_endpoints(component_usb_device_borrow_claimed_interface_t self, component_usb_device_list_own_endpoint_t *ret, component_usb_device_libusb_error_t *err);
+// The endpoint with address `address` (with direction bit) in the current alternate setting.
+// Errors: not-found if the alternate setting has no such endpoint.
+extern bool component_usb_device_method_claimed_interface_endpoint(component_usb_device_borrow_claimed_interface_t self, uint8_t address, component_usb_device_own_endpoint_t *ret, component_usb_device_libusb_error_t *err);
+// Endpoint address (with direction bit).
+extern uint8_t component_usb_device_method_endpoint_address(component_usb_device_borrow_endpoint_t self);
+extern component_usb_device_direction_t component_usb_device_method_endpoint_direction(component_usb_device_borrow_endpoint_t self);
+extern component_usb_device_transfer_type_t component_usb_device_method_endpoint_transfer_type(component_usb_device_borrow_endpoint_t self);
+// wMaxPacketSize of the endpoint.
+extern uint16_t component_usb_device_method_endpoint_max_packet_size(component_usb_device_borrow_endpoint_t self);
+// The full endpoint descriptor.
+extern void component_usb_device_method_endpoint_descriptor(component_usb_device_borrow_endpoint_t self, component_usb_device_endpoint_descriptor_t *ret);
+// Create a transfer of the type of the endpoint.
+// - `buf-size`: Bytes to read for IN endpoints, or the size of the data for OUT endpoints.
+// Fails with not-found once the interface is released.
+extern bool component_usb_device_method_endpoint_new_transfer(component_usb_device_borrow_endpoint_t self, uint32_t buf_size, component_usb_device_transfer_options_t *opts, component_usb_device_own_transfer_t *ret, component_usb_device_libusb_error_t *err);
+// Clear the halt/stall condition of the endpoint.
+extern bool component_usb_device_method_endpoint_clear_halt(component_usb_device_borrow_endpoint_t self, component_usb_device_libusb_error_t *err);
 // Initialize the libusb backend.
 // This may set up internal data structures or threads.
 // Corresponds to libusb_init().
 extern bool component_usb_device_init(component_usb_device_libusb_error_t *err);
-// Enumerate all USB devices on the system.
+// Enumerate the USB devices on the system that pass `filter`, or all of them without one.
 // Returns a list of usb-device objects representing each device.
 // This corresponds to libusb_get_device_list().
 // The returned devices are new references managed by the backend.
-extern bool component_usb_device_list_devices(component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t *ret, component_usb_device_libusb_error_t *err);
-
-// Imported Functions from `component:usb/usb-hotplug@0.2.1`
+// Each device comes with what `usb-device.get-metadata` returns, none where the host has no
+// sysfs entry for it, so the strings and kernel drivers are known without opening any device.
+extern bool component_usb_device_list_devices(component_usb_device_device_filter_t *maybe_filter, component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ret, component_usb_device_libusb_error_t *err);
+// Open the first device with the given vendor and product id, without creating a
+// usb-device for every device on the system.
+// Errors: not-found if no such device is attached or the device policy hides it.
+extern bool component_usb_device_open_device_with_vid_pid(uint16_t vendor_id, uint16_t product_id, component_usb_device_own_device_handle_t *ret, component_usb_device_libusb_error_t *err);
+// Query the backend before relying on optional features, this works before `init`.
+extern void component_usb_device_capabilities(component_usb_device_backend_capabilities_t *ret);
+
+// Imported Functions from `component:usb/usb-hotplug@0.3.0`
 extern bool component_usb_usb_hotplug_enable_hotplug(component_usb_usb_hotplug_libusb_error_t *err);
 extern void component_usb_usb_hotplug_poll_events(component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_t *ret);
 
@@ -485,22 +1009,68 @@ void component_usb_transfers_result_void_libusb_error_free(component_usb_transfe
 
 void component_usb_transfers_result_list_u8_libusb_error_free(component_usb_transfers_result_list_u8_libusb_error_t *ptr);
 
+void component_usb_transfers_list_borrow_transfer_free(component_usb_transfers_list_borrow_transfer_t *ptr);
+
+void cguest_list_list_u8_free(cguest_list_list_u8_t *ptr);
+
+void component_usb_transfers_result_void_batch_error_free(component_usb_transfers_result_void_batch_error_t *ptr);
+
+void component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_free(component_usb_transfers_tuple2_u32_result_list_u8_libusb_error_t *ptr);
+
+void component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_free(component_usb_transfers_result_tuple2_u32_result_list_u8_libusb_error_libusb_error_t *ptr);
+
 void component_usb_configuration_config_value_free(component_usb_configuration_config_value_t *ptr);
 
+void component_usb_descriptors_option_ss_endpoint_companion_free(component_usb_descriptors_option_ss_endpoint_companion_t *ptr);
+
+void component_usb_descriptors_endpoint_descriptor_free(component_usb_descriptors_endpoint_descriptor_t *ptr);
+
 void component_usb_descriptors_list_endpoint_descriptor_free(component_usb_descriptors_list_endpoint_descriptor_t *ptr);
 
 void component_usb_descriptors_interface_descriptor_free(component_usb_descriptors_interface_descriptor_t *ptr);
 
 void component_usb_descriptors_list_interface_descriptor_free(component_usb_descriptors_list_interface_descriptor_t *ptr);
 
+void component_usb_descriptors_usb_interface_free(component_usb_descriptors_usb_interface_t *ptr);
+
+void component_usb_descriptors_list_usb_interface_free(component_usb_descriptors_list_usb_interface_t *ptr);
+
 void component_usb_descriptors_configuration_descriptor_free(component_usb_descriptors_configuration_descriptor_t *ptr);
 
+void cguest_list_u32_free(cguest_list_u32_t *ptr);
+
+void component_usb_descriptors_superspeed_plus_capability_free(component_usb_descriptors_superspeed_plus_capability_t *ptr);
+
+void component_usb_descriptors_list_ms_os20_descriptor_set_free(component_usb_descriptors_list_ms_os20_descriptor_set_t *ptr);
+
+void component_usb_descriptors_platform_kind_free(component_usb_descriptors_platform_kind_t *ptr);
+
+void component_usb_descriptors_platform_capability_free(component_usb_descriptors_platform_capability_t *ptr);
+
+void component_usb_descriptors_unknown_capability_free(component_usb_descriptors_unknown_capability_t *ptr);
+
+void component_usb_descriptors_device_capability_free(component_usb_descriptors_device_capability_t *ptr);
+
+void component_usb_descriptors_list_device_capability_free(component_usb_descriptors_list_device_capability_t *ptr);
+
+void component_usb_descriptors_bos_descriptor_free(component_usb_descriptors_bos_descriptor_t *ptr);
+
+extern void wasi_io_poll_pollable_drop_own(wasi_io_poll_own_pollable_t handle);
+
+extern wasi_io_poll_borrow_pollable_t wasi_io_poll_borrow_pollable(wasi_io_poll_own_pollable_t handle);
+
+void wasi_io_poll_list_borrow_pollable_free(wasi_io_poll_list_borrow_pollable_t *ptr);
+
 void component_usb_device_config_value_free(component_usb_device_config_value_t *ptr);
 
 void component_usb_device_configuration_descriptor_free(component_usb_device_configuration_descriptor_t *ptr);
 
 void component_usb_device_interface_descriptor_free(component_usb_device_interface_descriptor_t *ptr);
 
+void component_usb_device_endpoint_descriptor_free(component_usb_device_endpoint_descriptor_t *ptr);
+
+void component_usb_device_bos_descriptor_free(component_usb_device_bos_descriptor_t *ptr);
+
 extern void component_usb_device_usb_device_drop_own(component_usb_device_own_usb_device_t handle);
 
 extern component_usb_device_borrow_usb_device_t component_usb_device_borrow_usb_device(component_usb_device_own_usb_device_t handle);
@@ -509,21 +1079,79 @@ extern void component_usb_device_device_handle_drop_own(component_usb_device_own
 
 extern component_usb_device_borrow_device_handle_t component_usb_device_borrow_device_handle(component_usb_device_own_device_handle_t handle);
 
+extern void component_usb_device_claimed_interface_drop_own(component_usb_device_own_claimed_interface_t handle);
+
+extern component_usb_device_borrow_claimed_interface_t component_usb_device_borrow_claimed_interface(component_usb_device_own_claimed_interface_t handle);
+
+extern void component_usb_device_endpoint_drop_own(component_usb_device_own_endpoint_t handle);
+
+extern component_usb_device_borrow_endpoint_t component_usb_device_borrow_endpoint(component_usb_device_own_endpoint_t handle);
+
+void cguest_option_string_free(cguest_option_string_t *ptr);
+
+void component_usb_device_interface_metadata_free(component_usb_device_interface_metadata_t *ptr);
+
+void component_usb_device_list_interface_metadata_free(component_usb_device_list_interface_metadata_t *ptr);
+
+void component_usb_device_device_metadata_free(component_usb_device_device_metadata_t *ptr);
+
+void cguest_option_u16_free(cguest_option_u16_t *ptr);
+
+void cguest_option_u8_free(cguest_option_u8_t *ptr);
+
+void cguest_option_list_u8_free(cguest_option_list_u8_t *ptr);
+
+void component_usb_device_device_filter_free(component_usb_device_device_filter_t *ptr);
+
+void component_usb_device_backend_capabilities_free(component_usb_device_backend_capabilities_t *ptr);
+
 void component_usb_device_result_own_device_handle_libusb_error_free(component_usb_device_result_own_device_handle_libusb_error_t *ptr);
 
+void component_usb_device_result_device_descriptor_libusb_error_free(component_usb_device_result_device_descriptor_libusb_error_t *ptr);
+
 void component_usb_device_result_configuration_descriptor_libusb_error_free(component_usb_device_result_configuration_descriptor_libusb_error_t *ptr);
 
+void component_usb_device_result_list_u8_libusb_error_free(component_usb_device_result_list_u8_libusb_error_t *ptr);
+
+void component_usb_device_result_device_metadata_libusb_error_free(component_usb_device_result_device_metadata_libusb_error_t *ptr);
+
+void component_usb_device_option_own_usb_device_free(component_usb_device_option_own_usb_device_t *ptr);
+
 void component_usb_device_result_u8_libusb_error_free(component_usb_device_result_u8_libusb_error_t *ptr);
 
 void component_usb_device_result_void_libusb_error_free(component_usb_device_result_void_libusb_error_t *ptr);
 
+void component_usb_device_result_own_claimed_interface_libusb_error_free(component_usb_device_result_own_claimed_interface_libusb_error_t *ptr);
+
 void component_usb_device_result_bool_libusb_error_free(component_usb_device_result_bool_libusb_error_t *ptr);
 
+void component_usb_device_result_string_libusb_error_free(component_usb_device_result_string_libusb_error_t *ptr);
+
+void cguest_list_u16_free(cguest_list_u16_t *ptr);
+
+void component_usb_device_result_list_u16_libusb_error_free(component_usb_device_result_list_u16_libusb_error_t *ptr);
+
+void component_usb_device_result_option_string_libusb_error_free(component_usb_device_result_option_string_libusb_error_t *ptr);
+
+void component_usb_device_result_bos_descriptor_libusb_error_free(component_usb_device_result_bos_descriptor_libusb_error_t *ptr);
+
 void component_usb_device_result_own_transfer_libusb_error_free(component_usb_device_result_own_transfer_libusb_error_t *ptr);
 
-void component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_free(component_usb_device_list_tuple3_own_usb_device_device_descriptor_device_location_t *ptr);
+void component_usb_device_list_own_endpoint_free(component_usb_device_list_own_endpoint_t *ptr);
+
+void component_usb_device_result_list_own_endpoint_libusb_error_free(component_usb_device_result_list_own_endpoint_libusb_error_t *ptr);
+
+void component_usb_device_result_own_endpoint_libusb_error_free(component_usb_device_result_own_endpoint_libusb_error_t *ptr);
 
-void component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_free(component_usb_device_result_list_tuple3_own_usb_device_device_descriptor_device_location_libusb_error_t *ptr);
+void component_usb_device_option_device_filter_free(component_usb_device_option_device_filter_t *ptr);
+
+void component_usb_device_option_device_metadata_free(component_usb_device_option_device_metadata_t *ptr);
+
+void component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(component_usb_device_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ptr);
+
+void component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_free(component_usb_device_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_t *ptr);
+
+void component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_free(component_usb_device_result_list_tuple4_own_usb_device_device_descriptor_device_location_option_device_metadata_libusb_error_t *ptr);
 
 void component_usb_usb_hotplug_result_void_libusb_error_free(component_usb_usb_hotplug_result_void_libusb_error_t *ptr);
 
@@ -531,6 +1159,16 @@ void component_usb_usb_hotplug_list_tuple3_event_info_own_usb_device_free(compon
 
 void exports_wasi_cli_run_result_void_void_free(exports_wasi_cli_run_result_void_void_t *ptr);
 
+// Transfers ownership of `s` into the string `ret`
+void cguest_string_set(cguest_string_t *ret, const char*s);
+
+// Creates a copy of the input nul-terminate string `s` and
+// stores it into the component model string `ret`.
+void cguest_string_dup(cguest_string_t *ret, const char*s);
+
+// Deallocates the string pointed to by `ret`, deallocating
+// the memory behind the string.
+void cguest_string_free(cguest_string_t *ret);
 
 #ifdef __cplusplus
 }
@@ -3,6 +3,31 @@
 #include <unistd.h>
 #include "bindings/cguest.h"
 
+// Submit an IN transfer, wait for it and print what it read. The transfer is consumed.
+static void run_transfer(component_usb_device_own_transfer_t transfer) {
+    component_usb_transfers_libusb_error_t err;
+    cguest_list_u8_t data = { .ptr = NULL, .len = 0 };
+    if (!component_usb_transfers_method_transfer_submit_transfer(
+            component_usb_transfers_borrow_transfer(transfer), &data, &err)) {
+        fprintf(stderr, "Failed to submit transfer: %d\n", err);
+        component_usb_transfers_transfer_drop_own(transfer);
+        return;
+    }
+    printf("Transfer submitted successfully.\n");
+
+    cguest_list_u8_t result;
+    if (!component_usb_transfers_await_transfer(transfer, &result, &err)) {
+        fprintf(stderr, "Failed to await transfer: %d\n", err);
+        return;
+    }
+    printf("Transfer completed successfully. Received %zu bytes.\n", result.len);
+    for (size_t i = 0; i < result.len; i++) {
+        printf("%02x ", result.ptr[i]);
+    }
+    printf("\n");
+    cguest_list_u8_free(&result);
+}
+
 bool exports_wasi_cli_run_run(void) {
     component_usb_device_libusb_error_t err;
 
@@ -42,9 +67,10 @@ bool exports_wasi_cli_run_run(void) {
                     continue;
                 }
 
-                // Claim interface 0
+                // Claim interface 0, it is released when the claimed interface is dropped
+                component_usb_device_own_claimed_interface_t interface;
                 if (!component_usb_device_method_device_handle_claim_interface(
-                        component_usb_device_borrow_device_handle(device_handle), 0, &err)) {
+                        component_usb_device_borrow_device_handle(device_handle), 0, &interface, &err)) {
                     fprintf(stderr, "Failed to claim interface: %d\n", err);
                     component_usb_device_method_device_handle_close(
                         component_usb_device_borrow_device_handle(device_handle));
@@ -52,53 +78,63 @@ bool exports_wasi_cli_run_run(void) {
                     continue;
                 }
 
-                // Perform a transfer (example: control transfer)
+                // Perform a control transfer on endpoint 0
                 component_usb_device_transfer_setup_t setup = {
                     .bm_request_type = 0x80, // Direction: IN, Type: Standard, Recipient: Device
                     .b_request = 0x06,      // GET_DESCRIPTOR
                     .w_value = 0x0100,      // Descriptor Type (Device) and Index
                     .w_index = 0x0000       // Language ID
                 };
-                component_usb_device_transfer_options_t options = {
-                    .endpoint = 0x00,       // Endpoint 0
-                    .timeout_ms = 1000,     // 1 second timeout
-                    .stream_id = 0,
-                    .iso_packets = 0
-                };
                 component_usb_device_own_transfer_t transfer;
-                if (!component_usb_device_method_device_handle_new_transfer(
-                        component_usb_device_borrow_device_handle(device_handle),
-                        COMPONENT_USB_TRANSFERS_TRANSFER_TYPE_CONTROL, &setup, 64, &options, &transfer, &err)) {
+                if (!component_usb_device_method_device_handle_new_control_transfer(
+                        component_usb_device_borrow_device_handle(device_handle), &setup, 64, 1000, &transfer, &err)) {
                     fprintf(stderr, "Failed to create transfer: %d\n", err);
                 } else {
-                    printf("Transfer created successfully.\n");
+                    printf("Control transfer created successfully.\n");
+                    run_transfer(transfer);
+                }
 
-                    // Submit the transfer
-                    cguest_list_u8_t data = { .ptr = NULL, .len = 0 };
-                    if (!component_usb_transfers_method_transfer_submit_transfer(
-                            component_usb_transfers_borrow_transfer(transfer), &data, &err)) {
-                        fprintf(stderr, "Failed to submit transfer: %d\n", err);
+                // Read once from the first IN endpoint of the interface, if it has one
+                component_usb_device_list_own_endpoint_t endpoints;
+                if (!component_usb_device_method_claimed_interface_endpoints(
+                        component_usb_device_borrow_claimed_interface(interface), &endpoints, &err)) {
+                    fprintf(stderr, "Failed to get endpoints: %d\n", err);
+                    endpoints.len = 0;
+                    endpoints.ptr = NULL;
+                }
+                for (size_t k = 0; k < endpoints.len; k++) {
+                    component_usb_device_borrow_endpoint_t endpoint =
+                        component_usb_device_borrow_endpoint(endpoints.ptr[k]);
+                    if (component_usb_device_method_endpoint_direction(endpoint) != COMPONENT_USB_DEVICE_DIRECTION_IN
+                            || component_usb_device_method_endpoint_transfer_type(endpoint)
+                                == COMPONENT_USB_TRANSFERS_TRANSFER_TYPE_ISOCHRONOUS) {
+                        continue;
+                    }
+                    // the endpoint creates a transfer of its own type
+                    component_usb_device_transfer_options_t options = {
+                        .timeout_ms = 1000,     // 1 second timeout
+                        .stream_id = 0,
+                        .iso_packets = 0
+                    };
+                    if (!component_usb_device_method_endpoint_new_transfer(
+                            endpoint, component_usb_device_method_endpoint_max_packet_size(endpoint),
+                            &options, &transfer, &err)) {
+                        fprintf(stderr, "Failed to create transfer: %d\n", err);
                     } else {
-                        printf("Transfer submitted successfully.\n");
-
-                        // Await the transfer
-                        cguest_list_u8_t result;
-                        if (!component_usb_transfers_await_transfer(transfer, &result, &err)) {
-                            fprintf(stderr, "Failed to await transfer: %d\n", err);
-                        } else {
-                            printf("Transfer completed successfully. Received %zu bytes.\n", result.len);
-                            for (size_t i = 0; i < result.len; i++) {
-                                printf("%02x ", result.ptr[i]);
-                            }
-                            printf("\n");
-                            cguest_list_u8_free(&result);
-                        }
+                        printf("Transfer on endpoint %02x created successfully.\n",
+                               component_usb_device_method_endpoint_address(endpoint));
+                        run_transfer(transfer);
                     }
+                    break;
+                }
+                // freeing the list does not drop the endpoints in it
+                for (size_t k = 0; k < endpoints.len; k++) {
+                    component_usb_device_endpoint_drop_own(endpoints.ptr[k]);
                 }
+                component_usb_device_list_own_endpoint_free(&endpoints);
 
                 // Release the interface and close the device
-                component_usb_device_method_device_handle_release_interface(
-                    component_usb_device_borrow_device_handle(device_handle), 0, &err);
+                component_usb_device_claimed_interface_drop_own(interface);
                 component_usb_device_method_device_handle_close(
                     component_usb_device_borrow_device_handle(device_handle));
                 component_usb_device_device_handle_drop_own(device_handle);
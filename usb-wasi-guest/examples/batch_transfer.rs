@@ -0,0 +1,54 @@
+use wit_bindgen::generate;
+generate!({
+    world: "guest",
+    path: "../wit",
+    generate_all,
+});
+
+use component::usb::{
+    device,
+    transfers::TransferSetup,
+};
+use crate::component::usb::transfers;
+
+fn main() {
+    device::init().expect("libusb init failed");
+    let mut devs = device::list_devices(None).expect("list_devices failed");
+    if devs.is_empty() {
+        println!("No USB devices found.");
+        return;
+    }
+    // open first device
+    let (dev, desc, _) = devs.remove(0);
+    let handle = dev.open().expect("open failed");
+
+    // one Control-IN transfer per configuration, reading the 9-byte header
+    let xfers: Vec<transfers::Transfer> = (0..desc.num_configurations)
+        .map(|index| {
+            let setup = TransferSetup { bm_request_type: 0x80, b_request: 0x06, w_value: 0x0200 | index as u16, w_index: 0, };
+            handle.new_control_transfer(setup, 9, 1_000).expect("alloc xfer")
+        })
+        .collect();
+
+    // submit all of them in one call, IN transfers carry no data
+    let borrowed: Vec<&transfers::Transfer> = xfers.iter().collect();
+    let data = vec![Vec::new(); xfers.len()];
+    if let Err(e) = transfers::submit_batch(&borrowed, &data) {
+        println!("submit_batch failed at transfer {}: {:?}", e.index, e.error);
+        return;
+    }
+
+    // handle the transfers in the order they complete
+    let mut pending: Vec<(u8, transfers::Transfer)> = (0..).zip(xfers).collect();
+    while !pending.is_empty() {
+        let borrowed: Vec<&transfers::Transfer> = pending.iter().map(|(_, x)| x).collect();
+        let (index, result) = transfers::await_any(&borrowed, 1_000).expect("await_any failed");
+        let (config, _) = pending.remove(index as usize);
+        match result {
+            Ok(buf) => println!("Config[{}] descriptor bytes: {:?}", config, buf),
+            Err(e) => println!("Config[{}] failed: {:?}", config, e),
+        }
+    }
+
+    handle.close();
+}
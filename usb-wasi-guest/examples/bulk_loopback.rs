@@ -1,57 +1,40 @@
-use wit_bindgen::generate;
-generate!({
-    world: "guest",
-    path: "../wit",
-});
-
-use component::usb::{
-    device,
-    transfers::{TransferType, TransferSetup, TransferOptions},
-};
-use crate::component::usb::configuration::ConfigValue;
-use crate::component::usb::transfers;
-
-fn main() {
-    // ...existing code: init + list...
-    device::init().expect("init failed");
-    let mut devs = device::list_devices().expect("list_devices failed");
-    if devs.is_empty() {
+use std::time::Duration;
+
+use wasi_usb::Context;
+
+fn main() -> Result<(), wasi_usb::Error> {
+    let context = Context::new()?;
+    let Some(device) = context.devices()?.into_iter().next() else {
         println!("No USB devices found.");
-        return;
-    }
-    let handle = devs.remove(0).0.open().expect("open failed");
+        return Ok(());
+    };
+    let handle = device.open()?;
 
     // assume cfg=1, iface=0, bulk OUT @0x01, bulk IN @0x81
-    handle.set_configuration(ConfigValue::Value(1)).expect("set_configuration");
+    handle.set_configuration(Some(1))?;
 
     // detach kernel driver if active
     if let Ok(true) = handle.kernel_driver_active(0) {
         let _ = handle.detach_kernel_driver(0);
     }
 
-    handle.claim_interface(0).expect("claim_interface");
+    let interface = handle.claim_interface(0)?;
+    let ep_out = interface.endpoint(0x01)?;
+    let ep_in = interface.endpoint(0x81)?;
 
     // prepare a 0..63 pattern
     let out_data: Vec<u8> = (0..64).collect();
+    let timeout = Duration::from_secs(5);
+
+    // bulk-OUT, then read the data back
+    let xfer_out = ep_out.transfer(out_data.len() as u32, timeout)?;
+    xfer_out.submit(&out_data)?;
+    let xfer_in = ep_in.transfer(out_data.len() as u32, timeout)?;
+    xfer_in.submit(&[])?;
 
-    // bulk‐OUT
-    let opts_out = TransferOptions { endpoint: 1, timeout_ms: 5_000, stream_id: 0, iso_packets: 0 };
-    let setup = TransferSetup { bm_request_type: 0, b_request: 0, w_value: 0, w_index: 0 };
-    let xfer_out = handle
-        .new_transfer(TransferType::Bulk, setup, out_data.len() as u32, opts_out)
-        .expect("alloc xfer_out");
-    xfer_out.submit_transfer(&out_data).expect("submit out");
-
-    // bulk‐IN
-    let opts_in = TransferOptions { endpoint: 0x81, timeout_ms: 5_000, stream_id: 0, iso_packets: 0 };
-    let xfer_in = handle
-        .new_transfer(TransferType::Bulk, setup, out_data.len() as u32, opts_in)
-        .expect("alloc xfer_in");
-    xfer_in.submit_transfer(&[]).expect("submit in");
-
-    let in_data = transfers::await_transfer(xfer_in).expect("await in");
+    let in_data = xfer_in.wait()?;
     println!("Received {} bytes: {:?}", in_data.len(), in_data);
 
-    handle.release_interface(0).expect("release_interface");
-    handle.close();
+    // dropping the interface releases it, dropping the handle closes it
+    Ok(())
 }
@@ -1,14 +1,16 @@
 use wit_bindgen::generate;
-generate!({ world: "guest", path: "../wit" });
+generate!({ world: "guest", path: "../wit", generate_all });
 
 use component::usb::{
     device,
     configuration::ConfigValue,
+    errors::LibusbError,
+    usb_hotplug::{self, Event},
 };
 
 fn main() {
     device::init().expect("init failed");
-    let mut devs = device::list_devices().expect("list_devices failed");
+    let mut devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() { return; }
     let handle = devs.remove(0).0.open().expect("open failed");
 
@@ -21,16 +23,38 @@ fn main() {
     if let Ok(true) = handle.kernel_driver_active(0) {
         let _ = handle.detach_kernel_driver(0);
     }
-    handle.claim_interface(0).expect("claim_interface failed");
+    let interface = handle.claim_interface(0).expect("claim_interface failed");
 
     // now clear a stall on endpoint 0x81
-    handle.clear_halt(0x81).expect("clear_halt failed");
+    interface
+        .endpoint(0x81)
+        .expect("endpoint 0x81 not found")
+        .clear_halt()
+        .expect("clear_halt failed");
 
-    // reset the device
-    handle.reset_device().expect("reset_device failed");
+    // reset the device, the host restores the configuration and the claimed interface
+    match handle.reset_device() {
+        Ok(()) => {}
+        Err(LibusbError::NotFound) => {
+            // the device came back as a different device, the old handle is gone
+            drop(interface);
+            let new_device = usb_hotplug::poll_events()
+                .into_iter()
+                .find(|(event, _, _)| event.contains(Event::REENUMERATED))
+                .map(|(_, info, device)| {
+                    println!("device re-enumerated as {:04x}:{:04x} at address {}", info.vendor, info.product, info.address);
+                    device
+                });
+            if let Some(device) = new_device {
+                device.open().expect("reopen failed").close();
+            }
+            return;
+        }
+        Err(e) => panic!("reset_device failed: {:?}", e),
+    }
 
     // release interface 0
-    handle.release_interface(0).expect("release_interface failed");
+    drop(interface);
 
     // unconfigure (cfg = 0)
     handle
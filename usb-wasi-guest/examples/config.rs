@@ -1,12 +1,12 @@
 use wit_bindgen::generate;
-generate!({ world: "guest", path: "../wit" });
+generate!({ world: "guest", path: "../wit", generate_all });
 
 use component::usb::device;
 use component::usb::descriptors::ConfigurationDescriptor;
 
 fn main() {
     device::init().expect("init failed");
-    let devs = device::list_devices().expect("list_devices failed");
+    let devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() {
         println!("No devices.");
         return;
@@ -5,6 +5,7 @@ use crate::component::usb::device::init;
 generate!({
     world: "guest",
     path: "../wit",
+    generate_all,
 });
 
 fn main() {
@@ -17,7 +18,7 @@ fn main() {
     println!("Hot-plug enabled – attach or remove a USB device to test.");
 
     // 2. Show the initial device count so we know the backend is alive.
-    match device::list_devices() {
+    match device::list_devices(None) {
         Ok(list) => println!("Initially {} devices present.", list.len()),
         Err(e) => {
             eprintln!("Could not list devices: {:?}", e);
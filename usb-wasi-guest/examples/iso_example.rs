@@ -2,11 +2,12 @@ use wit_bindgen::generate;
 generate!({
     world: "guest",
     path: "../wit",
+    generate_all,
 });
 
 use component::usb::{
     device,
-    transfers::{TransferType, TransferSetup, TransferOptions},
+    transfers::TransferOptions,
 };
 use crate::component::usb::configuration::ConfigValue;
 use crate::component::usb::transfers;
@@ -14,7 +15,7 @@ use crate::component::usb::transfers;
 fn main() {
     // ...existing code: init + list...
     device::init().expect("init failed");
-    let mut devs = device::list_devices().expect("list_devices failed");
+    let mut devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() {
         println!("No devices.");
         return;
@@ -29,22 +30,23 @@ fn main() {
         let _ = handle.detach_kernel_driver(2);
     }
 
-    handle.claim_interface(2).expect("claim_interface");
-    handle.set_interface_altsetting(2, 1).expect("altsetting");
+    let interface = handle.claim_interface(2).expect("claim_interface");
+    interface.set_alt_setting(1).expect("altsetting");
+    let endpoint = interface.endpoint(0x82).expect("iso IN endpoint");
 
     // request two 512-byte packets
-    let setup = TransferSetup { bm_request_type: 0, b_request: 0, w_value: 0, w_index: 0 };
-    let opts = TransferOptions { endpoint: 0x82, timeout_ms: 5000, stream_id: 0, iso_packets: 2 };
+    let opts = TransferOptions { timeout_ms: 5000, stream_id: 0, iso_packets: 2 };
     let buf_size = 512 * 2;
 
-    let xfer = handle
-        .new_transfer(TransferType::Isochronous, setup, buf_size, opts)
+    let xfer = endpoint
+        .new_transfer(buf_size, opts)
         .expect("new_transfer");
     xfer.submit_transfer(&[]).expect("submit_transfer");
 
     let buf = transfers::await_transfer(xfer).expect("await_transfer");
     println!("Isochronous total bytes: {}", buf.len());
 
-    handle.release_interface(2).expect("release_interface");
+    drop(endpoint);
+    drop(interface);
     handle.close();
 }
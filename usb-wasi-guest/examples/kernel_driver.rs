@@ -1,11 +1,11 @@
 use wit_bindgen::generate;
-generate!({ world: "guest", path: "../wit" });
+generate!({ world: "guest", path: "../wit", generate_all });
 
 use component::usb::{ device, configuration::ConfigValue };
 
 fn main() {
     device::init().expect("init failed");
-    let mut devs = device::list_devices().expect("list_devices failed");
+    let mut devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() { return; }
     let handle = devs.remove(0).0.open().expect("open failed");
 
@@ -30,5 +30,16 @@ fn main() {
         .expect("attach_kernel_driver failed");
     println!("re-attached kernel driver");
 
+    // the same with a single flag: claiming detaches the driver, releasing attaches it again
+    if device::capabilities().supports_detach_kernel_driver {
+        handle
+            .set_auto_detach_kernel_driver(true)
+            .expect("set_auto_detach_kernel_driver failed");
+        let interface = handle.claim_interface(ifac).expect("claim_interface failed");
+        println!("claimed ifac {} with its kernel driver detached", ifac);
+        drop(interface);
+        println!("released ifac {}, kernel driver attached again", ifac);
+    }
+
     handle.close();
 }
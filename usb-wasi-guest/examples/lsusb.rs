@@ -2,13 +2,13 @@ use wit_bindgen::generate;
 generate!({
     world: "guest",
     path: "../wit",
+    generate_all,
 });
 
 use component::usb::{
     device,
-    transfers::{TransferType, TransferSetup, TransferOptions},
+    transfers::TransferSetup,
 };
-use crate::component::usb::transfers;
 
 /// Issue a Control-IN transfer and return the received data.
 ///
@@ -26,34 +26,7 @@ fn control_in(
         w_value,
         w_index,
     };
-    let opts = TransferOptions {
-        endpoint: 0,          // EP-0
-        timeout_ms: 1_000,
-        stream_id: 0,
-        iso_packets: 0,
-    };
-    let xfer = handle
-        .new_transfer(TransferType::Control, setup, len as u32, opts)
-        .expect("new_transfer failed");
-
-    // OUT buffer is empty for IN requests
-    xfer.submit_transfer(&[]).expect("submit failed");
-    transfers::await_transfer(xfer)
-}
-
-/// Decode UTF-16LE bytes from a string-descriptor into Rust UTF-8.
-fn decode_usb_string(buf: &[u8]) -> String {
-    if buf.len() < 2 || buf[1] != 0x03 {
-        return "<bad string>".into();
-    }
-    // bytes 2.. are UTF-16LE code units
-    let utf16: Vec<u16> = buf[2..]
-        .chunks(2)
-        .filter_map(|b| if b.len() == 2 {
-            Some(u16::from_le_bytes([b[0], b[1]]))
-        } else { None })
-        .collect();
-    String::from_utf16(&utf16).unwrap_or_else(|_| "<utf16 error>".into())
+    handle.control_in(setup, len, 1_000)
 }
 
 fn main() {
@@ -65,7 +38,7 @@ fn main() {
     //----------------------------------------
     // 2: list and iterate devices
     //----------------------------------------
-    let devs = device::list_devices().expect("list_devices failed");
+    let devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() {
         println!("No USB devices found.");
         return;
@@ -82,6 +55,22 @@ fn main() {
             Ok(h) => h,
             Err(e) => {
                 println!("  <cannot open>  {:?}", e);
+                // sysfs still tells us what the device is
+                if let Some(meta) = dev.3.clone() {
+                    println!(
+                        "  {} {}",
+                        meta.manufacturer.unwrap_or_default(),
+                        meta.product.unwrap_or_default()
+                    );
+                    for iface in meta.interfaces {
+                        println!(
+                            "  Interface {} class {:#04x} driver {}",
+                            iface.interface_number,
+                            iface.interface_class,
+                            iface.driver.as_deref().unwrap_or("<none>")
+                        );
+                    }
+                }
                 continue;
             }
         };
@@ -131,20 +120,10 @@ fn main() {
         println!("  VID:PID            {:04x}:{:04x}", vid, pid);
 
         //------------------------------------
-        // fetch manufacturer / product strings (if any)
+        // fetch manufacturer / product / serial strings (if any)
         //------------------------------------
-        let mut mfg = None;
-        if mfg_idx != 0 {
-            if let Ok(buf) = control_in(&handle, 0x06, 0x0300 | mfg_idx as u16, 0x0409, 255) {
-                mfg = Some(decode_usb_string(&buf));
-            }
-        }
-        let mut prod = None;
-        if prod_idx != 0 {
-            if let Ok(buf) = control_in(&handle, 0x06, 0x0300 | prod_idx as u16, 0x0409, 255) {
-                prod = Some(decode_usb_string(&buf));
-            }
-        }
+        let mfg = handle.get_manufacturer().ok().flatten();
+        let prod = handle.get_product().ok().flatten();
         if mfg.is_some() || prod.is_some() {
             println!(
                 "  Strings            {} {}",
@@ -152,6 +131,9 @@ fn main() {
                 prod.as_deref().unwrap_or("")
             );
         }
+        if let Ok(Some(serial)) = handle.get_serial_number() {
+            println!("  Serial             {}", serial);
+        }
 
         //------------------------------------
         // Optionally list each configuration’s total length and endpoints
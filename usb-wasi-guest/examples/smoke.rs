@@ -4,16 +4,16 @@ use wit_bindgen::rt::async_support::futures::TryFutureExt;
 generate!({
     world: "guest",
     path: "../wit",
+    generate_all,
 });
 
 use component::usb::{
     device,
 };
 
-use component::usb::transfers::TransferType;
 use crate::component::usb;
 use crate::component::usb::transfers;
-use crate::component::usb::transfers::{TransferOptions, TransferSetup};
+use crate::component::usb::transfers::TransferSetup;
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() {
@@ -23,7 +23,7 @@ async fn main() {
     device::init().expect("libusb initialisation failed");
 
     // 2. List devices
-    let devices = device::list_devices().expect("list_devices failed");
+    let devices = device::list_devices(None).expect("list_devices failed");
     if devices.is_empty() {
         println!("No USB devices found.");
         return;
@@ -41,7 +41,7 @@ async fn main() {
     }
 
     // 4. Claim interface 0 if present
-    let _ = handle.claim_interface(0).expect("claim_interface failed");
+    let interface = handle.claim_interface(0).expect("claim_interface failed");
 
     // 5. Simple 18-byte GET_DESCRIPTOR(Device) control IN transfer
     let setup = TransferSetup {
@@ -50,15 +50,9 @@ async fn main() {
         w_value: 0x0100,              // descriptor type 1 (Device), index 0
         w_index: 0,
     };
-    let opts = TransferOptions {
-        endpoint: 0,                  // control EP0
-        timeout_ms: 1_000,
-        stream_id: 0,
-        iso_packets: 0,
-    };
     let xfer = handle
-        .new_transfer(TransferType::Control, setup, 18, opts)
-        .expect("new_transfer failed");
+        .new_control_transfer(setup, 18, 1_000)
+        .expect("new_control_transfer failed");
 
     xfer.submit_transfer(&*Vec::new()).expect("submit failed");
     match transfers::await_transfer(xfer) {
@@ -67,7 +61,7 @@ async fn main() {
     }
 
     // 6. Release IF 0 and close handle
-    let _ = handle.release_interface(0);
+    drop(interface);
     
     // wait for 5 seconds
     println!("Waiting for 5 seconds …");
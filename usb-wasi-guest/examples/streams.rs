@@ -1,11 +1,11 @@
 use wit_bindgen::generate;
-generate!({ world: "guest", path: "../wit" });
+generate!({ world: "guest", path: "../wit", generate_all });
 
 use component::usb::{ device, configuration::ConfigValue };
 
 fn main() {
     device::init().expect("init failed");
-    let mut devs = device::list_devices().expect("list_devices failed");
+    let mut devs = device::list_devices(None).expect("list_devices failed");
     if devs.is_empty() { return; }
     let handle = devs.remove(0).0.open().expect("open failed");
 
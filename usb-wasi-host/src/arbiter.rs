@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Identifies an interface of a device on the system. The device is identified by its session id,
+/// see [`crate::enumerate::session_id`], so a claim does not carry over to a device that gets the
+/// address of one that left. Stores that share an arbiter must therefore share a libusb context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InterfaceKey {
+    pub device: u64,
+    pub interface: u8,
+}
+
+/// Decides which guest owns an interface when several guests share one host process.
+/// A claim is only handed to libusb once the arbiter has granted it.
+#[derive(Debug, Default)]
+pub struct InterfaceArbiter {
+    claims: Mutex<HashMap<InterfaceKey, String>>,
+}
+
+impl InterfaceArbiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grant `key` to `owner`, true if `owner` did not hold it yet.
+    /// Fails with the current owner if somebody else holds it.
+    pub fn claim(&self, key: InterfaceKey, owner: &str) -> Result<bool, String> {
+        let mut claims = self.claims.lock().unwrap();
+        match claims.get(&key) {
+            Some(current) if current != owner => Err(current.clone()),
+            Some(_) => Ok(false),
+            None => {
+                claims.insert(key, owner.to_string());
+                Ok(true)
+            }
+        }
+    }
+
+    pub fn release(&self, key: InterfaceKey, owner: &str) {
+        let mut claims = self.claims.lock().unwrap();
+        if claims.get(&key).is_some_and(|current| current == owner) {
+            claims.remove(&key);
+        }
+    }
+
+    /// Drop every claim held by `owner`, e.g. when its store goes away.
+    pub fn release_owner(&self, owner: &str) {
+        self.claims.lock().unwrap().retain(|_, current| current != owner);
+    }
+
+    /// Snapshot of the current claims.
+    pub fn claims(&self) -> Vec<(InterfaceKey, String)> {
+        self.claims
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, owner)| (*key, owner.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: InterfaceKey = InterfaceKey { device: 1, interface: 0 };
+
+    #[test]
+    fn claim_and_reclaim() {
+        let arbiter = InterfaceArbiter::new();
+        assert_eq!(arbiter.claim(KEY, "a"), Ok(true));
+        // a second handle of the same owner shares the claim
+        assert_eq!(arbiter.claim(KEY, "a"), Ok(false));
+        assert_eq!(arbiter.claims(), vec![(KEY, "a".to_string())]);
+    }
+
+    #[test]
+    fn conflicting_owners() {
+        let arbiter = InterfaceArbiter::new();
+        arbiter.claim(KEY, "a").unwrap();
+        assert_eq!(arbiter.claim(KEY, "b"), Err("a".to_string()));
+        let other = InterfaceKey { interface: 1, ..KEY };
+        assert_eq!(arbiter.claim(other, "b"), Ok(true));
+        // a device that took over the address of one that left has another session id
+        assert_eq!(arbiter.claim(InterfaceKey { device: 2, ..KEY }, "b"), Ok(true));
+    }
+
+    #[test]
+    fn release() {
+        let arbiter = InterfaceArbiter::new();
+        arbiter.claim(KEY, "a").unwrap();
+        // only the owner can release a claim
+        arbiter.release(KEY, "b");
+        assert_eq!(arbiter.claim(KEY, "b"), Err("a".to_string()));
+        arbiter.release(KEY, "a");
+        assert_eq!(arbiter.claim(KEY, "b"), Ok(true));
+        assert_eq!(arbiter.claims(), vec![(KEY, "b".to_string())]);
+    }
+
+    #[test]
+    fn release_owner() {
+        let arbiter = InterfaceArbiter::new();
+        let other = InterfaceKey { interface: 1, ..KEY };
+        arbiter.claim(KEY, "a").unwrap();
+        arbiter.claim(other, "a").unwrap();
+        arbiter.release_owner("a");
+        assert!(arbiter.claims().is_empty());
+        assert_eq!(arbiter.claim(KEY, "b"), Ok(true));
+    }
+}
@@ -0,0 +1,196 @@
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::ValueEnum;
+use libusb1_sys::{libusb_device, libusb_device_descriptor, libusb_get_bus_number, libusb_get_device_address, libusb_get_device_descriptor};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::component::usb::transfers::TransferSetup;
+
+/// How transfer payloads are written to the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditPayloads {
+    /// Do not record payloads at all
+    None,
+    /// Record the SHA-256 of the payload
+    #[default]
+    Hash,
+    /// Record the payload itself as a hex string
+    Full,
+}
+
+/// Identifies the device an audited operation touched.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct AuditDevice {
+    pub bus: u8,
+    pub address: u8,
+    pub vendor_id: u16,
+    pub product_id: u16,
+}
+
+impl AuditDevice {
+    /// Collect the identifying fields of a device without opening it.
+    ///
+    /// # Safety
+    /// `dev` must be a valid libusb device.
+    pub unsafe fn from_raw(dev: *mut libusb_device) -> Option<Self> {
+        let mut desc = std::mem::MaybeUninit::<libusb_device_descriptor>::uninit();
+        if libusb_get_device_descriptor(dev, desc.as_mut_ptr()) < 0 {
+            return None;
+        }
+        let desc = desc.assume_init();
+        Some(Self {
+            bus: libusb_get_bus_number(dev),
+            address: libusb_get_device_address(dev),
+            vendor_id: desc.idVendor,
+            product_id: desc.idProduct,
+        })
+    }
+}
+
+/// Everything recorded about a single finished transfer.
+pub struct TransferRecord<'a> {
+    pub device: Option<AuditDevice>,
+    pub transfer_type: &'a str,
+    pub endpoint: u8,
+    pub setup: Option<TransferSetup>,
+    pub length: u32,
+    pub payload: Option<&'a [u8]>,
+    pub result: &'a str,
+    pub duration: Option<Duration>,
+}
+
+/// Append-only JSON-lines log of security relevant USB operations.
+///
+/// Every line carries the component path and a timestamp so that it can be
+/// traced back to the guest that performed the operation.
+pub struct AuditLog {
+    component: String,
+    payloads: AuditPayloads,
+    writer: Mutex<BufWriter<File>>,
+}
+
+impl AuditLog {
+    pub fn open(path: &Path, component: &Path, payloads: AuditPayloads) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            component: component.display().to_string(),
+            payloads,
+            writer: Mutex::new(BufWriter::new(file)),
+        })
+    }
+
+    /// Write a single operation to the log.
+    /// `fields` must be a JSON object; its entries are merged into the line.
+    pub fn record(&self, operation: &str, device: Option<AuditDevice>, result: &str, fields: Value) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut line = json!({
+            "timestamp": timestamp.as_secs_f64(),
+            "component": self.component,
+            "operation": operation,
+            "device": device,
+            "result": result,
+        });
+        if let (Some(line), Value::Object(fields)) = (line.as_object_mut(), fields) {
+            line.extend(fields);
+        }
+
+        let mut writer = self.writer.lock().unwrap();
+        if let Err(e) = writeln!(writer, "{}", line).and_then(|_| writer.flush()) {
+            error!("Failed to write audit log entry: {}", e);
+        }
+    }
+
+    pub fn record_transfer(&self, transfer: TransferRecord) {
+        // wLength is not part of the WIT setup, it is the length of the data stage
+        let setup = transfer.setup.map(|setup| json!({
+            "bm_request_type": setup.bm_request_type,
+            "b_request": setup.b_request,
+            "w_value": setup.w_value,
+            "w_index": setup.w_index,
+            "w_length": transfer.length,
+        }));
+        let mut fields = json!({
+            "transfer_type": transfer.transfer_type,
+            "endpoint": transfer.endpoint,
+            "setup": setup,
+            "length": transfer.length,
+            "duration_us": transfer.duration.map(|d| d.as_micros() as u64),
+        });
+        if let Some(payload) = transfer.payload {
+            match self.payloads {
+                AuditPayloads::None => {}
+                AuditPayloads::Hash => fields["payload"] = json!({ "sha256": hex(&Sha256::digest(payload)) }),
+                AuditPayloads::Full => fields["payload"] = json!({ "data": hex(payload) }),
+            }
+        }
+        self.record("transfer", transfer.device, transfer.result, fields);
+    }
+}
+
+/// Format the outcome of a host call the way it appears in the `result` field.
+pub fn outcome<T, E: std::fmt::Debug>(res: &Result<T, E>) -> String {
+    match res {
+        Ok(_) => "ok".to_string(),
+        Err(e) => format!("{:?}", e),
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_line(payloads: AuditPayloads) -> Value {
+        static NEXT: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+        let n = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("usb-wasi-audit-{}-{}.jsonl", std::process::id(), n));
+        let _ = std::fs::remove_file(&path);
+        let log = AuditLog::open(&path, Path::new("guest.wasm"), payloads).unwrap();
+        log.record_transfer(TransferRecord {
+            device: None,
+            transfer_type: "control",
+            endpoint: 0,
+            setup: Some(TransferSetup { bm_request_type: 0x80, b_request: 6, w_value: 0x0100, w_index: 0 }),
+            length: 18,
+            payload: Some(&[0x12, 0x01]),
+            result: "ok",
+            duration: None,
+        });
+        let line = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        serde_json::from_str(line.trim()).unwrap()
+    }
+
+    #[test]
+    fn payloads_none_leaves_the_field_out() {
+        let line = transfer_line(AuditPayloads::None);
+        assert!(line.get("payload").is_none());
+    }
+
+    #[test]
+    fn payloads_are_hashed_or_hex_encoded() {
+        assert_eq!(transfer_line(AuditPayloads::Full)["payload"]["data"], "1201");
+        let hash = transfer_line(AuditPayloads::Hash);
+        assert_eq!(hash["payload"]["sha256"].as_str().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn setup_records_w_length() {
+        let line = transfer_line(AuditPayloads::Hash);
+        assert_eq!(line["setup"]["w_length"], 18);
+        assert_eq!(line["setup"]["w_value"], 0x0100);
+    }
+}
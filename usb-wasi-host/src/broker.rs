@@ -0,0 +1,308 @@
+//! Privilege separation: a small broker process that runs as root, checks requests against the
+//! device policy and passes the opened device node over a Unix socket (SCM_RIGHTS), so the host
+//! that runs the guest can do without any privileges.
+//!
+//! The wire protocol is a 2-byte request `[bus, address]` answered by a little-endian `i32`
+//! libusb status, which carries the file descriptor of `/dev/bus/usb/BBB/DDD` when it is 0.
+//!
+//! Devices and clients are both governed by the policy file: only the devices it lists are
+//! handed out, and only to members of its group. The socket is created with mode 0660 and
+//! owned by that group, and the broker additionally checks the credentials of every peer
+//! (SO_PEERCRED), so a socket in a world-writable directory does not open it to other users.
+
+use std::ffi::{CStr, CString};
+use std::fs::{self, OpenOptions, Permissions};
+use std::io::{self, ErrorKind, Read, Write};
+use std::mem;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use libusb1_sys::{libusb_context, libusb_exit, libusb_init, libusb_unref_device};
+use log::{info, warn};
+
+use crate::component::usb::errors::LibusbError;
+use crate::enumerate::enumerate_devices;
+use crate::policy::{AllowedUSBDevices, PolicyFile};
+
+/// Connection of an unprivileged host to a broker started with [`serve`].
+pub struct BrokerClient {
+    socket: Mutex<UnixStream>,
+}
+
+impl BrokerClient {
+    pub fn connect(path: &Path) -> io::Result<Self> {
+        Ok(Self { socket: Mutex::new(UnixStream::connect(path)?) })
+    }
+
+    /// Ask the broker for the device node of the device at `address` on `bus`.
+    /// Fails with access if the policy of the broker denies the device.
+    pub fn open(&self, bus: u8, address: u8) -> Result<OwnedFd, LibusbError> {
+        let mut socket = self.socket.lock().unwrap();
+        let reply = socket.write_all(&[bus, address]).and_then(|()| recv_reply(&socket));
+        let (status, fd) = reply.map_err(|e| {
+            warn!("Device broker did not answer for {:03}:{:03}: {}", bus, address, e);
+            LibusbError::Io
+        })?;
+        LibusbError::check(status)?;
+        fd.ok_or(LibusbError::Other)
+    }
+}
+
+/// Hand out device nodes on the Unix socket at `path` until the process is stopped.
+/// Only members of the group of `policy` may connect, and every request is checked
+/// against the devices of `policy` before the node is opened.
+pub fn serve(path: &Path, policy: &PolicyFile) -> io::Result<()> {
+    let gid = group_id(&policy.group)?;
+    // a socket left behind by an earlier broker would make bind fail
+    if fs::remove_file(path).is_ok() {
+        info!("Removed stale socket {}", path.display());
+    }
+    // nobody else may connect before the group and mode are set
+    let mask = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(mask) };
+    let listener = listener?;
+    std::os::unix::fs::chown(path, None, Some(gid))?;
+    fs::set_permissions(path, Permissions::from_mode(0o660))?;
+    info!("Device broker listening on {} for group {}", path.display(), policy.group);
+    let allowed_usbdevices = Arc::new(policy.allowed_usbdevices());
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("Failed to accept a broker connection: {}", e);
+                continue;
+            }
+        };
+        match peer_allowed(&stream, gid) {
+            Ok(true) => {
+                let allowed_usbdevices = allowed_usbdevices.clone();
+                thread::spawn(move || handle_client(stream, &allowed_usbdevices));
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to get the credentials of a broker client: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Whether the process on the other end of `stream` runs as root or as a member of `gid`.
+fn peer_allowed(stream: &UnixStream, gid: libc::gid_t) -> io::Result<bool> {
+    let mut cred: libc::ucred = unsafe { mem::zeroed() };
+    let mut len = mem::size_of::<libc::ucred>() as libc::socklen_t;
+    let res = unsafe {
+        libc::getsockopt(
+            stream.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_PEERCRED,
+            (&mut cred as *mut libc::ucred).cast(),
+            &mut len,
+        )
+    };
+    if res < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if cred.uid == 0 || cred.gid == gid || member_of(cred.uid, gid) {
+        return Ok(true);
+    }
+    warn!("Refused broker client pid {} uid {}, it is not in the policy group", cred.pid, cred.uid);
+    Ok(false)
+}
+
+/// Whether `gid` is among the groups of the user `uid`, the supplementary groups
+/// of the peer process itself are not part of its credentials.
+fn member_of(uid: libc::uid_t, gid: libc::gid_t) -> bool {
+    unsafe {
+        // connections are accepted on a single thread, so the static entry is not shared
+        let entry = libc::getpwuid(uid);
+        if entry.is_null() {
+            return false;
+        }
+        let name = CStr::from_ptr((*entry).pw_name).to_owned();
+        let primary = (*entry).pw_gid;
+        let mut groups: Vec<libc::gid_t> = vec![0; 32];
+        loop {
+            let mut count = groups.len() as libc::c_int;
+            if libc::getgrouplist(name.as_ptr(), primary, groups.as_mut_ptr(), &mut count) >= 0 {
+                return groups[..count as usize].contains(&gid);
+            }
+            // the list was too small, count now holds the number of groups
+            groups.resize((count as usize).max(groups.len() * 2), 0);
+        }
+    }
+}
+
+fn group_id(group: &str) -> io::Result<libc::gid_t> {
+    let name = CString::new(group).map_err(|e| io::Error::new(ErrorKind::InvalidInput, e))?;
+    let entry = unsafe { libc::getgrnam(name.as_ptr()) };
+    if entry.is_null() {
+        return Err(io::Error::new(ErrorKind::NotFound, format!("group {} does not exist", group)));
+    }
+    Ok(unsafe { (*entry).gr_gid })
+}
+
+fn handle_client(mut stream: UnixStream, allowed_usbdevices: &AllowedUSBDevices) {
+    let mut request = [0u8; 2];
+    while stream.read_exact(&mut request).is_ok() {
+        let [bus, address] = request;
+        let reply = match open_device_node(bus, address, allowed_usbdevices) {
+            Ok(node) => {
+                info!("Passing device {:03}:{:03} to the host", bus, address);
+                send_reply(&stream, 0, Some(node.as_fd()))
+            }
+            Err(e) => {
+                warn!("Refused device {:03}:{:03}: {}", bus, address, e);
+                send_reply(&stream, e.to_raw(), None)
+            }
+        };
+        if let Err(e) = reply {
+            warn!("Failed to answer the host: {}", e);
+            return;
+        }
+    }
+}
+
+fn open_device_node(bus: u8, address: u8, allowed_usbdevices: &AllowedUSBDevices) -> Result<OwnedFd, LibusbError> {
+    if !device_allowed(bus, address, allowed_usbdevices)? {
+        return Err(LibusbError::Access);
+    }
+    let path = format!("/dev/bus/usb/{:03}/{:03}", bus, address);
+    let node = OpenOptions::new().read(true).write(true).open(&path).map_err(|e| match e.kind() {
+        ErrorKind::NotFound => LibusbError::NoDevice,
+        ErrorKind::PermissionDenied => LibusbError::Access,
+        _ => LibusbError::Io,
+    })?;
+    Ok(node.into())
+}
+
+/// Whether the device at `address` on `bus` passes the policy. Requests only name the node,
+/// so the device is looked up by enumerating, which does not need the node to be opened.
+fn device_allowed(bus: u8, address: u8, allowed_usbdevices: &AllowedUSBDevices) -> Result<bool, LibusbError> {
+    unsafe {
+        let mut ctx: *mut libusb_context = ptr::null_mut();
+        LibusbError::check(libusb_init(&mut ctx))?;
+        let allowed = enumerate_devices(ctx, allowed_usbdevices).map(|(devices, _)| {
+            let mut allowed = false;
+            for device in devices {
+                allowed |= device.location.bus_number == bus && device.location.device_address == address;
+                libusb_unref_device(device.device);
+            }
+            allowed
+        });
+        libusb_exit(ctx);
+        allowed
+    }
+}
+
+// large enough and aligned for one control message carrying a single file descriptor
+type ControlBuffer = [u64; 4];
+
+fn send_reply(stream: &UnixStream, status: i32, fd: Option<BorrowedFd>) -> io::Result<()> {
+    let mut payload = status.to_le_bytes();
+    let mut iov = libc::iovec { iov_base: payload.as_mut_ptr().cast(), iov_len: payload.len() };
+    let mut control: ControlBuffer = [0; 4];
+    unsafe {
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        if let Some(fd) = fd {
+            msg.msg_control = control.as_mut_ptr().cast();
+            msg.msg_controllen = libc::CMSG_SPACE(mem::size_of::<RawFd>() as u32) as _;
+            let cmsg = libc::CMSG_FIRSTHDR(&msg);
+            (*cmsg).cmsg_level = libc::SOL_SOCKET;
+            (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(mem::size_of::<RawFd>() as u32) as _;
+            ptr::write_unaligned(libc::CMSG_DATA(cmsg).cast::<RawFd>(), fd.as_raw_fd());
+        }
+        let sent = libc::sendmsg(stream.as_raw_fd(), &msg, libc::MSG_NOSIGNAL);
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if sent as usize != payload.len() {
+            return Err(io::Error::new(ErrorKind::WriteZero, "short write on broker socket"));
+        }
+    }
+    Ok(())
+}
+
+fn recv_reply(stream: &UnixStream) -> io::Result<(i32, Option<OwnedFd>)> {
+    let mut payload = [0u8; 4];
+    let mut iov = libc::iovec { iov_base: payload.as_mut_ptr().cast(), iov_len: payload.len() };
+    let mut control: ControlBuffer = [0; 4];
+    let mut fd = None;
+    unsafe {
+        let mut msg: libc::msghdr = mem::zeroed();
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = mem::size_of::<ControlBuffer>() as _;
+        let received = libc::recvmsg(stream.as_raw_fd(), &mut msg, libc::MSG_CMSG_CLOEXEC);
+        if received < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // take ownership first, so a descriptor that came with a broken reply is closed again
+        let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+        while !cmsg.is_null() {
+            if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                let raw = ptr::read_unaligned(libc::CMSG_DATA(cmsg).cast::<RawFd>());
+                fd = Some(OwnedFd::from_raw_fd(raw));
+            }
+            cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+        }
+        if received as usize != payload.len() {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "broker closed the connection"));
+        }
+        if msg.msg_flags & libc::MSG_CTRUNC != 0 {
+            return Err(io::Error::new(ErrorKind::InvalidData, "file descriptor was truncated"));
+        }
+    }
+    Ok((i32::from_le_bytes(payload), fd))
+}
+
+/// Give up all capabilities of the process for good, meant to be called once the broker
+/// connection is up. A host started as root also loses the way back through exec.
+pub fn drop_capabilities() -> io::Result<()> {
+    #[repr(C)]
+    struct CapHeader {
+        version: u32,
+        pid: libc::c_int,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct CapData {
+        effective: u32,
+        permitted: u32,
+        inheritable: u32,
+    }
+    const LINUX_CAPABILITY_VERSION_3: u32 = 0x2008_0522;
+
+    unsafe {
+        // both need CAP_SETPCAP and fail harmlessly for a host that was never privileged
+        let securebits = libc::SECBIT_NOROOT
+            | libc::SECBIT_NOROOT_LOCKED
+            | libc::SECBIT_NO_SETUID_FIXUP
+            | libc::SECBIT_NO_SETUID_FIXUP_LOCKED;
+        libc::prctl(libc::PR_SET_SECUREBITS, securebits as libc::c_ulong, 0, 0, 0);
+        for cap in 0..64 {
+            libc::prctl(libc::PR_CAPBSET_DROP, cap as libc::c_ulong, 0, 0, 0);
+        }
+
+        if libc::prctl(libc::PR_CAP_AMBIENT, libc::PR_CAP_AMBIENT_CLEAR_ALL as libc::c_ulong, 0, 0, 0) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let header = CapHeader { version: LINUX_CAPABILITY_VERSION_3, pid: 0 };
+        let data = [CapData { effective: 0, permitted: 0, inheritable: 0 }; 2];
+        if libc::syscall(libc::SYS_capset, &header, data.as_ptr()) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1 as libc::c_ulong, 0, 0, 0) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
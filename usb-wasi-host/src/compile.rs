@@ -0,0 +1,145 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use log::{debug, info, warn};
+use sha2::{Digest, Sha256};
+use wasmtime::component::Component;
+use wasmtime::{Engine, Error, Precompiled};
+
+use crate::engine_config;
+use crate::limits::EngineLimits;
+
+#[derive(Args)]
+pub struct CompileArgs {
+    /// Component to compile
+    input: PathBuf,
+
+    /// Where to write the compiled component, defaults to the input with a .cwasm extension
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+
+    /// Target triple to compile for, defaults to the host.
+    /// 32-bit ARM targets such as armv7-unknown-linux-musleabihf run on the Pulley interpreter
+    /// and are compiled for pulley32, which needs a host built with the cross-compile feature.
+    #[arg(long)]
+    target: Option<String>,
+
+    /// Compile for an engine with fuel enabled, needed to run with --fuel
+    #[arg(long)]
+    consume_fuel: bool,
+
+    /// Compile for an engine with epoch interruption, needed to run with --epoch-deadline or --timeout
+    #[arg(long)]
+    epoch_interruption: bool,
+}
+
+pub fn run(args: &CompileArgs) -> Result<(), Error> {
+    let limits = EngineLimits {
+        fuel: args.consume_fuel,
+        epoch: args.epoch_interruption,
+    };
+    let mut config = engine_config(limits);
+    if let Some(target) = &args.target {
+        config.target(wasmtime_target(target))?;
+    }
+    let engine = Engine::new(&config)?;
+    let output = args.output.clone().unwrap_or_else(|| args.input.with_extension("cwasm"));
+    info!("Compiling {} to {}", args.input.display(), output.display());
+    let compiled = engine.precompile_component(&std::fs::read(&args.input)?)?;
+    std::fs::write(&output, compiled)?;
+    Ok(())
+}
+
+/// Wasmtime has no native backend for 32-bit ARM, it runs code compiled for Pulley there.
+fn wasmtime_target(triple: &str) -> &str {
+    // arm64 is the Apple name of aarch64
+    let arm32 = triple.starts_with("arm") && !triple.starts_with("arm64");
+    if arm32 || triple.starts_with("thumb") {
+        "pulley32"
+    } else {
+        triple
+    }
+}
+
+/// Default location of the compiled-component cache.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".cache"),
+    };
+    Some(base.join("usb-wasi-host"))
+}
+
+/// Load a component, either an already compiled `.cwasm` or a `.wasm` that is compiled
+/// and stored in `cache_dir`. Cache entries are keyed by the SHA-256 of the component
+/// and the compilation settings of `engine`, so a changed component or engine config
+/// never picks up a stale entry.
+pub fn load_component(engine: &Engine, path: &Path, cache_dir: Option<&Path>) -> Result<Component, Error> {
+    if let Some(Precompiled::Component) = engine.detect_precompiled_file(path)? {
+        debug!("Loading precompiled component {}", path.display());
+        // precompiled components are trusted just like the components the host compiles itself
+        return unsafe { Component::deserialize_file(engine, path) };
+    }
+    let Some(cache_dir) = cache_dir else {
+        return Component::from_file(engine, path);
+    };
+
+    let bytes = std::fs::read(path)?;
+    let mut engine_hash = DefaultHasher::new();
+    engine.precompile_compatibility_hash().hash(&mut engine_hash);
+    let entry = cache_dir.join(format!(
+        "{}-{:016x}.cwasm",
+        hex(&Sha256::digest(&bytes)),
+        engine_hash.finish()
+    ));
+    if entry.exists() {
+        debug!("Loading {} from cache entry {}", path.display(), entry.display());
+        match unsafe { Component::deserialize_file(engine, &entry) } {
+            Ok(component) => return Ok(component),
+            Err(e) => warn!("Ignoring unusable cache entry {}: {:?}", entry.display(), e),
+        }
+    }
+
+    info!("Compiling {}", path.display());
+    let compiled = engine.precompile_component(&bytes)?;
+    if let Err(e) = store_entry(&entry, &compiled) {
+        warn!("Failed to write cache entry {}: {}", entry.display(), e);
+    }
+    unsafe { Component::deserialize(engine, &compiled) }
+}
+
+fn store_entry(entry: &Path, compiled: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = entry.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first so that concurrent hosts never read a partial entry
+    let tmp = entry.with_extension(format!("tmp{}", std::process::id()));
+    std::fs::write(&tmp, compiled)?;
+    std::fs::rename(&tmp, entry)
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arm32_targets_run_on_pulley() {
+        assert_eq!(wasmtime_target("armv7-unknown-linux-musleabihf"), "pulley32");
+        assert_eq!(wasmtime_target("arm-unknown-linux-gnueabi"), "pulley32");
+        assert_eq!(wasmtime_target("thumbv7neon-unknown-linux-gnueabihf"), "pulley32");
+    }
+
+    #[test]
+    fn other_targets_are_kept() {
+        assert_eq!(wasmtime_target("aarch64-unknown-linux-gnu"), "aarch64-unknown-linux-gnu");
+        assert_eq!(wasmtime_target("arm64-apple-darwin"), "arm64-apple-darwin");
+        assert_eq!(wasmtime_target("x86_64-unknown-linux-musl"), "x86_64-unknown-linux-musl");
+        assert_eq!(wasmtime_target("riscv64gc-unknown-linux-gnu"), "riscv64gc-unknown-linux-gnu");
+    }
+}
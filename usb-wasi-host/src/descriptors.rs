@@ -0,0 +1,600 @@
+use libusb1_sys::{libusb_bos_descriptor, libusb_bos_dev_capability_descriptor, libusb_config_descriptor, libusb_container_id_descriptor, libusb_device_descriptor, libusb_endpoint_descriptor, libusb_free_container_id_descriptor, libusb_free_ss_endpoint_companion_descriptor, libusb_free_ss_usb_device_capability_descriptor, libusb_free_usb_2_0_extension_descriptor, libusb_get_container_id_descriptor, libusb_get_ss_endpoint_companion_descriptor, libusb_get_ss_usb_device_capability_descriptor, libusb_get_usb_2_0_extension_descriptor, libusb_ss_endpoint_companion_descriptor, libusb_usb_2_0_extension_descriptor};
+
+use crate::component::usb::descriptors::{BosDescriptor, ConfigurationDescriptor, DeviceCapability, DeviceDescriptor, EndpointDescriptor, InterfaceDescriptor, MsOs20DescriptorSet, PlatformCapability, PlatformKind, SsEndpointCompanion, SuperspeedCapability, SuperspeedPlusCapability, UnknownCapability, Usb2ExtensionCapability, UsbInterface, WebusbPlatform};
+use crate::component::usb::errors::LibusbError;
+
+const LIBUSB_DT_STRING: u8 = 0x03;
+
+// bDevCapabilityType values of the capabilities in a BOS descriptor
+const USB_2_0_EXTENSION: u8 = 0x02;
+const SS_USB_DEVICE_CAPABILITY: u8 = 0x03;
+const CONTAINER_ID: u8 = 0x04;
+const PLATFORM: u8 = 0x05;
+const SUPERSPEED_PLUS: u8 = 0x0a;
+
+// platform capability UUIDs in the byte order they appear on the wire
+const WEBUSB_UUID: [u8; 16] = [
+    0x38, 0xb6, 0x08, 0x34, 0xa9, 0x09, 0xa0, 0x47, 0x8b, 0xfd, 0xa0, 0x76, 0x88, 0x15, 0xb6, 0x65,
+];
+const MS_OS_20_UUID: [u8; 16] = [
+    0xdf, 0x60, 0xdd, 0xd8, 0x89, 0x45, 0xc7, 0x4c, 0x9c, 0xd2, 0x65, 0x9d, 0x9e, 0x64, 0x8a, 0x9f,
+];
+
+/// `libusb_bos_descriptor` as libusb lays it out. libusb1-sys declares `dev_capability`
+/// as an array of descriptors, while libusb stores pointers to them.
+#[allow(non_snake_case)]
+#[repr(C)]
+struct RawBosDescriptor {
+    bLength: u8,
+    bDescriptorType: u8,
+    wTotalLength: u16,
+    bNumDeviceCaps: u8,
+    dev_capability: [*const libusb_bos_dev_capability_descriptor; 0],
+}
+
+/// `libusb_ss_usb_device_capability_descriptor` as libusb lays it out,
+/// libusb1-sys declares `bU2DevExitLat` as a u8 instead of a u16.
+#[allow(non_snake_case)]
+#[repr(C)]
+struct RawSsUsbDeviceCapability {
+    bLength: u8,
+    bDescriptorType: u8,
+    bDevCapabilityType: u8,
+    bmAttributes: u8,
+    wSpeedSupported: u16,
+    bFunctionalitySupport: u8,
+    bU1DevExitLat: u8,
+    bU2DevExitLat: u16,
+}
+
+/// Convert a libusb configuration descriptor into its WIT representation.
+///
+/// # Safety
+/// `raw_descriptor` must come from libusb and not have been freed yet.
+pub unsafe fn generate_config_descriptor(raw_descriptor: &libusb_config_descriptor) -> ConfigurationDescriptor {
+    let mut interfaces: Vec<UsbInterface> = Vec::new();
+    for i in 0..raw_descriptor.bNumInterfaces {
+        let interface = &*raw_descriptor.interface.wrapping_add(i as usize);
+        let mut alt_settings: Vec<InterfaceDescriptor> = Vec::new();
+        for j in 0..interface.num_altsetting {
+            let mut endpoints: Vec<EndpointDescriptor> = Vec::new();
+            let alt_setting = &*interface.altsetting.wrapping_add(j as usize);
+            for k in 0..alt_setting.bNumEndpoints {
+                let endpoint = &*alt_setting.endpoint.wrapping_add(k as usize);
+                let endpoint_desc = EndpointDescriptor {
+                    length: endpoint.bLength,
+                    descriptor_type: endpoint.bDescriptorType,
+                    endpoint_address: endpoint.bEndpointAddress,
+                    attributes: endpoint.bmAttributes,
+                    max_packet_size: endpoint.wMaxPacketSize,
+                    interval: endpoint.bInterval,
+                    refresh: endpoint.bRefresh,
+                    synch_address: endpoint.bSynchAddress,
+                    extra: extra_bytes(endpoint.extra, endpoint.extra_length),
+                    ss_endpoint_companion: ss_endpoint_companion(endpoint),
+                };
+                endpoints.push(endpoint_desc);
+            }
+            let interface_desc = InterfaceDescriptor {
+                length: alt_setting.bLength,
+                descriptor_type: alt_setting.bDescriptorType,
+                interface_number: alt_setting.bInterfaceNumber,
+                alternate_setting: alt_setting.bAlternateSetting,
+                interface_class: alt_setting.bInterfaceClass,
+                interface_subclass: alt_setting.bInterfaceSubClass,
+                interface_protocol: alt_setting.bInterfaceProtocol,
+                interface_index: alt_setting.iInterface,
+                endpoints,
+                extra: extra_bytes(alt_setting.extra, alt_setting.extra_length),
+            };
+            alt_settings.push(interface_desc);
+        }
+        // libusb groups the alternate settings by interface, so they all share one number
+        let interface_number = alt_settings.first().map_or(i, |alt_setting| alt_setting.interface_number);
+        interfaces.push(UsbInterface { interface_number, alt_settings });
+    }
+
+    ConfigurationDescriptor {
+        length: raw_descriptor.bLength,
+        descriptor_type: raw_descriptor.bDescriptorType,
+        total_length: raw_descriptor.wTotalLength,
+        configuration_value: raw_descriptor.bConfigurationValue,
+        configuration_index: raw_descriptor.iConfiguration,
+        attributes: raw_descriptor.bmAttributes,
+        max_power: raw_descriptor.bMaxPower,
+        interfaces,
+        extra: extra_bytes(raw_descriptor.extra, raw_descriptor.extra_length),
+    }
+}
+
+/// Copy the descriptors libusb did not parse itself out of a descriptor.
+///
+/// # Safety
+/// `extra` must point to `extra_length` readable bytes or be null.
+unsafe fn extra_bytes(extra: *const u8, extra_length: i32) -> Vec<u8> {
+    if extra.is_null() || extra_length <= 0 {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(extra, extra_length as usize).to_vec()
+}
+
+/// Parse the SuperSpeed endpoint companion out of the extra bytes of `endpoint`, if it has one.
+///
+/// # Safety
+/// `endpoint` must come from libusb and not have been freed yet.
+unsafe fn ss_endpoint_companion(endpoint: &libusb_endpoint_descriptor) -> Option<SsEndpointCompanion> {
+    let mut raw: *const libusb_ss_endpoint_companion_descriptor = std::ptr::null();
+    // the context is only used for logging, libusb falls back to the default one
+    if libusb_get_ss_endpoint_companion_descriptor(std::ptr::null_mut(), endpoint, &mut raw) != 0 {
+        return None;
+    }
+    let companion = &*raw;
+    let max_streams = match (endpoint.bmAttributes & 0x03, companion.bmAttributes & 0x1f) {
+        (0x02, streams) if streams > 0 => 1 << streams,
+        _ => 0,
+    };
+    let descriptor = SsEndpointCompanion {
+        length: companion.bLength,
+        descriptor_type: companion.bDescriptorType,
+        max_burst: companion.bMaxBurst,
+        attributes: companion.bmAttributes,
+        bytes_per_interval: companion.wBytesPerInterval,
+        max_streams,
+    };
+    libusb_free_ss_endpoint_companion_descriptor(raw as *mut _);
+    Some(descriptor)
+}
+
+/// Convert a libusb BOS descriptor into its WIT representation.
+///
+/// # Safety
+/// `raw_descriptor` must come from `libusb_get_bos_descriptor` and not have been freed yet.
+pub unsafe fn generate_bos_descriptor(raw_descriptor: *const libusb_bos_descriptor) -> BosDescriptor {
+    let bos = &*(raw_descriptor as *const RawBosDescriptor);
+    let capabilities = std::slice::from_raw_parts(bos.dev_capability.as_ptr(), bos.bNumDeviceCaps as usize)
+        .iter()
+        .map(|&capability| device_capability(capability as *mut _))
+        .collect();
+    BosDescriptor {
+        length: bos.bLength,
+        descriptor_type: bos.bDescriptorType,
+        total_length: bos.wTotalLength,
+        capabilities,
+    }
+}
+
+/// Decode one device capability. The fixed-layout ones go through the libusb parsers,
+/// SuperSpeedPlus and platform capabilities are decoded from the capability data.
+///
+/// # Safety
+/// `capability` must point to a capability of a BOS descriptor that has not been freed yet.
+unsafe fn device_capability(capability: *mut libusb_bos_dev_capability_descriptor) -> DeviceCapability {
+    let header = &*capability;
+    let data = std::slice::from_raw_parts(
+        header.dev_capability_data.as_ptr(),
+        header.bLength.saturating_sub(3) as usize,
+    );
+    match header.bDevCapabilityType {
+        USB_2_0_EXTENSION => {
+            let mut raw: *const libusb_usb_2_0_extension_descriptor = std::ptr::null();
+            if libusb_get_usb_2_0_extension_descriptor(std::ptr::null_mut(), capability, &mut raw) == 0 {
+                let attributes = (*raw).bmAttributes;
+                libusb_free_usb_2_0_extension_descriptor(raw as *mut _);
+                return DeviceCapability::Usb2Extension(Usb2ExtensionCapability {
+                    attributes,
+                    lpm_supported: attributes & 0x02 != 0,
+                });
+            }
+        }
+        SS_USB_DEVICE_CAPABILITY => {
+            let mut raw = std::ptr::null();
+            if libusb_get_ss_usb_device_capability_descriptor(std::ptr::null_mut(), capability, &mut raw) == 0 {
+                let ss = &*(raw as *const RawSsUsbDeviceCapability);
+                let descriptor = SuperspeedCapability {
+                    attributes: ss.bmAttributes,
+                    speeds_supported: ss.wSpeedSupported,
+                    functionality_support: ss.bFunctionalitySupport,
+                    u1_exit_latency: ss.bU1DevExitLat,
+                    u2_exit_latency: ss.bU2DevExitLat,
+                };
+                libusb_free_ss_usb_device_capability_descriptor(raw as *mut _);
+                return DeviceCapability::Superspeed(descriptor);
+            }
+        }
+        CONTAINER_ID => {
+            let mut raw: *const libusb_container_id_descriptor = std::ptr::null();
+            if libusb_get_container_id_descriptor(std::ptr::null_mut(), capability, &mut raw) == 0 {
+                let container_id = (*raw).ContainerId.to_vec();
+                libusb_free_container_id_descriptor(raw as *mut _);
+                return DeviceCapability::ContainerId(container_id);
+            }
+        }
+        SUPERSPEED_PLUS => {
+            if let Some(capability) = superspeed_plus_capability(data) {
+                return DeviceCapability::SuperspeedPlus(capability);
+            }
+        }
+        PLATFORM => {
+            if let Some(capability) = platform_capability(data) {
+                return DeviceCapability::Platform(capability);
+            }
+        }
+        _ => {}
+    }
+    DeviceCapability::Other(UnknownCapability {
+        capability_type: header.bDevCapabilityType,
+        data: data.to_vec(),
+    })
+}
+
+/// Decode the data of a SuperSpeedPlus capability, None if it is truncated.
+fn superspeed_plus_capability(data: &[u8]) -> Option<SuperspeedPlusCapability> {
+    // bReserved, bmAttributes, wFunctionalitySupport, wReserved
+    let header = data.get(..9)?;
+    let attributes = u32::from_le_bytes(header[1..5].try_into().ok()?);
+    let functionality_support = u16::from_le_bytes([header[5], header[6]]);
+    // SublinkSpeedAttrCount is the number of attributes minus one
+    let count = (attributes & 0x1f) as usize + 1;
+    let sublink_speed_attributes = data[9..]
+        .chunks_exact(4)
+        .take(count)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    Some(SuperspeedPlusCapability { attributes, functionality_support, sublink_speed_attributes })
+}
+
+/// Decode the data of a platform capability, None if it is truncated.
+fn platform_capability(data: &[u8]) -> Option<PlatformCapability> {
+    // bReserved, PlatformCapabilityUUID
+    let uuid = data.get(1..17)?;
+    let capability_data = &data[17..];
+    let kind = if uuid == WEBUSB_UUID && capability_data.len() >= 4 {
+        PlatformKind::Webusb(WebusbPlatform {
+            version_bcd: u16::from_le_bytes([capability_data[0], capability_data[1]]),
+            vendor_code: capability_data[2],
+            landing_page_index: capability_data[3],
+        })
+    } else if uuid == MS_OS_20_UUID {
+        PlatformKind::MsOs20(
+            capability_data
+                .chunks_exact(8)
+                .map(|set| MsOs20DescriptorSet {
+                    windows_version: u32::from_le_bytes([set[0], set[1], set[2], set[3]]),
+                    total_length: u16::from_le_bytes([set[4], set[5]]),
+                    vendor_code: set[6],
+                    alt_enum_code: set[7],
+                })
+                .collect(),
+        )
+    } else {
+        PlatformKind::Unknown
+    };
+    Some(PlatformCapability { uuid: uuid.to_vec(), data: capability_data.to_vec(), kind })
+}
+
+pub fn device_descriptor_from_raw(device_desc: &libusb_device_descriptor) -> DeviceDescriptor {
+    DeviceDescriptor {
+        length: device_desc.bLength,
+        descriptor_type: device_desc.bDescriptorType,
+        usb_version_bcd: device_desc.bcdUSB,
+        device_class: device_desc.bDeviceClass,
+        device_subclass: device_desc.bDeviceSubClass,
+        device_protocol: device_desc.bDeviceProtocol,
+        max_packet_size0: device_desc.bMaxPacketSize0,
+        vendor_id: device_desc.idVendor,
+        product_id: device_desc.idProduct,
+        device_version_bcd: device_desc.bcdDevice,
+        manufacturer_index: device_desc.iManufacturer,
+        product_index: device_desc.iProduct,
+        serial_number_index: device_desc.iSerialNumber,
+        num_configurations: device_desc.bNumConfigurations,
+    }
+}
+
+/// Code units of a string descriptor, the header is validated and stripped.
+fn string_descriptor_units(data: &[u8]) -> Result<impl Iterator<Item = u16> + '_, LibusbError> {
+    if data.len() < 2 || data[0] < 2 || data[1] != LIBUSB_DT_STRING {
+        return Err(LibusbError::Io);
+    }
+    // bLength can be shorter than what the device sent, never trust it to be longer
+    let len = (data[0] as usize).min(data.len());
+    Ok(data[2..len].chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])))
+}
+
+/// Decode a string descriptor as returned by GET_DESCRIPTOR(STRING) from UTF-16LE.
+/// Unpaired surrogates are replaced rather than failing the whole string.
+pub fn decode_string_descriptor(data: &[u8]) -> Result<String, LibusbError> {
+    Ok(char::decode_utf16(string_descriptor_units(data)?)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect())
+}
+
+/// Decode string descriptor 0, which lists the supported language IDs.
+pub fn decode_language_ids(data: &[u8]) -> Result<Vec<u16>, LibusbError> {
+    Ok(string_descriptor_units(data)?.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libusb1_sys::{libusb_interface, libusb_interface_descriptor};
+
+    fn endpoint(address: u8, attributes: u8, extra: &[u8]) -> libusb_endpoint_descriptor {
+        libusb_endpoint_descriptor {
+            bLength: 7,
+            bDescriptorType: 0x05,
+            bEndpointAddress: address,
+            bmAttributes: attributes,
+            wMaxPacketSize: 512,
+            bInterval: 1,
+            bRefresh: 0,
+            bSynchAddress: 0,
+            extra: extra.as_ptr(),
+            extra_length: extra.len() as i32,
+        }
+    }
+
+    fn alt_setting(
+        number: u8,
+        alternate: u8,
+        class: u8,
+        endpoints: &[libusb_endpoint_descriptor],
+        extra: &[u8],
+    ) -> libusb_interface_descriptor {
+        libusb_interface_descriptor {
+            bLength: 9,
+            bDescriptorType: 0x04,
+            bInterfaceNumber: number,
+            bAlternateSetting: alternate,
+            bNumEndpoints: endpoints.len() as u8,
+            bInterfaceClass: class,
+            bInterfaceSubClass: 0,
+            bInterfaceProtocol: 0,
+            iInterface: 0,
+            endpoint: endpoints.as_ptr(),
+            extra: extra.as_ptr(),
+            extra_length: extra.len() as i32,
+        }
+    }
+
+    #[test]
+    fn config_descriptor_keeps_alt_settings_and_extra_bytes() {
+        // interface association covering both interfaces
+        let iad = [8, 0x0b, 0, 2, 0x0e, 0x03, 0, 0];
+        let hid = [9, 0x21, 0x11, 0x01, 0, 1, 0x22, 0x3f, 0];
+        let interrupt = [endpoint(0x81, 0x03, &[])];
+        let iso = [endpoint(0x82, 0x05, &[])];
+        let control_settings = [alt_setting(0, 0, 0x03, &interrupt, &hid)];
+        // a streaming interface that only gets bandwidth in its second alternate setting
+        let streaming_settings = [alt_setting(3, 0, 0x0e, &[], &[]), alt_setting(3, 1, 0x0e, &iso, &[])];
+        let interfaces = [
+            libusb_interface { altsetting: control_settings.as_ptr(), num_altsetting: 1 },
+            libusb_interface { altsetting: streaming_settings.as_ptr(), num_altsetting: 2 },
+        ];
+        let raw = libusb_config_descriptor {
+            bLength: 9,
+            bDescriptorType: 0x02,
+            wTotalLength: 64,
+            bNumInterfaces: 2,
+            bConfigurationValue: 1,
+            iConfiguration: 0,
+            bmAttributes: 0x80,
+            bMaxPower: 250,
+            interface: interfaces.as_ptr(),
+            extra: iad.as_ptr(),
+            extra_length: iad.len() as i32,
+        };
+
+        let config = unsafe { generate_config_descriptor(&raw) };
+        assert_eq!(config.extra, iad);
+        assert_eq!(config.total_length, 64);
+        assert_eq!(config.interfaces.len(), 2);
+        let control = &config.interfaces[0];
+        assert_eq!(control.interface_number, 0);
+        assert_eq!(control.alt_settings.len(), 1);
+        assert_eq!(control.alt_settings[0].extra, hid);
+        assert_eq!(control.alt_settings[0].endpoints[0].endpoint_address, 0x81);
+        // the number comes from the descriptors, not the position in the configuration
+        let streaming = &config.interfaces[1];
+        assert_eq!(streaming.interface_number, 3);
+        let alternates: Vec<u8> = streaming.alt_settings.iter().map(|alt| alt.alternate_setting).collect();
+        assert_eq!(alternates, [0, 1]);
+        assert!(streaming.alt_settings[0].endpoints.is_empty());
+        assert_eq!(streaming.alt_settings[1].endpoints[0].endpoint_address, 0x82);
+        assert!(streaming.alt_settings[1].endpoints[0].extra.is_empty());
+    }
+
+    #[test]
+    fn extra_bytes_of_nothing_are_empty() {
+        unsafe {
+            assert!(extra_bytes(std::ptr::null(), 4).is_empty());
+            assert!(extra_bytes([1u8].as_ptr(), 0).is_empty());
+            assert!(extra_bytes([1u8].as_ptr(), -1).is_empty());
+        }
+    }
+
+    #[test]
+    fn ss_endpoint_companion_reports_streams_of_bulk_endpoints() {
+        // bMaxBurst 15, 2^4 streams
+        let companion = [6, 0x30, 15, 4, 0, 0];
+        let bulk = endpoint(0x81, 0x02, &companion);
+        let decoded = unsafe { ss_endpoint_companion(&bulk) }.unwrap();
+        assert_eq!((decoded.max_burst, decoded.attributes, decoded.max_streams), (15, 4, 16));
+        // the same attributes mean Mult for an isochronous endpoint
+        let iso = endpoint(0x81, 0x05, &companion);
+        assert_eq!(unsafe { ss_endpoint_companion(&iso) }.unwrap().max_streams, 0);
+        assert!(unsafe { ss_endpoint_companion(&endpoint(0x81, 0x02, &[])) }.is_none());
+    }
+
+    /// `libusb_bos_descriptor` with room for the capability pointers.
+    #[allow(non_snake_case)]
+    #[repr(C)]
+    struct TestBos<const N: usize> {
+        bLength: u8,
+        bDescriptorType: u8,
+        wTotalLength: u16,
+        bNumDeviceCaps: u8,
+        dev_capability: [*const libusb_bos_dev_capability_descriptor; N],
+    }
+
+    fn capability(capability_type: u8, data: &[u8]) -> Vec<u8> {
+        let mut descriptor = vec![3 + data.len() as u8, 0x10, capability_type];
+        descriptor.extend_from_slice(data);
+        descriptor
+    }
+
+    #[test]
+    fn bos_descriptor_decodes_each_capability() {
+        let usb2 = capability(USB_2_0_EXTENSION, &[0x06, 0, 0, 0]);
+        // U2 exit latency 0x07ff, which does not fit the u8 of libusb1-sys
+        let superspeed = capability(SS_USB_DEVICE_CAPABILITY, &[0x02, 0x0e, 0x00, 0x01, 0x0a, 0xff, 0x07]);
+        let container_id = capability(CONTAINER_ID, &[0; 1].into_iter().chain(1..=16).collect::<Vec<u8>>());
+        let unknown = capability(0x0d, &[1, 2, 3]);
+        let capabilities = [&usb2, &superspeed, &container_id, &unknown];
+        let bos = TestBos {
+            bLength: 5,
+            bDescriptorType: 0x0f,
+            wTotalLength: 5 + capabilities.iter().map(|c| c.len() as u16).sum::<u16>(),
+            bNumDeviceCaps: 4,
+            dev_capability: capabilities.map(|c| c.as_ptr() as *const libusb_bos_dev_capability_descriptor),
+        };
+
+        let decoded = unsafe { generate_bos_descriptor(&bos as *const TestBos<4> as *const libusb_bos_descriptor) };
+        assert_eq!(decoded.total_length, bos.wTotalLength);
+        assert_eq!(decoded.capabilities.len(), 4);
+        match &decoded.capabilities[0] {
+            DeviceCapability::Usb2Extension(usb2) => assert!(usb2.lpm_supported),
+            other => panic!("expected the USB 2.0 extension, got {:?}", other),
+        }
+        match &decoded.capabilities[1] {
+            DeviceCapability::Superspeed(ss) => {
+                assert_eq!((ss.speeds_supported, ss.functionality_support), (0x000e, 1));
+                assert_eq!((ss.u1_exit_latency, ss.u2_exit_latency), (0x0a, 0x07ff));
+            }
+            other => panic!("expected the SuperSpeed capability, got {:?}", other),
+        }
+        match &decoded.capabilities[2] {
+            DeviceCapability::ContainerId(id) => assert_eq!(id, &(1..=16).collect::<Vec<u8>>()),
+            other => panic!("expected the container ID, got {:?}", other),
+        }
+        match &decoded.capabilities[3] {
+            DeviceCapability::Other(other) => {
+                assert_eq!((other.capability_type, other.data.as_slice()), (0x0d, &[1, 2, 3][..]))
+            }
+            other => panic!("expected an unknown capability, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_capability_is_kept_as_unknown() {
+        // a USB 2.0 extension one byte short
+        let usb2 = capability(USB_2_0_EXTENSION, &[0x02, 0, 0]);
+        let bos = TestBos {
+            bLength: 5,
+            bDescriptorType: 0x0f,
+            wTotalLength: 5 + usb2.len() as u16,
+            bNumDeviceCaps: 1,
+            dev_capability: [usb2.as_ptr() as *const libusb_bos_dev_capability_descriptor],
+        };
+        let decoded = unsafe { generate_bos_descriptor(&bos as *const TestBos<1> as *const libusb_bos_descriptor) };
+        match &decoded.capabilities[0] {
+            DeviceCapability::Other(other) => assert_eq!(other.capability_type, USB_2_0_EXTENSION),
+            other => panic!("expected an unknown capability, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn superspeed_plus_reads_the_sublink_speed_attributes() {
+        // two sublink speed attributes, SublinkSpeedAttrCount is one less
+        let mut data = vec![0, 0x01, 0, 0, 0, 0x00, 0x11, 0, 0];
+        data.extend(0x000a_4030u32.to_le_bytes());
+        data.extend(0x000a_40b0u32.to_le_bytes());
+        let capability = superspeed_plus_capability(&data).unwrap();
+        assert_eq!(capability.attributes, 1);
+        assert_eq!(capability.functionality_support, 0x1100);
+        assert_eq!(capability.sublink_speed_attributes, [0x000a_4030, 0x000a_40b0]);
+        assert!(superspeed_plus_capability(&data[..8]).is_none());
+    }
+
+    #[test]
+    fn platform_capability_recognises_webusb_and_ms_os_20() {
+        let mut webusb = vec![0];
+        webusb.extend(WEBUSB_UUID);
+        webusb.extend([0x00, 0x01, 0x01, 0x01]);
+        match platform_capability(&webusb).unwrap().kind {
+            PlatformKind::Webusb(webusb) => {
+                assert_eq!((webusb.version_bcd, webusb.vendor_code, webusb.landing_page_index), (0x0100, 1, 1));
+            }
+            other => panic!("expected WebUSB, got {:?}", other),
+        }
+
+        let mut ms_os = vec![0];
+        ms_os.extend(MS_OS_20_UUID);
+        ms_os.extend([0x00, 0x00, 0x03, 0x06, 0xb2, 0x00, 0x02, 0x00]);
+        match platform_capability(&ms_os).unwrap().kind {
+            PlatformKind::MsOs20(sets) => {
+                assert_eq!(sets.len(), 1);
+                assert_eq!((sets[0].windows_version, sets[0].total_length), (0x0603_0000, 0xb2));
+                assert_eq!((sets[0].vendor_code, sets[0].alt_enum_code), (2, 0));
+            }
+            other => panic!("expected Microsoft OS 2.0, got {:?}", other),
+        }
+
+        let mut other = vec![0];
+        other.extend([0xaa; 16]);
+        let capability = platform_capability(&other).unwrap();
+        assert!(matches!(capability.kind, PlatformKind::Unknown));
+        assert_eq!(capability.uuid, [0xaa; 16]);
+        assert!(platform_capability(&other[..16]).is_none());
+    }
+
+    /// A string descriptor with a correct bLength.
+    fn string_descriptor(units: &[u16]) -> Vec<u8> {
+        let mut data = vec![2 + 2 * units.len() as u8, LIBUSB_DT_STRING];
+        data.extend(units.iter().flat_map(|unit| unit.to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn string_descriptor_is_utf16le() {
+        let units: Vec<u16> = "Flash Drive \u{e9}\u{1f50c}".encode_utf16().collect();
+        assert_eq!(decode_string_descriptor(&string_descriptor(&units)).unwrap(), "Flash Drive \u{e9}\u{1f50c}");
+        assert_eq!(decode_string_descriptor(&string_descriptor(&[])).unwrap(), "");
+    }
+
+    #[test]
+    fn string_descriptor_replaces_unpaired_surrogates() {
+        let data = string_descriptor(&[0x0041, 0xd800, 0x0042]);
+        assert_eq!(decode_string_descriptor(&data).unwrap(), "A\u{fffd}B");
+    }
+
+    #[test]
+    fn string_descriptor_is_cut_at_b_length() {
+        let mut data = string_descriptor(&[0x0041, 0x0042]);
+        // bytes past bLength, e.g. left over in the buffer
+        data.extend([0x43, 0x00]);
+        assert_eq!(decode_string_descriptor(&data).unwrap(), "AB");
+        // a bLength past the data only covers what was received, an odd byte is dropped
+        let mut data = string_descriptor(&[0x0041, 0x0042]);
+        data[0] = 0xff;
+        data.push(0x43);
+        assert_eq!(decode_string_descriptor(&data).unwrap(), "AB");
+    }
+
+    #[test]
+    fn string_descriptor_header_is_checked() {
+        assert_eq!(decode_string_descriptor(&[]), Err(LibusbError::Io));
+        assert_eq!(decode_string_descriptor(&[2]), Err(LibusbError::Io));
+        // a device descriptor instead of a string descriptor
+        assert_eq!(decode_string_descriptor(&[4, 0x01, 0x41, 0x00]), Err(LibusbError::Io));
+        assert_eq!(decode_string_descriptor(&[0, LIBUSB_DT_STRING, 0x41, 0x00]), Err(LibusbError::Io));
+        assert_eq!(decode_string_descriptor(&[1, LIBUSB_DT_STRING]), Err(LibusbError::Io));
+    }
+
+    #[test]
+    fn language_ids_are_read_from_descriptor_zero() {
+        let data = string_descriptor(&[0x0409, 0x0407]);
+        assert_eq!(decode_language_ids(&data).unwrap(), vec![0x0409, 0x0407]);
+        assert_eq!(decode_language_ids(&[2, 0x01]), Err(LibusbError::Io));
+    }
+}
@@ -0,0 +1,218 @@
+//! Support for driver components, which export the `usb-driver` world and are bound
+//! to devices by the host instead of searching for them themselves.
+
+use serde::Deserialize;
+use wasmtime::component::bindgen;
+
+use crate::component::usb::descriptors::{ConfigurationDescriptor, DeviceDescriptor};
+
+bindgen!({
+    world: "usb-driver",
+    path: "../wit",
+    with: {
+        "component:usb/errors": crate::component::usb::errors,
+        "component:usb/configuration": crate::component::usb::configuration,
+        "component:usb/descriptors": crate::component::usb::descriptors,
+        "component:usb/transfers": crate::component::usb::transfers,
+        "component:usb/device": crate::component::usb::device,
+        "wasi:io/poll": wasmtime_wasi::bindings::io::poll,
+    },
+    async: {
+        only_imports: [
+            "await-transfer",
+            "await-any",
+            "[method]device-handle.reset-device",
+            "[method]device-handle.control-in",
+            "[method]device-handle.control-out",
+            "[method]device-handle.bulk-read",
+            "[method]device-handle.bulk-write",
+            "[method]device-handle.interrupt-read",
+            "[method]device-handle.interrupt-write",
+        ]
+    },
+});
+
+/// One entry of the match table of a driver. Fields that are left out match any device,
+/// the class triple is compared against the device and against every alternate setting of its interfaces.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MatchEntry {
+    pub vendor_id: Option<u16>,
+    pub product_id: Option<u16>,
+    pub class: Option<u8>,
+    pub subclass: Option<u8>,
+    pub protocol: Option<u8>,
+}
+
+impl MatchEntry {
+    pub fn matches(&self, device: &DeviceDescriptor, configuration: &ConfigurationDescriptor) -> bool {
+        if self.vendor_id.is_some_and(|v| v != device.vendor_id)
+            || self.product_id.is_some_and(|p| p != device.product_id)
+        {
+            return false;
+        }
+        if self.class.is_none() && self.subclass.is_none() && self.protocol.is_none() {
+            return true;
+        }
+        self.matches_class(device.device_class, device.device_subclass, device.device_protocol)
+            || configuration
+                .interfaces
+                .iter()
+                .flat_map(|interface| &interface.alt_settings)
+                .any(|alt_setting| {
+                    self.matches_class(
+                        alt_setting.interface_class,
+                        alt_setting.interface_subclass,
+                        alt_setting.interface_protocol,
+                    )
+                })
+    }
+
+    fn matches_class(&self, class: u8, subclass: u8, protocol: u8) -> bool {
+        self.class.is_none_or(|c| c == class)
+            && self.subclass.is_none_or(|s| s == subclass)
+            && self.protocol.is_none_or(|p| p == protocol)
+    }
+}
+
+/// Match table of a driver, usually read from a TOML file next to the component:
+///
+/// ```toml
+/// [[match]]
+/// vendor_id = 0x0781
+/// product_id = 0x5581
+///
+/// [[match]]
+/// class = 0x08
+/// subclass = 0x06
+/// protocol = 0x50
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MatchTable {
+    #[serde(rename = "match", default)]
+    pub entries: Vec<MatchEntry>,
+}
+
+impl MatchTable {
+    pub fn matches(&self, device: &DeviceDescriptor, configuration: &ConfigurationDescriptor) -> bool {
+        self.entries.iter().any(|entry| entry.matches(device, configuration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::usb::descriptors::{InterfaceDescriptor, UsbInterface};
+
+    fn device(vendor_id: u16, product_id: u16, class: (u8, u8, u8)) -> DeviceDescriptor {
+        DeviceDescriptor {
+            length: 18,
+            descriptor_type: 1,
+            usb_version_bcd: 0x0200,
+            device_class: class.0,
+            device_subclass: class.1,
+            device_protocol: class.2,
+            max_packet_size0: 64,
+            vendor_id,
+            product_id,
+            device_version_bcd: 0x0100,
+            manufacturer_index: 0,
+            product_index: 0,
+            serial_number_index: 0,
+            num_configurations: 1,
+        }
+    }
+
+    /// A configuration with one interface per entry, each with its alternate settings.
+    fn configuration(interfaces: &[&[(u8, u8, u8)]]) -> ConfigurationDescriptor {
+        let interfaces = interfaces
+            .iter()
+            .enumerate()
+            .map(|(number, alt_settings)| UsbInterface {
+                interface_number: number as u8,
+                alt_settings: alt_settings
+                    .iter()
+                    .enumerate()
+                    .map(|(alternate, &(class, subclass, protocol))| InterfaceDescriptor {
+                        length: 9,
+                        descriptor_type: 4,
+                        interface_number: number as u8,
+                        alternate_setting: alternate as u8,
+                        endpoints: Vec::new(),
+                        interface_class: class,
+                        interface_subclass: subclass,
+                        interface_protocol: protocol,
+                        interface_index: 0,
+                        extra: Vec::new(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        ConfigurationDescriptor {
+            length: 9,
+            descriptor_type: 2,
+            total_length: 0,
+            interfaces,
+            configuration_value: 1,
+            configuration_index: 0,
+            attributes: 0x80,
+            max_power: 50,
+            extra: Vec::new(),
+        }
+    }
+
+    fn mass_storage() -> MatchEntry {
+        MatchEntry { class: Some(0x08), subclass: Some(0x06), protocol: Some(0x50), ..Default::default() }
+    }
+
+    #[test]
+    fn empty_entry_matches_any_device() {
+        assert!(MatchEntry::default().matches(&device(0x1234, 0x5678, (0, 0, 0)), &configuration(&[])));
+    }
+
+    #[test]
+    fn ids_must_all_match() {
+        let entry = MatchEntry { vendor_id: Some(0x0781), product_id: Some(0x5581), ..Default::default() };
+        let config = configuration(&[]);
+        assert!(entry.matches(&device(0x0781, 0x5581, (0, 0, 0)), &config));
+        assert!(!entry.matches(&device(0x0781, 0x5582, (0, 0, 0)), &config));
+        assert!(!entry.matches(&device(0x0782, 0x5581, (0, 0, 0)), &config));
+    }
+
+    #[test]
+    fn class_matches_the_device_or_any_alternate_setting() {
+        let entry = mass_storage();
+        assert!(entry.matches(&device(1, 1, (0x08, 0x06, 0x50)), &configuration(&[])));
+        // composite device, the mass storage function is the second alternate setting of the second interface
+        let composite = configuration(&[&[(0x03, 0, 0)], &[(0xff, 0, 0), (0x08, 0x06, 0x50)]]);
+        assert!(entry.matches(&device(1, 1, (0, 0, 0)), &composite));
+        assert!(!entry.matches(&device(1, 1, (0, 0, 0)), &configuration(&[&[(0x08, 0x06, 0x62)]])));
+    }
+
+    #[test]
+    fn class_fields_come_from_one_descriptor() {
+        // class from the device and protocol from an interface are not a match
+        let entry = mass_storage();
+        assert!(!entry.matches(&device(1, 1, (0x08, 0x06, 0)), &configuration(&[&[(0x03, 0, 0x50)]])));
+    }
+
+    #[test]
+    fn ids_and_class_must_both_match() {
+        let entry = MatchEntry { vendor_id: Some(0x0781), ..mass_storage() };
+        let config = configuration(&[&[(0x08, 0x06, 0x50)]]);
+        assert!(entry.matches(&device(0x0781, 1, (0, 0, 0)), &config));
+        assert!(!entry.matches(&device(0x0782, 1, (0, 0, 0)), &config));
+    }
+
+    #[test]
+    fn table_reads_match_entries_from_toml() {
+        let table: MatchTable = toml::from_str(
+            "[[match]]\nvendor_id = 0x0781\nproduct_id = 0x5581\n\n[[match]]\nclass = 0x08\nsubclass = 0x06\nprotocol = 0x50\n",
+        )
+        .unwrap();
+        assert_eq!(table.entries.len(), 2);
+        assert!(table.matches(&device(0x0781, 0x5581, (0, 0, 0)), &configuration(&[])));
+        assert!(table.matches(&device(1, 1, (0, 0, 0)), &configuration(&[&[(0x08, 0x06, 0x50)]])));
+        assert!(!table.matches(&device(1, 1, (0, 0, 0)), &configuration(&[&[(0x03, 0, 0)]])));
+        assert!(!MatchTable::default().matches(&device(1, 1, (0, 0, 0)), &configuration(&[])));
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use clap::Args;
+use libc::timeval;
+use libusb1_sys::constants::{
+    LIBUSB_CAP_HAS_HOTPLUG, LIBUSB_HOTPLUG_ENUMERATE, LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED,
+    LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT, LIBUSB_HOTPLUG_MATCH_ANY,
+};
+use libusb1_sys::{libusb_config_descriptor, libusb_context, libusb_device, libusb_device_descriptor, libusb_exit, libusb_free_config_descriptor, libusb_get_active_config_descriptor, libusb_get_config_descriptor, libusb_get_device_descriptor, libusb_handle_events_timeout_completed, libusb_has_capability, libusb_hotplug_callback_handle, libusb_hotplug_deregister_callback, libusb_hotplug_register_callback, libusb_init, libusb_ref_device, libusb_unref_device};
+use log::{debug, error, info, warn};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use wasmtime::component::{Component, Linker, Resource};
+use wasmtime::{Engine, Error, Store};
+
+use usb_wasi_host::component::usb::descriptors::{ConfigurationDescriptor, DeviceDescriptor};
+use usb_wasi_host::component::usb::errors::LibusbError;
+use usb_wasi_host::broker::BrokerClient;
+use usb_wasi_host::descriptors::{device_descriptor_from_raw, generate_config_descriptor};
+use usb_wasi_host::driver::{MatchTable, UsbDriver};
+use usb_wasi_host::enumerate::{enumerate_devices, release_sessions, session_id, session_left};
+use usb_wasi_host::{AllowedInterfaces, AllowedUSBDevices, USBDeviceIdentifier, UsbDeviceHandle, WasiUsbCtx};
+
+use crate::compile::load_component;
+use crate::limits::{tear_down, EngineLimits, GuestLimits};
+use crate::wasi::WasiArgs;
+use crate::{new_engine, new_linker, MyState};
+
+const DRAIN_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Args)]
+pub struct DriversArgs {
+    /// Directory with driver components (name.wasm) and their match tables (name.toml)
+    #[arg(long)]
+    dir: PathBuf,
+
+    /// Reload a driver when its component or match table changes on disk.
+    /// SIGHUP reloads all drivers regardless.
+    #[arg(long)]
+    watch: bool,
+}
+
+struct Driver {
+    name: String,
+    path: PathBuf,
+    component: Component,
+    match_table: MatchTable,
+    modified: Option<SystemTime>,
+}
+
+/// A driver instance that accepted a device.
+struct Bound {
+    driver: String,
+    device: *mut libusb_device,
+    /// The handle given to `attach`, so it can be handed to the next version on reload
+    handle: u32,
+    store: Store<MyState>,
+    instance: UsbDriver,
+}
+
+impl Drop for Bound {
+    fn drop(&mut self) {
+        unsafe { libusb_unref_device(self.device) };
+    }
+}
+
+struct DeviceEvent {
+    arrived: bool,
+    device: *mut libusb_device,
+}
+
+unsafe impl Send for DeviceEvent {}
+
+/// Binds driver components to devices, like the kernel does for its drivers:
+/// every arriving device is offered to the drivers whose match table covers it, in
+/// name order, and the first one whose `probe` accepts it gets the device attached.
+struct DriverHost {
+    engine: Engine,
+    linker: Linker<MyState>,
+    context: *mut libusb_context,
+    allowed_usbdevices: AllowedUSBDevices,
+    allowed_interfaces: AllowedInterfaces,
+    /// Devices are opened through the broker when the host runs without privileges
+    broker: Option<Arc<BrokerClient>>,
+    drivers: Vec<Driver>,
+    /// By the session id of the device, see [`session_id`]
+    bound: HashMap<u64, Bound>,
+    cache_dir: Option<PathBuf>,
+    /// Applied to every driver instance, the timeout bounds each call into a driver
+    limits: GuestLimits,
+    engine_limits: EngineLimits,
+}
+
+pub async fn run(
+    args: &DriversArgs,
+    allowed_usbdevices: &AllowedUSBDevices,
+    allowed_interfaces: &AllowedInterfaces,
+    broker: Option<Arc<BrokerClient>>,
+    limits: &GuestLimits,
+    cache_dir: Option<&Path>,
+) -> Result<(), Error> {
+    let engine_limits = EngineLimits::of([limits]);
+    let engine = new_engine(engine_limits)?;
+    let linker = new_linker(&engine)?;
+    let drivers = load_drivers(&engine, &args.dir, cache_dir)?;
+    if drivers.is_empty() {
+        return Err(Error::msg(format!("No drivers found in {}", args.dir.display())));
+    }
+
+    let mut context: *mut libusb_context = std::ptr::null_mut();
+    LibusbError::check(unsafe { libusb_init(&mut context) })?;
+    let mut host = DriverHost {
+        engine,
+        linker,
+        context,
+        allowed_usbdevices: allowed_usbdevices.clone(),
+        allowed_interfaces: allowed_interfaces.clone(),
+        broker,
+        drivers,
+        bound: HashMap::new(),
+        cache_dir: cache_dir.map(Path::to_path_buf),
+        limits: limits.clone(),
+        engine_limits,
+    };
+
+    let (sender, mut receiver) = unbounded_channel();
+    // boxed so the pointer handed to libusb stays put until the callback is deregistered
+    let sender = Box::new(sender);
+    let hotplug_handle = unsafe { register_hotplug(context, &sender, allowed_usbdevices)? };
+
+    let flag = Arc::new(AtomicBool::new(true));
+    let event_thread = {
+        let flag = flag.clone();
+        let ctx_num = context as usize;
+        thread::spawn(move || {
+            let tv = timeval { tv_sec: 0, tv_usec: 20_000 }; // 20 ms
+            while flag.load(Ordering::SeqCst) {
+                let rc = unsafe {
+                    libusb_handle_events_timeout_completed(ctx_num as *mut libusb_context, &tv, std::ptr::null_mut())
+                };
+                if rc < 0 {
+                    error!("Error in libusb_handle_events_timeout: {}", rc);
+                    break;
+                }
+            }
+        })
+    };
+
+    let mut hangup = signal(SignalKind::hangup())?;
+    let mut watch = tokio::time::interval(Duration::from_secs(1));
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            Some(event) = receiver.recv() => {
+                host.handle(&event).await;
+                unsafe { libusb_unref_device(event.device) };
+            }
+            _ = hangup.recv() => host.reload_changed(true).await,
+            _ = watch.tick(), if args.watch => host.reload_changed(false).await,
+        }
+    }
+
+    info!("Detaching all drivers");
+    let keys: Vec<u64> = host.bound.keys().copied().collect();
+    for key in keys {
+        host.unbind(key).await;
+    }
+    unsafe {
+        if let Some(handle) = hotplug_handle {
+            libusb_hotplug_deregister_callback(context, handle);
+        }
+    }
+    flag.store(false, Ordering::SeqCst);
+    let _ = event_thread.join();
+    drop(host);
+    unsafe {
+        // the driver stores hand out device ids on this context
+        release_sessions(context);
+        libusb_exit(context);
+    }
+    Ok(())
+}
+
+fn load_drivers(engine: &Engine, dir: &Path, cache_dir: Option<&Path>) -> Result<Vec<Driver>, Error> {
+    let mut drivers = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "wasm") {
+            continue;
+        }
+        let table_path = path.with_extension("toml");
+        if !table_path.exists() {
+            warn!("Skipping {}, it has no match table {}", path.display(), table_path.display());
+            continue;
+        }
+        let match_table = match_table(&path)?;
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+        info!("Loading driver {} with {} match entries", name, match_table.entries.len());
+        drivers.push(Driver {
+            component: load_component(engine, &path, cache_dir)?,
+            modified: modified(&path),
+            name,
+            path,
+            match_table,
+        });
+    }
+    drivers.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(drivers)
+}
+
+fn match_table(component_path: &Path) -> Result<MatchTable, Error> {
+    Ok(toml::from_str(&std::fs::read_to_string(component_path.with_extension("toml"))?)?)
+}
+
+/// Latest modification of the component or its match table.
+fn modified(component_path: &Path) -> Option<SystemTime> {
+    let mtime = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    mtime(component_path).max(mtime(&component_path.with_extension("toml")))
+}
+
+extern "system" fn hotplug_cb(
+    _: *mut libusb_context,
+    dev: *mut libusb_device,
+    ev: libusb1_sys::libusb_hotplug_event,
+    user_data: *mut std::ffi::c_void,
+) -> std::os::raw::c_int {
+    let sender = unsafe { &*(user_data as *const UnboundedSender<DeviceEvent>) };
+    let arrived = match ev {
+        LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED => true,
+        LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT => false,
+        _ => return 0,
+    };
+    unsafe {
+        // released by the receiver once the event is handled
+        libusb_ref_device(dev);
+        if sender.send(DeviceEvent { arrived, device: dev }).is_err() {
+            libusb_unref_device(dev);
+        }
+    }
+    0
+}
+
+/// Queue an arrival for every present device and follow hotplug events when libusb supports them.
+unsafe fn register_hotplug(
+    context: *mut libusb_context,
+    sender: &UnboundedSender<DeviceEvent>,
+    allowed_usbdevices: &AllowedUSBDevices,
+) -> Result<Option<libusb_hotplug_callback_handle>, Error> {
+    if libusb_has_capability(LIBUSB_CAP_HAS_HOTPLUG) == 0 {
+        warn!("Hotplug is not supported, only devices present now are bound");
+        let (devices, _) = enumerate_devices(context, allowed_usbdevices)?;
+        for device in devices {
+            // the reference taken by the enumeration moves into the event
+            if sender.send(DeviceEvent { arrived: true, device: device.device }).is_err() {
+                libusb_unref_device(device.device);
+            }
+        }
+        return Ok(None);
+    }
+
+    let mut handle: libusb_hotplug_callback_handle = 0;
+    let rc = libusb_hotplug_register_callback(
+        context,
+        LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED | LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT,
+        LIBUSB_HOTPLUG_ENUMERATE,
+        LIBUSB_HOTPLUG_MATCH_ANY,
+        LIBUSB_HOTPLUG_MATCH_ANY,
+        LIBUSB_HOTPLUG_MATCH_ANY,
+        hotplug_cb,
+        sender as *const UnboundedSender<DeviceEvent> as *mut std::ffi::c_void,
+        &mut handle,
+    );
+    LibusbError::check(rc)?;
+    Ok(Some(handle))
+}
+
+/// The active configuration, or the first one when the device is not configured.
+unsafe fn configuration(dev: *mut libusb_device) -> Option<ConfigurationDescriptor> {
+    let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+    if libusb_get_active_config_descriptor(dev, &mut config_desc) < 0
+        && libusb_get_config_descriptor(dev, 0, &mut config_desc) < 0
+    {
+        return None;
+    }
+    let configuration = generate_config_descriptor(&*config_desc);
+    libusb_free_config_descriptor(config_desc);
+    Some(configuration)
+}
+
+impl DriverHost {
+    async fn handle(&mut self, event: &DeviceEvent) {
+        // addresses are reused, so bindings are kept by the session id of the device
+        let key = unsafe { session_id(self.context, event.device) };
+        if !event.arrived {
+            if let Some(bound) = self.bound.get_mut(&key) {
+                // the driver sees the device is gone before it is told to detach
+                let handle = Resource::<UsbDeviceHandle>::new_borrow(bound.handle);
+                if let Ok(usb_handle) = bound.store.data_mut().table.get(&handle) {
+                    usb_handle.mark_disconnected();
+                }
+            }
+            self.unbind(key).await;
+            unsafe { session_left(event.device) };
+            return;
+        }
+        if self.bound.contains_key(&key) {
+            return;
+        }
+        if let Err(e) = self.bind(key, event.device, None).await {
+            error!("Failed to bind a driver to device {}: {:?}", key, e);
+        }
+    }
+
+    /// Offer the device to the matching drivers. `handle` is an already open handle
+    /// that is handed to the driver instead of opening the device again.
+    async fn bind(
+        &mut self,
+        key: u64,
+        dev: *mut libusb_device,
+        mut handle: Option<UsbDeviceHandle>,
+    ) -> Result<(), Error> {
+        let Some((descriptor, configuration)) = self.describe(key, dev)? else {
+            return Ok(());
+        };
+        for index in 0..self.drivers.len() {
+            if self.try_driver(index, key, dev, descriptor, &configuration, &mut handle).await {
+                return Ok(());
+            }
+        }
+        debug!("No driver for {:04x}:{:04x} (device {})", descriptor.vendor_id, descriptor.product_id, key);
+        Ok(())
+    }
+
+    /// The descriptors drivers are matched against, `None` for a device the policy does not
+    /// allow or that has no configuration.
+    fn describe(
+        &self,
+        key: u64,
+        dev: *mut libusb_device,
+    ) -> Result<Option<(DeviceDescriptor, ConfigurationDescriptor)>, Error> {
+        let (descriptor, configuration) = unsafe {
+            let mut desc = std::mem::MaybeUninit::<libusb_device_descriptor>::uninit();
+            LibusbError::check(libusb_get_device_descriptor(dev, desc.as_mut_ptr()))?;
+            let descriptor = device_descriptor_from_raw(&desc.assume_init());
+            let Some(configuration) = configuration(dev) else {
+                debug!("Device {} has no configuration descriptor", key);
+                return Ok(None);
+            };
+            (descriptor, configuration)
+        };
+        let id = USBDeviceIdentifier {
+            vendor_id: descriptor.vendor_id,
+            product_id: descriptor.product_id,
+        };
+        if !self.allowed_usbdevices.is_allowed(&id) {
+            debug!("Device not allowed: {:?}", id);
+            return Ok(None);
+        }
+        Ok(Some((descriptor, configuration)))
+    }
+
+    /// Probe the device with the driver at `index` and attach it when it accepts, true once it is bound.
+    /// The driver takes the handle out of `handle` if there is one, and opens the device otherwise.
+    async fn try_driver(
+        &mut self,
+        index: usize,
+        key: u64,
+        dev: *mut libusb_device,
+        descriptor: DeviceDescriptor,
+        configuration: &ConfigurationDescriptor,
+        handle: &mut Option<UsbDeviceHandle>,
+    ) -> bool {
+        let driver = &self.drivers[index];
+        let id = USBDeviceIdentifier {
+            vendor_id: descriptor.vendor_id,
+            product_id: descriptor.product_id,
+        };
+        if !driver.match_table.matches(&descriptor, configuration) {
+            return false;
+        }
+        let mut store = match self.new_store(driver) {
+            Ok(store) => store,
+            Err(e) => {
+                error!("Failed to create a store for driver {}: {:?}", driver.name, e);
+                return false;
+            }
+        };
+        // a driver that fails to load or traps in probe must not keep the device from the next one
+        let probed = async {
+            let instance = UsbDriver::instantiate_async(&mut store, &driver.component, &self.linker).await?;
+            let accepted = instance.component_usb_driver().call_probe(&mut store, descriptor, configuration).await?;
+            Ok::<_, Error>((instance, accepted))
+        };
+        let instance = match self.limits.run(probed).await {
+            Ok((instance, true)) => instance,
+            Ok((_, false)) => {
+                debug!("Driver {} declined {:?}", driver.name, id);
+                return false;
+            }
+            Err(e) => {
+                error!("Driver {} failed to probe {:?}: {:?}", driver.name, id, e);
+                return false;
+            }
+        };
+        let guest = instance.component_usb_driver();
+
+        let usb_handle = match handle.take() {
+            Some(handle) => handle,
+            None => match unsafe { store.data().usb.open_device(dev) } {
+                Ok(handle) => handle,
+                Err(e) => {
+                    error!("Failed to open {:?} for driver {}: {:?}", id, driver.name, e);
+                    return false;
+                }
+            },
+        };
+        let resource = match store.data_mut().table.push(usb_handle) {
+            Ok(resource) => resource,
+            Err(e) => {
+                error!("Failed to hand {:?} to driver {}: {:?}", id, driver.name, e);
+                return false;
+            }
+        };
+        let rep = resource.rep();
+        match self.limits.run(guest.call_attach(&mut store, resource)).await {
+            Ok(Ok(())) => {
+                info!("Driver {} attached to {:?} (device {})", driver.name, id, key);
+                let driver = driver.name.clone();
+                unsafe { libusb_ref_device(dev) };
+                self.bound.insert(key, Bound { driver, device: dev, handle: rep, store, instance });
+                return true;
+            }
+            Ok(Err(e)) => {
+                warn!("Driver {} failed to attach to {:?}: {:?}", driver.name, id, e);
+                // transfers submitted during attach must complete before their buffers are dropped
+                if !store.data_mut().usb.drain_transfers(DRAIN_GRACE).await {
+                    warn!("Transfers of driver {} on device {} did not complete", driver.name, key);
+                }
+            }
+            Err(e) => tear_down(store, &e.context(format!("driver {} failed to attach", driver.name))).await,
+        }
+        false
+    }
+
+    async fn unbind(&mut self, key: u64) {
+        let Some(mut bound) = self.bound.remove(&key) else {
+            return;
+        };
+        info!("Detaching driver {} from device {}", bound.driver, key);
+        let detached = self.limits.run(bound.instance.component_usb_driver().call_detach(&mut bound.store)).await;
+        if let Err(e) = detached {
+            error!("Driver {} failed to detach: {:?}", bound.driver, e);
+        }
+        // the driver may have left transfers in flight, even more so when it trapped
+        if !bound.store.data_mut().usb.drain_transfers(DRAIN_GRACE).await {
+            warn!("Transfers of driver {} on device {} did not complete", bound.driver, key);
+        }
+        // dropping the store closes the device handle
+    }
+
+    /// Reload the drivers whose component or match table changed on disk, or all of them when `force` is set.
+    async fn reload_changed(&mut self, force: bool) {
+        for index in 0..self.drivers.len() {
+            let driver = &self.drivers[index];
+            if force || modified(&driver.path) != driver.modified {
+                self.reload(index).await;
+            }
+        }
+    }
+
+    /// Swap in a new version of a driver. Its devices stay open with their interfaces
+    /// claimed: the handles are taken from the old instances and attached to new ones.
+    async fn reload(&mut self, index: usize) {
+        let path = self.drivers[index].path.clone();
+        let name = self.drivers[index].name.clone();
+        let modified = modified(&path);
+        let reloaded = match_table(&path).and_then(|match_table| {
+            Ok((load_component(&self.engine, &path, self.cache_dir.as_deref())?, match_table))
+        });
+        let (component, match_table) = match reloaded {
+            Ok(reloaded) => reloaded,
+            Err(e) => {
+                error!("Failed to reload driver {}, keeping the running version: {:?}", name, e);
+                // don't retry until the file changes again
+                self.drivers[index].modified = modified;
+                return;
+            }
+        };
+        info!("Reloading driver {}", name);
+        let driver = &mut self.drivers[index];
+        driver.component = component;
+        driver.match_table = match_table;
+        driver.modified = modified;
+
+        let keys: Vec<u64> = self
+            .bound
+            .iter()
+            .filter(|(_, bound)| bound.driver == name)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in keys {
+            if let Err(e) = self.hand_over(key).await {
+                error!("Failed to hand device {} over to the new version of {}: {:?}", key, name, e);
+            }
+        }
+    }
+
+    async fn hand_over(&mut self, key: u64) -> Result<(), Error> {
+        let Some(mut old) = self.bound.remove(&key) else {
+            return Ok(());
+        };
+        if !old.store.data_mut().usb.drain_transfers(DRAIN_GRACE).await {
+            warn!("Transfers of driver {} on device {} did not complete", old.driver, key);
+        }
+        let handle = old
+            .store
+            .data_mut()
+            .table
+            .delete(Resource::<UsbDeviceHandle>::new_own(old.handle))
+            .map(|mut handle| {
+                handle.disown_claims();
+                handle
+            });
+        let device = old.device;
+        unsafe { libusb_ref_device(device) };
+        let name = old.driver.clone();
+        // the handle was moved out, so dropping the old instance leaves the device open
+        drop(old);
+        if handle.is_err() {
+            warn!("The old instance closed its handle for device {}, opening it again", key);
+        }
+        let result = self.take_back(&name, key, device, handle.ok()).await;
+        unsafe { libusb_unref_device(device) };
+        result
+    }
+
+    /// Attach the new version of driver `name` to the device it had, with the handle and the
+    /// interfaces it claimed. Only when that fails is the device offered to all drivers.
+    async fn take_back(
+        &mut self,
+        name: &str,
+        key: u64,
+        dev: *mut libusb_device,
+        mut handle: Option<UsbDeviceHandle>,
+    ) -> Result<(), Error> {
+        let Some((descriptor, configuration)) = self.describe(key, dev)? else {
+            return Ok(());
+        };
+        if let Some(index) = self.drivers.iter().position(|driver| driver.name == name) {
+            if self.try_driver(index, key, dev, descriptor, &configuration, &mut handle).await {
+                return Ok(());
+            }
+        }
+        warn!("The new version of {} did not take device {} back, offering it to all drivers", name, key);
+        self.bind(key, dev, handle).await
+    }
+
+    fn new_store(&self, driver: &Driver) -> Result<Store<MyState>, Error> {
+        let wasi_ctx = WasiArgs::default().build_ctx(&driver.path)?;
+        let mut usb_ctx = WasiUsbCtx::builder();
+        usb_ctx.policy(self.allowed_usbdevices.clone()).interfaces(self.allowed_interfaces.clone());
+        // the event thread of the driver host also completes the transfers of the drivers
+        unsafe { usb_ctx.libusb_context(self.context) };
+        if let Some(broker) = &self.broker {
+            usb_ctx.broker(broker.clone());
+        }
+        let mut store = Store::new(&self.engine, MyState::new(wasi_ctx, usb_ctx.build()));
+        self.limits.apply(&mut store, self.engine_limits)?;
+        Ok(store)
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use libusb1_sys::{libusb_context, libusb_device, libusb_free_device_list, libusb_get_bus_number, libusb_get_device_address, libusb_get_device_descriptor, libusb_get_device_list, libusb_get_device_speed, libusb_get_port_number, libusb_get_port_numbers, libusb_ref_device, libusb_unref_device};
+use log::{debug, info, warn};
+
+use crate::component::usb::descriptors::DeviceDescriptor;
+use crate::component::usb::device::{DeviceLocation, UsbSpeed};
+use crate::component::usb::errors::LibusbError;
+use crate::descriptors::device_descriptor_from_raw;
+use crate::policy::{AllowedUSBDevices, USBDeviceIdentifier};
+
+/// A device found by [`enumerate_devices`].
+/// `device` holds the reference taken by `libusb_get_device_list`, the caller must release it.
+pub struct EnumeratedDevice {
+    pub device: *mut libusb_device,
+    pub descriptor: DeviceDescriptor,
+    pub location: DeviceLocation,
+}
+
+/// Enumerate all devices on `ctx` that pass the device policy.
+/// This is shared by the WIT `list-devices` call and the `list` subcommand.
+/// Returns the allowed devices and the number of devices hidden by the policy.
+///
+/// # Safety
+/// `ctx` must be a valid libusb context.
+pub unsafe fn enumerate_devices(
+    ctx: *mut libusb_context,
+    allowed_usbdevices: &AllowedUSBDevices,
+) -> Result<(Vec<EnumeratedDevice>, usize), LibusbError> {
+    let mut list_ptr: *mut *mut libusb_device = std::ptr::null_mut();
+    info!("libusb_get_device_list called.");
+    let cnt = libusb_get_device_list(ctx, &mut list_ptr as *mut _ as *mut _);
+    info!("libusb_get_device_list returned count: {}", cnt);
+    if cnt < 0 {
+        return Err(LibusbError::from_raw(cnt as i32));
+    }
+    if !list_ptr.is_null() {
+        sessions_attached(ctx, std::slice::from_raw_parts(list_ptr, cnt as usize));
+    }
+    let mut devices: Vec<EnumeratedDevice> = Vec::new();
+    let mut denied = 0;
+    for i in 0..cnt {
+        let dev = *list_ptr.add(i as usize);
+        if dev.is_null() {
+            warn!("Device at index {} is null, skipping.", i);
+            continue;
+        }
+        let descriptor = match device_descriptor(dev) {
+            Ok(descriptor) => descriptor,
+            Err(e) => {
+                warn!("Failed to get device descriptor for device at index {}: {:?}", i, e);
+                libusb_unref_device(dev);
+                continue;
+            }
+        };
+        let usb_device = USBDeviceIdentifier {
+            vendor_id: descriptor.vendor_id,
+            product_id: descriptor.product_id,
+        };
+        debug!("{:?}", usb_device);
+
+        if !allowed_usbdevices.is_allowed(&usb_device) {
+            warn!("Device at index {} is not allowed, freeing device.", i);
+            libusb_unref_device(dev);
+            denied += 1;
+            continue;
+        }
+        info!("Device at index {} is allowed.", i);
+
+        let location = device_location(dev);
+        devices.push(EnumeratedDevice { device: dev, descriptor, location });
+    }
+    info!("Freeing device list pointer.");
+    libusb_free_device_list(list_ptr, 0);
+    Ok((devices, denied))
+}
+
+/// Read the (cached) device descriptor of `dev`.
+///
+/// # Safety
+/// `dev` must be a valid libusb device.
+pub unsafe fn device_descriptor(dev: *mut libusb_device) -> Result<DeviceDescriptor, LibusbError> {
+    let mut desc = std::mem::MaybeUninit::<libusb1_sys::libusb_device_descriptor>::uninit();
+    LibusbError::check(libusb_get_device_descriptor(dev, desc.as_mut_ptr()))?;
+    Ok(device_descriptor_from_raw(&desc.assume_init()))
+}
+
+/// Where `dev` sits on the bus and how fast it runs.
+///
+/// # Safety
+/// `dev` must be a valid libusb device.
+pub unsafe fn device_location(dev: *mut libusb_device) -> DeviceLocation {
+    DeviceLocation {
+        bus_number: libusb_get_bus_number(dev),
+        device_address: libusb_get_device_address(dev),
+        port_number: libusb_get_port_number(dev),
+        speed: UsbSpeed::from_raw(libusb_get_device_speed(dev) as u8),
+    }
+}
+
+/// How long the id of a device that left stays known, so every callback of the `left` event sees it.
+const LEFT_GRACE: Duration = Duration::from_secs(5);
+
+struct Session {
+    id: u64,
+    context: usize,
+    left: Option<Instant>,
+}
+
+struct Sessions {
+    next: u64,
+    /// By the address of the `libusb_device`, each entry holds a reference to it so libusb
+    /// cannot hand the same address to a device that arrives later.
+    devices: BTreeMap<usize, Session>,
+}
+
+impl Sessions {
+    /// Drop the devices whose grace period is over, along with their references.
+    unsafe fn expire(&mut self, now: Instant) {
+        self.devices.retain(|&device, session| match session.left {
+            Some(left) if now.duration_since(left) > LEFT_GRACE => {
+                libusb_unref_device(device as *mut libusb_device);
+                false
+            }
+            _ => true,
+        });
+    }
+}
+
+static SESSIONS: Mutex<Sessions> = Mutex::new(Sessions { next: 1, devices: BTreeMap::new() });
+
+/// Identifies `dev` while it is attached. Ids come from a counter and are handed out the first
+/// time a device of `ctx` is seen, libusb keeps one `libusb_device` per attached device, so every
+/// lookup for it returns the same id and a device that is plugged in again gets a new one. Bus
+/// number and address do not work for this, a host controller can give a new device the address
+/// of one that just left.
+///
+/// # Safety
+/// `dev` must be a valid libusb device of `ctx`.
+pub unsafe fn session_id(ctx: *mut libusb_context, dev: *mut libusb_device) -> u64 {
+    let mut sessions = SESSIONS.lock().unwrap();
+    sessions.expire(Instant::now());
+    if let Some(session) = sessions.devices.get(&(dev as usize)) {
+        return session.id;
+    }
+    let id = sessions.next;
+    sessions.next += 1;
+    libusb_ref_device(dev);
+    sessions.devices.insert(dev as usize, Session { id, context: ctx as usize, left: None });
+    id
+}
+
+/// Called when `dev` left, its id is forgotten a little later.
+///
+/// # Safety
+/// `dev` must be a valid libusb device.
+pub unsafe fn session_left(dev: *mut libusb_device) {
+    if let Some(session) = SESSIONS.lock().unwrap().devices.get_mut(&(dev as usize)) {
+        session.left.get_or_insert_with(Instant::now);
+    }
+}
+
+/// Treat the devices of `ctx` that are not among `attached` as left, so their ids and references
+/// do not pile up without hotplug events, e.g. across the `list-devices` calls of a long-running host.
+///
+/// # Safety
+/// `attached` must be the complete device list of `ctx`.
+unsafe fn sessions_attached(ctx: *mut libusb_context, attached: &[*mut libusb_device]) {
+    let mut sessions = SESSIONS.lock().unwrap();
+    let now = Instant::now();
+    for (&device, session) in sessions.devices.iter_mut() {
+        if session.context == ctx as usize && !attached.contains(&(device as *mut libusb_device)) {
+            session.left.get_or_insert(now);
+        }
+    }
+    sessions.expire(now);
+}
+
+/// Forget the ids of the devices of `ctx`, which must happen before `libusb_exit`.
+///
+/// # Safety
+/// `ctx` must be a valid libusb context, its events must no longer be handled.
+pub unsafe fn release_sessions(ctx: *mut libusb_context) {
+    SESSIONS.lock().unwrap().devices.retain(|&device, session| {
+        if session.context != ctx as usize {
+            return true;
+        }
+        libusb_unref_device(device as *mut libusb_device);
+        false
+    });
+}
+
+/// Port numbers from the root hub down to `dev`, empty for a root hub.
+///
+/// # Safety
+/// `dev` must be a valid libusb device.
+pub unsafe fn port_path(dev: *mut libusb_device) -> Result<Vec<u8>, LibusbError> {
+    // USB 3.0 limits the hub depth to 7
+    let mut ports = [0u8; 7];
+    let res = libusb_get_port_numbers(dev, ports.as_mut_ptr(), ports.len() as i32);
+    if res < 0 {
+        return Err(LibusbError::from_raw(res));
+    }
+    Ok(ports[..res as usize].to_vec())
+}
+
+/// The device at `port_path` on `bus`, if the device policy allows it.
+/// The caller must release the reference of the returned device.
+///
+/// # Safety
+/// `ctx` must be a valid libusb context.
+pub unsafe fn find_device_at(
+    ctx: *mut libusb_context,
+    allowed_usbdevices: &AllowedUSBDevices,
+    bus: u8,
+    path: &[u8],
+) -> Option<EnumeratedDevice> {
+    let (devices, _) = enumerate_devices(ctx, allowed_usbdevices).ok()?;
+    let mut found = None;
+    for device in devices {
+        if found.is_none()
+            && device.location.bus_number == bus
+            && port_path(device.device).is_ok_and(|p| p == path)
+        {
+            found = Some(device);
+        } else {
+            libusb_unref_device(device.device);
+        }
+    }
+    found
+}
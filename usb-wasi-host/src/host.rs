@@ -0,0 +1,2309 @@
+use libc::timeval;
+use libusb1_sys::constants::{
+    LIBUSB_CAP_HAS_HID_ACCESS, LIBUSB_CAP_HAS_HOTPLUG, LIBUSB_CAP_SUPPORTS_DETACH_KERNEL_DRIVER, LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED, LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT,
+    LIBUSB_HOTPLUG_MATCH_ANY, LIBUSB_HOTPLUG_NO_FLAGS, LIBUSB_TRANSFER_CANCELLED, LIBUSB_TRANSFER_COMPLETED,
+    LIBUSB_TRANSFER_ERROR, LIBUSB_TRANSFER_NO_DEVICE, LIBUSB_TRANSFER_OVERFLOW, LIBUSB_TRANSFER_STALL,
+    LIBUSB_TRANSFER_TIMED_OUT,
+    LIBUSB_TRANSFER_TYPE_BULK, LIBUSB_TRANSFER_TYPE_CONTROL, LIBUSB_TRANSFER_TYPE_INTERRUPT,
+    LIBUSB_TRANSFER_TYPE_ISOCHRONOUS, LIBUSB_DT_CONFIG,
+};
+use libusb1_sys::{libusb_alloc_streams, libusb_alloc_transfer, libusb_attach_kernel_driver, libusb_cancel_transfer, libusb_claim_interface, libusb_clear_halt, libusb_close, libusb_config_descriptor, libusb_context, libusb_detach_kernel_driver, libusb_device, libusb_device_handle, libusb_free_config_descriptor, libusb_free_streams, libusb_free_transfer, libusb_get_config_descriptor, libusb_get_config_descriptor_by_value, libusb_get_configuration, libusb_has_capability, libusb_hotplug_callback_handle, libusb_hotplug_register_callback, libusb_init, libusb_kernel_driver_active, libusb_open, libusb_release_interface, libusb_reset_device, libusb_set_configuration, libusb_set_interface_alt_setting, libusb_transfer, libusb_transfer_set_stream_id, libusb_unref_device, libusb_submit_transfer, libusb_handle_events_timeout_completed, libusb_ref_device, libusb_get_active_config_descriptor, libusb_get_bus_number, libusb_get_device_address, libusb_get_string_descriptor, libusb_get_device_speed, libusb_get_parent, libusb_get_descriptor, libusb_bos_descriptor, libusb_get_bos_descriptor, libusb_free_bos_descriptor, libusb_get_version, libusb_set_auto_detach_kernel_driver, libusb_wrap_sys_device};
+
+use wasmtime::component::*;
+use wasmtime::Error;
+
+use std::collections::{HashMap, VecDeque};
+use std::os::fd::{AsRawFd, OwnedFd};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+use std::time::{Duration, Instant};
+use log::{debug, error, info, trace, warn};
+use serde_json::json;
+use tokio::sync::{oneshot, Notify};
+use wasmtime_wasi::{async_trait, subscribe, DynPollable, Pollable};
+
+use crate::audit::{outcome, AuditDevice, AuditLog, TransferRecord};
+use crate::broker::BrokerClient;
+use crate::component;
+use crate::component::usb::configuration::ConfigValue;
+use crate::component::usb::descriptors::{BosDescriptor, ConfigurationDescriptor, DeviceDescriptor, EndpointDescriptor};
+use crate::component::usb::device::{BackendCapabilities, DeviceFilter, DeviceLocation, DeviceMetadata, Direction, HostClaimedInterface, HostDeviceHandle, HostEndpoint, HostUsbDevice, TransferOptions, TransferSetup, TransferType, UsbSpeed};
+use crate::component::usb::errors::LibusbError;
+use crate::component::usb::transfers::{BatchError, HostTransfer, Transfer};
+use crate::component::usb::usb_hotplug::{Event, Info};
+use crate::descriptors::{decode_language_ids, decode_string_descriptor, generate_bos_descriptor, generate_config_descriptor};
+use crate::enumerate::{device_descriptor, device_location, enumerate_devices, find_device_at, port_path, session_id, session_left, EnumeratedDevice};
+use crate::policy::{AllowedUSBDevices, USBDeviceIdentifier};
+use crate::sysfs::read_metadata;
+use crate::arbiter::InterfaceKey;
+use crate::{WasiUsbCtx, WasiUsbCtxView};
+
+/// Set once the device of a handle is gone, shared with its transfers and the disconnect callback.
+#[derive(Debug, Default)]
+pub(crate) struct Disconnect {
+    gone: AtomicBool,
+    notify: Notify,
+}
+
+impl Disconnect {
+    pub(crate) fn set(&self) {
+        self.gone.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub(crate) fn is_set(&self) -> bool {
+        self.gone.load(Ordering::SeqCst)
+    }
+
+    async fn wait(&self) {
+        loop {
+            // registered before the check, so a disconnect in between is not missed
+            let notified = self.notify.notified();
+            if self.is_set() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Set once libusb handed a submitted transfer back, shared between the transfer, the context and
+/// the completion callback. The callback frees the transfer under the lock, so cancelling under
+/// it never touches a freed transfer.
+#[derive(Debug, Default)]
+pub(crate) struct Completion {
+    done: Mutex<bool>,
+}
+
+impl Completion {
+    pub(crate) fn is_set(&self) -> bool {
+        *self.done.lock().unwrap()
+    }
+
+    /// Cancel `transfer` unless it already completed, `None` if it did.
+    ///
+    /// # Safety
+    /// `transfer` must be the submitted transfer this completion belongs to.
+    pub(crate) unsafe fn cancel(&self, transfer: *mut libusb_transfer) -> Option<i32> {
+        let done = self.done.lock().unwrap();
+        (!*done).then(|| libusb_cancel_transfer(transfer))
+    }
+
+    /// Called by the completion callback, the transfer must not be used after this.
+    unsafe fn complete(&self, transfer: *mut libusb_transfer) {
+        let mut done = self.done.lock().unwrap();
+        *done = true;
+        libusb_free_transfer(transfer);
+    }
+}
+
+/// The open handles of a store by the session id of their device, shared with the
+/// disconnect callback of its context.
+#[derive(Default)]
+pub(crate) struct DisconnectWatch {
+    handles: Mutex<Vec<(u64, Weak<Disconnect>)>>,
+}
+
+impl DisconnectWatch {
+    pub(crate) fn add(&self, session: u64, disconnect: &Arc<Disconnect>) {
+        let mut handles = self.handles.lock().unwrap();
+        handles.retain(|(_, d)| d.strong_count() > 0);
+        handles.push((session, Arc::downgrade(disconnect)));
+    }
+
+    fn device_left(&self, session: u64) {
+        let handles = self.handles.lock().unwrap();
+        for (_, disconnect) in handles.iter().filter(|(s, _)| *s == session) {
+            if let Some(disconnect) = disconnect.upgrade() {
+                disconnect.set();
+            }
+        }
+    }
+}
+
+/// The `pollable` returned by `device-handle.on-disconnect`.
+pub struct DisconnectPollable(Arc<Disconnect>);
+
+#[async_trait]
+impl Pollable for DisconnectPollable {
+    async fn ready(&mut self) {
+        self.0.wait().await
+    }
+}
+
+extern "system" fn disconnect_cb(
+    ctx: *mut libusb_context,
+    dev: *mut libusb_device,
+    _: libusb1_sys::libusb_hotplug_event,
+    user_data: *mut std::ffi::c_void,
+) -> std::os::raw::c_int {
+    unsafe {
+        let watch = &*(user_data as *const DisconnectWatch);
+        let session = session_id(ctx, dev);
+        debug!("Device {} left, failing its handles", session);
+        watch.device_left(session);
+        session_left(dev);
+    }
+    0
+}
+
+/// The devices of `devices` that pass `filter`, the others are released.
+/// The serial number is only read, by opening the device, when everything else matches.
+/// The device is opened the way the guest would open it, through the broker and into the audit log.
+///
+/// # Safety
+/// The devices must be valid libusb devices of the context of `ctx`.
+unsafe fn filter_devices(ctx: &WasiUsbCtx, devices: Vec<EnumeratedDevice>, filter: &DeviceFilter) -> Vec<EnumeratedDevice> {
+    let mut matching = Vec::new();
+    for device in devices {
+        if device_matches(ctx, &device, filter) {
+            matching.push(device);
+        } else {
+            libusb_unref_device(device.device);
+        }
+    }
+    matching
+}
+
+unsafe fn device_matches(ctx: &WasiUsbCtx, device: &EnumeratedDevice, filter: &DeviceFilter) -> bool {
+    let descriptor = &device.descriptor;
+    if filter.vendor_id.is_some_and(|vendor_id| vendor_id != descriptor.vendor_id)
+        || filter.product_id.is_some_and(|product_id| product_id != descriptor.product_id)
+        || filter.bus.is_some_and(|bus| bus != device.location.bus_number)
+    {
+        return false;
+    }
+    if let Some(path) = &filter.port_path {
+        if !port_path(device.device).is_ok_and(|p| &p == path) {
+            return false;
+        }
+    }
+    if let Some(class) = filter.class {
+        if descriptor.device_class != class && !interface_classes(device.device).contains(&class) {
+            return false;
+        }
+    }
+    if let Some(serial_number) = &filter.serial_number {
+        let handle = ctx.open_device(device.device);
+        ctx.audit(
+            "open",
+            AuditDevice::from_raw(device.device),
+            &outcome(&handle),
+            json!({ "filter": "serial-number" }),
+        );
+        return match handle {
+            Ok(mut handle) => handle.serial_number().is_ok_and(|serial| serial.as_ref() == Some(serial_number)),
+            Err(e) => {
+                debug!("Cannot read the serial number of {:?}: {}", device.location, e);
+                false
+            }
+        };
+    }
+    true
+}
+
+/// The classes of all alternate settings of the interfaces in the active configuration of `dev`.
+unsafe fn interface_classes(dev: *mut libusb_device) -> Vec<u8> {
+    let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+    if libusb_get_active_config_descriptor(dev, &mut config_desc) < 0 {
+        return Vec::new();
+    }
+    let configuration = generate_config_descriptor(&*config_desc);
+    libusb_free_config_descriptor(config_desc);
+    configuration
+        .interfaces
+        .iter()
+        .flat_map(|i| &i.alt_settings)
+        .map(|alt_setting| alt_setting.interface_class)
+        .collect()
+}
+
+/// How long `reset-device` waits for a device that re-enumerated to show up again.
+const REENUMERATE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Shared with the hotplug callback of a single context, so every store only sees its own events.
+pub(crate) struct HotplugState {
+    allowed_usbdevices: AllowedUSBDevices,
+    queue: Mutex<VecDeque<(Event, Info, UsbDevice)>>,
+}
+
+#[derive(Debug)]
+pub struct UsbTransfer {
+    transfer: *mut libusb_transfer,
+    completed: Arc<Completion>,
+    disconnect: Arc<Disconnect>,
+    pub buffer: Option<Box<[u8]>>,
+    pub buf_len: u32,
+    receiver: Option<oneshot::Receiver<Result<Vec<u8>, LibusbError>>>,
+    control_setup: Option<TransferSetup>,
+    // bookkeeping for the audit log, the libusb transfer is freed on completion
+    transfer_type: TransferType,
+    endpoint: u8,
+    device: Option<AuditDevice>,
+}
+
+impl UsbTransfer {
+    /// What the audit log records about the transfer once it is over.
+    fn audit(&self, log: Arc<AuditLog>, out_payload: Option<Vec<u8>>) -> TransferAudit {
+        TransferAudit {
+            log,
+            device: self.device,
+            transfer_type: self.transfer_type,
+            endpoint: self.endpoint,
+            setup: self.control_setup.filter(|_| matches!(self.transfer_type, TransferType::Control)),
+            length: self.buf_len,
+            out_payload,
+            submitted_at: Instant::now(),
+        }
+    }
+}
+
+/// The audit record of a submitted transfer, written by the completion callback so that every
+/// transfer is recorded with its final status: awaited, cancelled, dropped by the guest or
+/// drained when its store goes away.
+struct TransferAudit {
+    log: Arc<AuditLog>,
+    device: Option<AuditDevice>,
+    transfer_type: TransferType,
+    endpoint: u8,
+    setup: Option<TransferSetup>,
+    length: u32,
+    out_payload: Option<Vec<u8>>,
+    submitted_at: Instant,
+}
+
+impl TransferAudit {
+    fn record(&self, result: &Result<Vec<u8>, LibusbError>) {
+        let payload = match result {
+            Ok(data) if !data.is_empty() => Some(data.as_slice()),
+            _ => self.out_payload.as_deref(),
+        };
+        self.log.record_transfer(TransferRecord {
+            device: self.device,
+            transfer_type: match self.transfer_type {
+                TransferType::Control => "control",
+                TransferType::Bulk => "bulk",
+                TransferType::Interrupt => "interrupt",
+                TransferType::Isochronous => "isochronous",
+            },
+            endpoint: self.endpoint,
+            setup: self.setup,
+            length: self.length,
+            payload,
+            result: &outcome(result),
+            duration: Some(self.submitted_at.elapsed()),
+        });
+    }
+}
+
+/// An interface claimed on a device handle. It refers to the handle by its resource rep and
+/// raw pointer, so that it never outlives the handle: once the handle is closed or the
+/// interface released, every use fails with not-found.
+pub struct ClaimedInterface {
+    pub(crate) handle: u32,
+    pub(crate) raw_handle: *mut libusb_device_handle,
+    pub(crate) interface: u8,
+}
+
+/// An endpoint of a [`ClaimedInterface`], with the descriptor it was created from.
+pub struct Endpoint {
+    pub(crate) handle: u32,
+    pub(crate) raw_handle: *mut libusb_device_handle,
+    pub(crate) interface: u8,
+    pub(crate) descriptor: EndpointDescriptor,
+}
+
+impl Endpoint {
+    fn transfer_type(&self) -> TransferType {
+        match self.descriptor.attributes & 0x03 {
+            0 => TransferType::Control,
+            1 => TransferType::Isochronous,
+            2 => TransferType::Bulk,
+            _ => TransferType::Interrupt,
+        }
+    }
+}
+
+/// The device handle `handle`, unless its device was unplugged or re-enumerated.
+fn live_handle<'a>(
+    table: &'a mut ResourceTable,
+    handle: &Resource<UsbDeviceHandle>,
+) -> Result<&'a mut UsbDeviceHandle, LibusbError> {
+    let usb_device_handle = table.get_mut(handle).expect("Failed to get device handle");
+    if usb_device_handle.is_gone() {
+        return Err(LibusbError::NoDevice);
+    }
+    Ok(usb_device_handle)
+}
+
+/// The device handle `interface` was claimed on, as long as it is open and still holds the interface.
+fn claimed_handle(
+    table: &mut ResourceTable,
+    handle: u32,
+    raw_handle: *mut libusb_device_handle,
+    interface: u8,
+) -> Result<&mut UsbDeviceHandle, LibusbError> {
+    match table.get_mut(&Resource::<UsbDeviceHandle>::new_borrow(handle)) {
+        Ok(usb_device_handle) if usb_device_handle.is_gone() => Err(LibusbError::NoDevice),
+        Ok(usb_device_handle)
+            if usb_device_handle.handle == raw_handle && usb_device_handle.claimed_interfaces.contains(&interface) =>
+        {
+            Ok(usb_device_handle)
+        }
+        _ => Err(LibusbError::NotFound),
+    }
+}
+
+/// Where a new transfer goes.
+struct TransferTarget {
+    handle: *mut libusb_device_handle,
+    disconnect: Arc<Disconnect>,
+    device: Option<AuditDevice>,
+    endpoint: u8,
+    transfer_type: TransferType,
+}
+
+/// A submitted transfer, tracked by the context so it can be drained when the guest goes away.
+pub(crate) struct InFlightTransfer {
+    pub(crate) transfer: *mut libusb_transfer,
+    pub(crate) completed: Arc<Completion>,
+}
+
+pub struct UsbDevice {
+    pub(crate) device: *mut libusb_device,
+}
+pub struct UsbDeviceHandle {
+    pub(crate) handle: *mut libusb_device_handle,
+    // the enumerated device the handle was opened on, a handle on a node from the broker gets a
+    // device of its own from libusb that is in no device list, so this one is used instead
+    device: *mut libusb_device,
+    // session id of `device`, see `enumerate::session_id`
+    pub(crate) session: u64,
+    pub(crate) claimed_interfaces: Vec<u8>,
+    // live claimed-interface resources of each claimed interface, it is released with the last one
+    claims: HashMap<u8, u32>,
+    // alternate setting selected for each claimed interface
+    pub(crate) alt_settings: HashMap<u8, u8>,
+    pub(crate) detached_kernel_drivers: Vec<u8>,
+    // string descriptors by (index, langid), they do not change while the device is open
+    strings: HashMap<(u8, u16), String>,
+    languages: Option<Vec<u16>>,
+    // set once a reset made the device re-enumerate, the handle then refers to a device that is gone
+    pub(crate) reenumerated: bool,
+    pub(crate) disconnect: Arc<Disconnect>,
+    // device node handed out by a broker, libusb does not close it itself
+    node: Option<OwnedFd>,
+}
+
+impl UsbDeviceHandle {
+    /// Open `device` on behalf of a guest, the handle is closed when it is dropped.
+    ///
+    /// # Safety
+    /// `device` must be a valid libusb device.
+    pub unsafe fn open(device: *mut libusb_device) -> Result<Self, LibusbError> {
+        let mut handle: *mut libusb_device_handle = std::ptr::null_mut();
+        LibusbError::check(libusb_open(device, &mut handle))?;
+        Ok(Self::from_raw(handle, device))
+    }
+
+    /// Open `device` with the node `broker` hands out, for hosts without access to `/dev/bus/usb`.
+    ///
+    /// # Safety
+    /// `context` must be the libusb context of `device`, `device` a valid libusb device.
+    pub unsafe fn open_brokered(
+        context: *mut libusb_context,
+        device: *mut libusb_device,
+        broker: &BrokerClient,
+    ) -> Result<Self, LibusbError> {
+        let node = broker.open(libusb_get_bus_number(device), libusb_get_device_address(device))?;
+        let mut handle: *mut libusb_device_handle = std::ptr::null_mut();
+        // libusb takes the descriptor as an intptr_t
+        LibusbError::check(libusb_wrap_sys_device(context, node.as_raw_fd() as isize as *mut std::os::raw::c_int, &mut handle))?;
+        let mut this = Self::from_raw(handle, device);
+        this.node = Some(node);
+        Ok(this)
+    }
+
+    unsafe fn from_raw(handle: *mut libusb_device_handle, device: *mut libusb_device) -> Self {
+        libusb_ref_device(device);
+        Self {
+            handle,
+            device,
+            session: 0,
+            claimed_interfaces: Vec::new(),
+            claims: HashMap::new(),
+            alt_settings: HashMap::new(),
+            detached_kernel_drivers: Vec::new(),
+            strings: HashMap::new(),
+            languages: None,
+            reenumerated: false,
+            disconnect: Arc::new(Disconnect::default()),
+            node: None,
+        }
+    }
+
+    /// Let the handle fail with no-device from now on and wake the guests waiting on `on-disconnect`,
+    /// for hosts that learn about departed devices themselves.
+    pub fn mark_disconnected(&self) {
+        self.disconnect.set();
+    }
+
+    fn is_gone(&self) -> bool {
+        self.reenumerated || self.disconnect.is_set()
+    }
+
+    /// Reset the device and bring it back into the state the guest left it in: the active
+    /// configuration, the claimed interfaces with their alternate settings and the detached
+    /// kernel drivers. Fails with not-found if the device re-enumerated with other descriptors.
+    ///
+    /// # Safety
+    /// The handle must be open.
+    unsafe fn reset(&mut self) -> Result<(), LibusbError> {
+        let mut config = 0;
+        LibusbError::check(libusb_get_configuration(self.handle, &mut config))?;
+        LibusbError::check(libusb_reset_device(self.handle))?;
+
+        let mut current = 0;
+        LibusbError::check(libusb_get_configuration(self.handle, &mut current))?;
+        if current != config {
+            for ifac in &self.claimed_interfaces {
+                libusb_release_interface(self.handle, *ifac as i32);
+            }
+            LibusbError::check(libusb_set_configuration(self.handle, config))?;
+        }
+        for ifac in &self.claimed_interfaces {
+            let ifac = *ifac as i32;
+            if self.detached_kernel_drivers.contains(&(ifac as u8)) && libusb_kernel_driver_active(self.handle, ifac) == 1 {
+                LibusbError::check(libusb_detach_kernel_driver(self.handle, ifac))?;
+            }
+            // libusb claims the interfaces again itself where it can, claiming twice is fine
+            LibusbError::check(libusb_claim_interface(self.handle, ifac))?;
+            if let Some(&alt_setting) = self.alt_settings.get(&(ifac as u8)).filter(|&&alt_setting| alt_setting != 0) {
+                LibusbError::check(libusb_set_interface_alt_setting(self.handle, ifac, alt_setting as i32))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn string_descriptor(&mut self, index: u8, langid: u16) -> Result<String, LibusbError> {
+        if let Some(string) = self.strings.get(&(index, langid)) {
+            return Ok(string.clone());
+        }
+        let mut buf = [0u8; 255];
+        let res = unsafe { libusb_get_string_descriptor(self.handle, index, langid, buf.as_mut_ptr(), buf.len() as i32) };
+        if res < 0 {
+            return Err(LibusbError::from_raw(res));
+        }
+        let string = decode_string_descriptor(&buf[..res as usize])?;
+        self.strings.insert((index, langid), string.clone());
+        Ok(string)
+    }
+
+    fn supported_languages(&mut self) -> Result<Vec<u16>, LibusbError> {
+        if let Some(languages) = &self.languages {
+            return Ok(languages.clone());
+        }
+        let mut buf = [0u8; 255];
+        let res = unsafe { libusb_get_string_descriptor(self.handle, 0, 0, buf.as_mut_ptr(), buf.len() as i32) };
+        if res < 0 {
+            return Err(LibusbError::from_raw(res));
+        }
+        let languages = decode_language_ids(&buf[..res as usize])?;
+        self.languages = Some(languages.clone());
+        Ok(languages)
+    }
+
+    /// The manufacturer string in the first supported language, as `get-manufacturer` returns it.
+    pub fn manufacturer(&mut self) -> Result<Option<String>, LibusbError> {
+        self.device_string(|desc| desc.iManufacturer)
+    }
+
+    pub fn product(&mut self) -> Result<Option<String>, LibusbError> {
+        self.device_string(|desc| desc.iProduct)
+    }
+
+    pub fn serial_number(&mut self) -> Result<Option<String>, LibusbError> {
+        self.device_string(|desc| desc.iSerialNumber)
+    }
+
+    /// The libusb handle, it stays owned by `self`.
+    pub fn as_raw(&self) -> *mut libusb_device_handle {
+        self.handle
+    }
+
+    /// Forget the claimed-interface resources of the store the handle is taken out of, they
+    /// are gone with it. The interfaces stay claimed until the next store claims them again.
+    pub fn disown_claims(&mut self) {
+        self.claims.clear();
+    }
+
+    /// The string the device descriptor field selected by `index` refers to, in the first supported language.
+    fn device_string(&mut self, index: impl Fn(&libusb1_sys::libusb_device_descriptor) -> u8) -> Result<Option<String>, LibusbError> {
+        let index = unsafe {
+            let mut desc = std::mem::MaybeUninit::<libusb1_sys::libusb_device_descriptor>::uninit();
+            LibusbError::check(libusb1_sys::libusb_get_device_descriptor(self.device, desc.as_mut_ptr()))?;
+            index(&desc.assume_init())
+        };
+        if index == 0 {
+            return Ok(None);
+        }
+        let langid = *self.supported_languages()?.first().ok_or(LibusbError::NotFound)?;
+        self.string_descriptor(index, langid).map(Some)
+    }
+
+    fn audit_device(&self) -> Option<AuditDevice> {
+        unsafe { AuditDevice::from_raw(self.device) }
+    }
+
+    /// Endpoints of the selected alternate setting of `interface` in the active configuration.
+    fn endpoint_descriptors(&self, interface: u8) -> Result<Vec<EndpointDescriptor>, LibusbError> {
+        let alt_setting = self.alt_settings.get(&interface).copied().unwrap_or(0);
+        let configuration = unsafe {
+            let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+            LibusbError::check(libusb_get_active_config_descriptor(self.device, &mut config_desc))?;
+            let descriptor = generate_config_descriptor(&*config_desc);
+            libusb_free_config_descriptor(config_desc);
+            descriptor
+        };
+        configuration
+            .interfaces
+            .into_iter()
+            .filter(|usb_interface| usb_interface.interface_number == interface)
+            .flat_map(|usb_interface| usb_interface.alt_settings)
+            .find(|descriptor| descriptor.alternate_setting == alt_setting)
+            .map(|descriptor| descriptor.endpoints)
+            .ok_or(LibusbError::NotFound)
+    }
+
+    pub(crate) fn interface_key(&self, interface: u8) -> InterfaceKey {
+        InterfaceKey { device: self.session, interface }
+    }
+}
+
+// Context struct for transfer callback
+struct TransferContext {
+    sender: oneshot::Sender<Result<Vec<u8>, LibusbError>>,
+    completed: Arc<Completion>,
+    disconnect: Arc<Disconnect>,
+    buffer: Box<[u8]>,
+    audit: Option<TransferAudit>,
+}
+
+// Safety: Ensure that the usage of `*mut libusb_device` is thread-safe.
+unsafe impl Send for UsbDevice {}
+unsafe impl Sync for UsbDevice {}
+
+impl Drop for UsbDeviceHandle {
+    // also runs when a store is dropped with open handles, e.g. after the guest trapped,
+    // so the device is left the way the guest found it
+    fn drop(&mut self) {
+        unsafe {
+            for ifac in &self.claimed_interfaces {
+                libusb_release_interface(self.handle, *ifac as i32);
+            }
+            for ifac in &self.detached_kernel_drivers {
+                let res = libusb_attach_kernel_driver(self.handle, *ifac as i32);
+                if res < 0 {
+                    warn!("Failed to reattach kernel driver of interface {}: {}", ifac, LibusbError::from_raw(res));
+                }
+            }
+            libusb_close(self.handle);
+            libusb_unref_device(self.device);
+        }
+    }
+}
+
+unsafe impl Send for UsbDeviceHandle {}
+unsafe impl Sync for UsbDeviceHandle {}
+
+unsafe impl Send for UsbTransfer {}
+unsafe impl Sync for UsbTransfer {}
+unsafe impl Send for ClaimedInterface {}
+unsafe impl Sync for ClaimedInterface {}
+unsafe impl Send for Endpoint {}
+unsafe impl Sync for Endpoint {}
+
+extern "system" fn hotplug_cb(
+    ctx: *mut libusb_context,
+    dev: *mut libusb_device,
+    ev: libusb1_sys::libusb_hotplug_event,
+    user_data: *mut std::ffi::c_void,
+) -> std::os::raw::c_int {
+    debug!("hotplug_cb called with event code: {:?}", ev);
+    unsafe {
+        // gather minimal info WITHOUT opening the device
+        let mut desc = std::mem::MaybeUninit::<libusb1_sys::libusb_device_descriptor>::uninit();
+        if libusb1_sys::libusb_get_device_descriptor(dev, desc.as_mut_ptr()) != 0 {
+            log::error!("Failed to get device descriptor");
+            return 0; // ignore
+        }
+        let desc = desc.assume_init();
+        let vendor_id = desc.idVendor;
+        let product_id = desc.idProduct;
+        let device_id = USBDeviceIdentifier {
+            vendor_id,
+            product_id,
+        };
+        
+        let state = &*(user_data as *const HotplugState);
+        if !state.allowed_usbdevices.is_allowed(&device_id) {
+            log::warn!("Device not allowed: {:?}", device_id);
+            return 0; // ignore
+        }
+        debug!("Device allowed: {:?}", device_id);
+        
+        let bus = libusb1_sys::libusb_get_bus_number(dev);
+        let addr = libusb1_sys::libusb_get_device_address(dev);
+        debug!(
+            "Device details - bus: {}, address: {}, vendor: {:#06x}, product: {:#06x}",
+            bus,
+            addr,
+            desc.idVendor,
+            desc.idProduct
+        );
+        
+        let info = Info {
+            id: session_id(ctx, dev),
+            bus,
+            address: addr,
+            vendor: desc.idVendor,
+            product: desc.idProduct,
+        };
+        let event = match ev {
+            LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED => {
+                log::info!("Device arrived: {:?}", info);
+                Event::ARRIVED
+            }
+            LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT => {
+                log::info!("Device left: {:?}", info);
+                session_left(dev);
+                Event::LEFT
+            }
+            _ => {
+                warn!("Unknown hotplug event: {:?}", ev);
+                return 0;
+            }
+        };
+
+        // Need to increase refcount before storing in queue
+        libusb_ref_device(dev); // Add this line to increment reference count
+        
+        let mut q = state.queue.lock().unwrap();
+        q.push_back((event, info, UsbDevice{ device: dev }));
+        debug!("Hotplug event pushed to queue");
+        0
+    }
+}
+
+extern "system" fn transfer_callback(transfer: *mut libusb_transfer) {
+    unsafe {
+        // Reconstruct the context
+        let ctx_ptr = (*transfer).user_data as *mut TransferContext;
+        let ctx = Box::from_raw(ctx_ptr);
+        // Determine transfer status and prepare result
+        let status = (*transfer).status;
+        let result: Result<Vec<u8>, LibusbError> =
+            if status == LIBUSB_TRANSFER_COMPLETED {
+                // Transfer completed successfully
+                let mut data_vec = Vec::new();
+                if (*transfer).num_iso_packets > 0 {
+                    // Isochronous transfer: combine data from all packets
+                    let num_packets = (*transfer).num_iso_packets as usize;
+                    let mut total_len: usize = 0;
+                    for i in 0..num_packets {
+                        let desc = (*transfer).iso_packet_desc.as_ptr().add(i);
+                        total_len += (*desc).actual_length as usize;
+                    }
+                    let buf_ptr = (*transfer).buffer;
+                    if !buf_ptr.is_null() && total_len > 0 {
+                        let data_slice = std::slice::from_raw_parts(buf_ptr, total_len);
+                        data_vec = data_slice.to_vec();
+                    }
+                } else if (*transfer).transfer_type == LIBUSB_TRANSFER_TYPE_CONTROL {
+                    // Control transfer
+                    // For control IN (device-to-host): skip setup packet (first 8 bytes)
+                    let actual_len = (*transfer).actual_length as usize;
+                    debug!("Control transfer completed with actual length: {}", actual_len);
+                    
+                    // Extract request type from the setup packet
+                    let buf_ptr = (*transfer).buffer;
+                    let bm_request_type = if !buf_ptr.is_null() { *buf_ptr } else { 0 };
+                    let is_device_to_host = (bm_request_type & 0x80) != 0;
+                    
+                    if is_device_to_host && actual_len > 0 {
+                        // For IN transfers, return the data after the setup packet
+                        if !buf_ptr.is_null() {
+                            // Get the data portion (skipping 8-byte setup)
+                            let data_slice = std::slice::from_raw_parts(buf_ptr.add(8), actual_len);
+                            data_vec = data_slice.to_vec();
+                            debug!("Control IN transfer data: {:?}", data_vec);
+                        }
+                    } else {
+                        // For OUT transfers, no data to return
+                        data_vec = Vec::new();
+                    }
+                } else {
+                    // Bulk/Interrupt transfer
+                    let actual_len = (*transfer).actual_length as usize;
+                    if (*transfer).endpoint & 0x80 != 0 {
+                        // IN transfer: copy received data
+                        if actual_len > 0 {
+                            let buf_ptr = (*transfer).buffer;
+                            if !buf_ptr.is_null() {
+                                let data_slice = std::slice::from_raw_parts(buf_ptr, actual_len);
+                                data_vec = data_slice.to_vec();
+                            }
+                        }
+                    } else {
+                        // OUT transfer: no data to return
+                        data_vec = Vec::new();
+                    }
+                }
+                Ok(data_vec)
+            } else {
+                // Transfer did not complete successfully, map status to LibusbError
+                let err = match status {
+                    LIBUSB_TRANSFER_TIMED_OUT => LibusbError::Timeout,
+                    LIBUSB_TRANSFER_CANCELLED => LibusbError::Interrupted,
+                    LIBUSB_TRANSFER_STALL => LibusbError::Pipe,
+                    LIBUSB_TRANSFER_NO_DEVICE => LibusbError::NoDevice,
+                    LIBUSB_TRANSFER_OVERFLOW => LibusbError::Overflow,
+                    LIBUSB_TRANSFER_ERROR => LibusbError::Io,
+                    _ => LibusbError::Other,
+                };
+                if err == LibusbError::NoDevice {
+                    ctx.disconnect.set();
+                }
+                Err(err)
+            };
+        if let Some(audit) = &ctx.audit {
+            audit.record(&result);
+        }
+        // Mark as completed and free the libusb transfer struct
+        ctx.completed.complete(transfer);
+        // Send result (if receiver still exists)
+        let _ = ctx.sender.send(result);
+        // Box::from_raw has taken ownership of ctx, dropping it here will free buffer
+        // (Buffer is inside ctx.buffer as Box<[u8]> and will be dropped automatically)
+    }
+}
+
+extern "system" fn empty_callback(_transfer: *mut libusb_transfer) {}
+
+impl LibusbError {
+    /// Convert a raw `libusb_error` integer value to a `LibusbError` variant.
+    pub fn from_raw(value: i32) -> Self {
+        match value {
+            -1 => LibusbError::Io,
+            -2 => LibusbError::InvalidParam,
+            -3 => LibusbError::Access,
+            -4 => LibusbError::NoDevice,
+            -5 => LibusbError::NotFound,
+            -6 => LibusbError::Busy,
+            -7 => LibusbError::Timeout,
+            -8 => LibusbError::Overflow,
+            -9 => LibusbError::Pipe,
+            -10 => LibusbError::Interrupted,
+            -11 => LibusbError::NoMem,
+            -12 => LibusbError::NotSupported,
+            -99 => LibusbError::Other,
+            _ => LibusbError::Other, // Default to `Other` for unknown error codes
+        }
+    }
+
+    /// The raw `libusb_error` value of this error, the inverse of [`LibusbError::from_raw`].
+    pub fn to_raw(self) -> i32 {
+        match self {
+            LibusbError::Io => -1,
+            LibusbError::InvalidParam => -2,
+            LibusbError::Access => -3,
+            LibusbError::NoDevice => -4,
+            LibusbError::NotFound => -5,
+            LibusbError::Busy => -6,
+            LibusbError::Timeout => -7,
+            LibusbError::Overflow => -8,
+            LibusbError::Pipe => -9,
+            LibusbError::Interrupted => -10,
+            LibusbError::NoMem => -11,
+            LibusbError::NotSupported => -12,
+            LibusbError::Other => -99,
+        }
+    }
+
+    /// Map a libusb return code to `Ok` for non-negative values and the matching error otherwise.
+    pub fn check(value: i32) -> Result<(), Self> {
+        match value {
+            0.. => Ok(()),
+            _ => Err(Self::from_raw(value)),
+        }
+    }
+}
+
+impl UsbSpeed {
+    pub fn from_raw(value: u8) -> Self {
+        match value {
+            0 => UsbSpeed::Unknown,
+            1 => UsbSpeed::Low,
+            2 => UsbSpeed::Full,
+            3 => UsbSpeed::High,
+            4 => UsbSpeed::Super,
+            5 => UsbSpeed::SuperPlus,
+            6 => UsbSpeed::SuperPlusX2,
+            _ => UsbSpeed::Unknown,
+        }
+    }
+}
+
+impl component::usb::configuration::Host for WasiUsbCtxView<'_> {}
+impl component::usb::descriptors::Host for WasiUsbCtxView<'_> {}
+impl component::usb::errors::Host for WasiUsbCtxView<'_> {}
+
+impl HostTransfer for WasiUsbCtxView<'_> {
+    fn submit_transfer(
+        &mut self,
+        self_: Resource<Transfer>,
+        data: Vec<u8>,
+    ) -> Result<(), component::usb::transfers::LibusbError> {
+        debug!("Submit transfer");
+        let audit_log = self.ctx.audit_log.clone();
+        let usb_transfer = self.table.get_mut(&self_).expect("Failed to get transfer");
+        debug!("Transfer: {:?}", usb_transfer);
+        let transfer_ptr = usb_transfer.transfer;
+        if usb_transfer.completed.is_set() {
+            warn!("Transfer already completed");
+            return Err(LibusbError::Busy);
+        }
+        if usb_transfer.buffer.is_none() {
+            warn!("Transfer already submitted");
+            return Err(LibusbError::Busy);
+        }
+        if usb_transfer.disconnect.is_set() {
+            return Err(LibusbError::NoDevice);
+        }
+
+        unsafe {
+            let transfer_type = (*transfer_ptr).transfer_type;
+            debug!("Transfer type: {:?}", transfer_type);
+
+            if transfer_type == LIBUSB_TRANSFER_TYPE_CONTROL {
+                let setup_buf = (*transfer_ptr).buffer;
+                if !setup_buf.is_null() {
+                    let bm_request_type = usb_transfer.control_setup.unwrap().bm_request_type;
+                    let direction_in = bm_request_type & 0x80 != 0;
+                    if direction_in {
+                        // control transfer IN
+                        debug!("Control transfer IN");
+                    } else {
+                        debug!("Control transfer OUT");
+                        // control transfer out
+                        if data.len() as u32 != usb_transfer.buf_len {
+                            error!(
+                                "Invalid data length for control transfer OUT: {}, expected {}",
+                                data.len(),
+                                usb_transfer.buf_len
+                            );
+                            return Err(LibusbError::InvalidParam);
+                        }
+                        let buf_ptr = (*transfer_ptr).buffer;
+                        if !buf_ptr.is_null() {
+                            debug!("Copying data to control transfer OUT buffer");
+                            std::ptr::copy_nonoverlapping(
+                                data.as_ptr(),
+                                setup_buf.add(8),
+                                data.len(),
+                            );
+                        }
+                    }
+                }
+            } else if (*transfer_ptr).endpoint & 0x80 != 0 {
+                // IN transfer
+                info!("IN transfer");
+            } else {
+                info!("OUT transfer");
+                // OUT transfer
+                if data.len() as u32 != usb_transfer.buf_len {
+                    error!(
+                        "Invalid data length for OUT transfer: {}, expected {}",
+                        data.len(),
+                        usb_transfer.buf_len
+                    );
+                    return Err(LibusbError::InvalidParam);
+                }
+                let buf_ptr = (*transfer_ptr).buffer;
+                if !buf_ptr.is_null() {
+                    debug!("Copying data to OUT transfer buffer");
+                    std::ptr::copy_nonoverlapping(data.as_ptr(), buf_ptr, data.len());
+                }
+            }
+
+            debug!("creating transfer context");
+
+            let (sender, receiver) = oneshot::channel();
+
+            let buffer_box = usb_transfer.buffer.take().expect("buffer not allocated");
+            let audit = audit_log.map(|log| usb_transfer.audit(log, Some(data.clone()).filter(|data| !data.is_empty())));
+            let ctx = Box::new(TransferContext {
+                sender,
+                completed: usb_transfer.completed.clone(),
+                disconnect: usb_transfer.disconnect.clone(),
+                buffer: buffer_box,
+                audit,
+            });
+
+            (*transfer_ptr).user_data = Box::into_raw(ctx) as *mut _;
+            (*transfer_ptr).callback = transfer_callback;
+
+            debug!("submitting transfer: {:?}", transfer_ptr);
+            let submit_result = libusb_submit_transfer(transfer_ptr);
+            if submit_result < 0 {
+                error!(
+                    "Failed to submit transfer: {}",
+                    LibusbError::from_raw(submit_result)
+                );
+                let ctx = Box::from_raw((*transfer_ptr).user_data as *mut TransferContext);
+                (*transfer_ptr).callback = empty_callback;
+                (*transfer_ptr).user_data = std::ptr::null_mut();
+                let error = LibusbError::from_raw(submit_result);
+                if let Some(audit) = &ctx.audit {
+                    audit.record(&Err(error));
+                }
+                return Err(error);
+            } else {
+                debug!("transfer submitted");
+                let transfer_mut = self.table.get_mut(&self_).expect("Failed to get transfer");
+                self.ctx.in_flight.retain(|t| !t.completed.is_set());
+                self.ctx.in_flight.push(InFlightTransfer {
+                    transfer: transfer_ptr,
+                    completed: transfer_mut.completed.clone(),
+                });
+                transfer_mut.receiver = Some(receiver);
+            }
+        }
+        Ok(())
+    }
+
+    fn cancel_transfer(&mut self, self_: Resource<UsbTransfer>) -> Result<(), LibusbError> {
+        let usb_transfer = self.table.get(&self_).expect("Failed to get transfer");
+        let transfer_ptr = usb_transfer.transfer;
+        let result = unsafe {
+            match usb_transfer.completed.cancel(transfer_ptr) {
+                Some(res) if res < 0 => Err(LibusbError::from_raw(res)),
+                _ => Ok(()),
+            }
+        };
+        self.ctx.audit(
+            "cancel-transfer",
+            usb_transfer.device,
+            &outcome(&result),
+            json!({ "endpoint": usb_transfer.endpoint }),
+        );
+        result
+    }
+
+    fn drop(&mut self, self_: Resource<UsbTransfer>) -> Result<(), Error> {
+        trace!("Drop transfer");
+        if let Ok(transfer) = self.table.delete(self_) {
+            unsafe {
+                if transfer.buffer.is_some() {
+                    // never submitted, libusb does not know about it
+                    libusb_free_transfer(transfer.transfer);
+                } else {
+                    // the completion callback frees the transfer once the cancellation went through,
+                    // or already did if the transfer completed
+                    let _ = transfer.completed.cancel(transfer.transfer);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl component::usb::transfers::Host for WasiUsbCtxView<'_> {
+    async fn await_transfer(
+        &mut self,
+        self_: Resource<UsbTransfer>,
+    ) -> Result<Vec<u8>, LibusbError> {
+        info!("Awaiting transfer");
+        let usb_transfer = self.table.get_mut(&self_).expect("Failed to get transfer");
+
+        if usb_transfer.receiver.is_none() {
+            error!("Transfer receiver not set");
+            return Err(LibusbError::NotFound);
+        }
+
+        let receiver = usb_transfer.receiver.take().ok_or(LibusbError::NotFound)?;
+        info!("Transfer receiver set");
+
+        let result = match receiver.await {
+            Ok(result) => {
+                info!("Transfer result: {:?}", result);
+                result
+            }
+            Err(_) => Err(LibusbError::Interrupted),
+        };
+
+        // Remove the transfer from the resource table to free memory
+        self.table.delete(self_).ok();
+
+        result
+    }
+
+    fn submit_batch(
+        &mut self,
+        xfers: Vec<Resource<UsbTransfer>>,
+        data: Vec<Vec<u8>>,
+    ) -> Result<(), BatchError> {
+        if xfers.len() != data.len() {
+            return Err(BatchError { index: 0, error: LibusbError::InvalidParam });
+        }
+        for (index, (xfer, data)) in xfers.into_iter().zip(data).enumerate() {
+            self.submit_transfer(xfer, data)
+                .map_err(|error| BatchError { index: index as u32, error })?;
+        }
+        Ok(())
+    }
+
+    async fn await_any(
+        &mut self,
+        xfers: Vec<Resource<UsbTransfer>>,
+        timeout_ms: u32,
+    ) -> Result<(u32, Result<Vec<u8>, LibusbError>), LibusbError> {
+        let mut receivers = Vec::with_capacity(xfers.len());
+        for xfer in &xfers {
+            match self.table.get_mut(xfer).ok().and_then(|t| t.receiver.take()) {
+                Some(receiver) => receivers.push(receiver),
+                None => break,
+            }
+        }
+        if xfers.is_empty() || receivers.len() != xfers.len() {
+            // not submitted, already awaited or listed twice
+            for (xfer, receiver) in xfers.iter().zip(receivers) {
+                self.table.get_mut(xfer).expect("Failed to get transfer").receiver = Some(receiver);
+            }
+            return Err(LibusbError::InvalidParam);
+        }
+
+        let first = std::future::poll_fn(|cx| {
+            for (index, receiver) in receivers.iter_mut().enumerate() {
+                if let Poll::Ready(result) = Pin::new(receiver).poll(cx) {
+                    return Poll::Ready((index, result.unwrap_or(Err(LibusbError::Interrupted))));
+                }
+            }
+            Poll::Pending
+        });
+        let completed = if timeout_ms == 0 {
+            Some(first.await)
+        } else {
+            tokio::time::timeout(Duration::from_millis(timeout_ms as u64), first).await.ok()
+        };
+
+        // the transfers that are still pending can be awaited again
+        for (index, (xfer, receiver)) in xfers.iter().zip(receivers).enumerate() {
+            if !matches!(completed, Some((i, _)) if i == index) {
+                self.table.get_mut(xfer).expect("Failed to get transfer").receiver = Some(receiver);
+            }
+        }
+        let (index, result) = completed.ok_or(LibusbError::Timeout)?;
+        Ok((index as u32, result))
+    }
+}
+
+impl WasiUsbCtxView<'_> {
+    /// Wait for a device that re-enumerated at `bus`/`port_path` to come back and queue a
+    /// reenumerated event with it, giving up after [`REENUMERATE_TIMEOUT`].
+    async fn queue_reenumerated(&mut self, bus: u8, port_path: Vec<u8>) {
+        let deadline = Instant::now() + REENUMERATE_TIMEOUT;
+        while Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let Some(context) = self.ctx.context else {
+                return;
+            };
+            if let Some(found) = unsafe { find_device_at(context, &self.ctx.allowed_usbdevices, bus, &port_path) } {
+                let info = Info {
+                    id: unsafe { session_id(context, found.device) },
+                    bus,
+                    address: found.location.device_address,
+                    vendor: found.descriptor.vendor_id,
+                    product: found.descriptor.product_id,
+                };
+                info!("Device re-enumerated: {:?}", info);
+                self.ctx.pending_events.push_back((Event::REENUMERATED, info, UsbDevice { device: found.device }));
+                return;
+            }
+        }
+        warn!("Device on bus {} port path {:?} did not come back after the reset", bus, port_path);
+    }
+
+    /// Target for a control transfer on endpoint 0 of `handle`.
+    fn control_target(&mut self, handle: &Resource<UsbDeviceHandle>) -> Result<TransferTarget, LibusbError> {
+        let usb_handle = live_handle(self.table, handle)?;
+        Ok(TransferTarget {
+            handle: usb_handle.handle,
+            disconnect: usb_handle.disconnect.clone(),
+            device: self.ctx.audit_log.as_ref().and_then(|_| usb_handle.audit_device()),
+            endpoint: 0,
+            transfer_type: TransferType::Control,
+        })
+    }
+
+    /// Target for a synchronous transfer on `ep`, which must be an endpoint of `handle`
+    /// with the given type and direction.
+    fn endpoint_target(
+        &mut self,
+        handle: &Resource<UsbDeviceHandle>,
+        ep: &Resource<Endpoint>,
+        transfer_type: TransferType,
+        direction: Direction,
+    ) -> Result<TransferTarget, LibusbError> {
+        let endpoint = self.table.get(ep).expect("Failed to get endpoint");
+        let (rep, raw_handle, interface) = (endpoint.handle, endpoint.raw_handle, endpoint.interface);
+        let address = endpoint.descriptor.endpoint_address;
+        let endpoint_direction = if address & 0x80 != 0 { Direction::In } else { Direction::Out };
+        if rep != handle.rep() || endpoint.transfer_type() != transfer_type || endpoint_direction != direction {
+            warn!("Endpoint {:#04x} cannot be used for a {:?} {:?} transfer", address, transfer_type, direction);
+            return Err(LibusbError::InvalidParam);
+        }
+        let usb_handle = claimed_handle(self.table, rep, raw_handle, interface)?;
+        Ok(TransferTarget {
+            handle: raw_handle,
+            disconnect: usb_handle.disconnect.clone(),
+            device: self.ctx.audit_log.as_ref().and_then(|_| usb_handle.audit_device()),
+            endpoint: address,
+            transfer_type,
+        })
+    }
+
+    /// Allocate and submit a transfer in one go, for the synchronous calls of device-handle.
+    /// The caller awaits the returned transfer, the target holds a raw handle and must not live
+    /// across that await.
+    fn submit_sync(
+        &mut self,
+        target: TransferTarget,
+        setup: Option<TransferSetup>,
+        buf_size: u32,
+        timeout_ms: u32,
+        data: Vec<u8>,
+    ) -> Result<Resource<Transfer>, LibusbError> {
+        let opts = TransferOptions { timeout_ms, stream_id: 0, iso_packets: 0 };
+        let transfer = self.alloc_transfer(target, setup, buf_size, opts)?;
+        if let Err(e) = self.submit_transfer(Resource::new_borrow(transfer.rep()), data) {
+            // never submitted, so libusb does not know about it
+            if let Ok(usb_transfer) = self.table.delete(transfer) {
+                unsafe { libusb_free_transfer(usb_transfer.transfer) };
+            }
+            return Err(e);
+        }
+        Ok(transfer)
+    }
+
+    /// Allocate a transfer to `target`. `setup` is required for control transfers and ignored otherwise.
+    fn alloc_transfer(
+        &mut self,
+        target: TransferTarget,
+        setup: Option<TransferSetup>,
+        buf_size: u32,
+        opts: TransferOptions,
+    ) -> Result<Resource<Transfer>, LibusbError> {
+        let xfer_type = target.transfer_type;
+        info!(
+            "Starting new_transfer with buf_size: {buf_size} and transfer type: {:?}",
+            xfer_type
+        );
+
+        let limits = self.ctx.limits;
+        if limits.max_transfer_size.is_some_and(|max| buf_size > max) {
+            warn!("Transfer of {} bytes exceeds the configured limit", buf_size);
+            return Err(LibusbError::InvalidParam);
+        }
+        if limits.max_iso_packets.is_some_and(|max| opts.iso_packets > max) {
+            warn!("Transfer with {} iso packets exceeds the configured limit", opts.iso_packets);
+            return Err(LibusbError::InvalidParam);
+        }
+
+        let setup = match (xfer_type, setup) {
+            (TransferType::Control, None) => return Err(LibusbError::InvalidParam),
+            (TransferType::Control, setup) => setup,
+            _ => None,
+        };
+
+        unsafe {
+            let iso_packets =
+                if matches!(xfer_type, TransferType::Isochronous) {
+                    opts.iso_packets as i32
+                } else {
+                    0
+                };
+            debug!("Calculated iso_packets: {iso_packets}");
+
+            let transfer_ptr = libusb_alloc_transfer(iso_packets);
+            if transfer_ptr.is_null() {
+                log::error!(
+                    "Failed to allocate USB transfer (libusb_alloc_transfer returned null)"
+                );
+                return Err(LibusbError::NoMem);
+            }
+            debug!("Allocated transfer pointer: {:?}", transfer_ptr);
+
+            (*transfer_ptr).dev_handle = target.handle;
+            (*transfer_ptr).endpoint = target.endpoint;
+            (*transfer_ptr).transfer_type = match xfer_type {
+                TransferType::Control => LIBUSB_TRANSFER_TYPE_CONTROL,
+                TransferType::Bulk => LIBUSB_TRANSFER_TYPE_BULK,
+                TransferType::Interrupt => LIBUSB_TRANSFER_TYPE_INTERRUPT,
+                TransferType::Isochronous => LIBUSB_TRANSFER_TYPE_ISOCHRONOUS,
+            };
+            (*transfer_ptr).timeout = opts.timeout_ms;
+            debug!(
+                "Transfer configured with endpoint: {}, type: {:?}, timeout: {}ms",
+                target.endpoint,
+                (*transfer_ptr).transfer_type,
+                opts.timeout_ms
+            );
+
+            if opts.stream_id != 0 {
+                libusb_transfer_set_stream_id(transfer_ptr, opts.stream_id);
+                debug!("Stream ID set to: {}", opts.stream_id);
+            }
+
+            let total_len: u32 = if (*transfer_ptr).transfer_type == LIBUSB_TRANSFER_TYPE_CONTROL {
+                8 + buf_size
+                // buf_size
+            } else {
+                buf_size
+            };
+            debug!(
+                "Calculated total transfer buffer size: {}, based on transfer type: {:?}",
+                total_len,
+                (*transfer_ptr).transfer_type
+            );
+
+            let mut buffer_vec = vec![0u8; total_len as usize];
+
+            if let Some(setup) = setup {
+                buffer_vec[0] = setup.bm_request_type;
+                buffer_vec[1] = setup.b_request;
+                buffer_vec[2] = (setup.w_value & 0xFF) as u8;
+                buffer_vec[3] = (setup.w_value >> 8) as u8;
+                buffer_vec[4] = (setup.w_index & 0xFF) as u8;
+                buffer_vec[5] = (setup.w_index >> 8) as u8;
+                buffer_vec[6] = (buf_size & 0xFF) as u8;
+                buffer_vec[7] = ((buf_size >> 8) & 0xFF) as u8;
+            
+                debug!(
+                    "Control transfer setup filled: bm_request_type: {}, b_request: {}, w_value: {}, w_index: {}",
+                    setup.bm_request_type,
+                    setup.b_request,
+                    setup.w_value,
+                    setup.w_index
+                );
+            }
+
+            let buffer_box = buffer_vec.into_boxed_slice();
+            (*transfer_ptr).buffer = buffer_box.as_ptr() as *mut u8;
+            (*transfer_ptr).length = total_len as i32;
+            debug!("Transfer buffer configured with length: {}", total_len);
+
+            if iso_packets > 0 {
+                let packet_count = iso_packets as usize;
+                let base_len = buf_size / iso_packets as u32;
+                let rem = buf_size % iso_packets as u32;
+
+                for i in 0..packet_count {
+                    let desc = (*transfer_ptr).iso_packet_desc.as_mut_ptr().add(i);
+                    let packet_len = if i == packet_count - 1 {
+                        base_len + rem
+                    } else {
+                        base_len
+                    };
+                    (*desc).length = packet_len;
+                    debug!("Iso packet {} configured with length: {}", i, packet_len);
+                }
+
+                (*transfer_ptr).num_iso_packets = iso_packets;
+                info!(
+                    "Isochronous transfer configured with {} packets",
+                    iso_packets
+                );
+            }
+
+            let transfer_resource = self
+                .table
+                .push(UsbTransfer {
+                    transfer: transfer_ptr,
+                    buffer: Some(buffer_box),
+                    buf_len: buf_size,
+                    completed: Arc::new(Completion::default()),
+                    disconnect: target.disconnect,
+                    receiver: None,
+                    control_setup: setup,
+                    transfer_type: xfer_type,
+                    endpoint: target.endpoint,
+                    device: target.device,
+                })
+                .or(Err(LibusbError::Other))?;
+            info!("Transfer resource created successfully");
+
+            Ok(transfer_resource)
+        }
+    }
+}
+
+impl HostUsbDevice for WasiUsbCtxView<'_> {
+    fn open(
+        &mut self,
+        self_: Resource<UsbDevice>,
+    ) -> Result<Resource<UsbDeviceHandle>, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        let device_ptr = usb_device.device;
+        unsafe {
+            let handle = self.ctx.open_device(device_ptr);
+            self.ctx.audit("open", AuditDevice::from_raw(device_ptr), &outcome(&handle), json!({}));
+            let handle = handle?;
+            self.ctx.disconnects.add(handle.session, &handle.disconnect);
+            let resource = self.table.push(handle).or(Err(LibusbError::Other))?;
+            Ok(resource)
+        }
+    }
+    
+    fn get_active_configuration_descriptor(
+        &mut self,
+        self_: Resource<UsbDevice>,
+    ) -> Result<ConfigurationDescriptor, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        let device_ptr = usb_device.device;
+        unsafe {
+            let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+            let res = libusb_get_active_config_descriptor(device_ptr, &mut config_desc);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            let descriptor = generate_config_descriptor(&*config_desc);
+            libusb_free_config_descriptor(config_desc);
+            Ok(descriptor)
+        }
+    }
+
+    fn get_configuration_descriptor(
+        &mut self,
+        self_: Resource<UsbDevice>,
+        config_index: u8,
+    ) -> Result<ConfigurationDescriptor, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        let device_ptr = usb_device.device;
+        let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+        unsafe {
+            let res = libusb_get_config_descriptor(device_ptr, config_index, &mut config_desc);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            let descriptor = generate_config_descriptor(&*config_desc);
+            libusb_free_config_descriptor(config_desc);
+            Ok(descriptor)
+        }
+    }
+
+    fn get_configuration_descriptor_by_value(
+        &mut self,
+        self_: Resource<UsbDevice>,
+        config_value: u8,
+    ) -> Result<
+        component::usb::device::ConfigurationDescriptor,
+        component::usb::device::LibusbError,
+    > {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        let device_ptr = usb_device.device;
+        let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+        unsafe {
+            let res =
+                libusb_get_config_descriptor_by_value(device_ptr, config_value, &mut config_desc);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            let descriptor = generate_config_descriptor(&*config_desc);
+            // Create the ConfigurationDescriptor from the config_desc
+            libusb_free_config_descriptor(config_desc);
+            Ok(descriptor)
+        }
+    }
+
+    fn get_device_descriptor(&mut self, self_: Resource<UsbDevice>) -> Result<DeviceDescriptor, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe { device_descriptor(usb_device.device) }
+    }
+
+    fn id(&mut self, self_: Resource<UsbDevice>) -> u64 {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe { self.ctx.session_id(usb_device.device) }
+    }
+
+    fn get_location(&mut self, self_: Resource<UsbDevice>) -> DeviceLocation {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe { device_location(usb_device.device) }
+    }
+
+    fn get_speed(&mut self, self_: Resource<UsbDevice>) -> UsbSpeed {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe { UsbSpeed::from_raw(libusb_get_device_speed(usb_device.device) as u8) }
+    }
+
+    fn get_port_path(&mut self, self_: Resource<UsbDevice>) -> Result<Vec<u8>, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe { port_path(usb_device.device) }
+    }
+
+    fn get_metadata(&mut self, self_: Resource<UsbDevice>) -> Result<DeviceMetadata, LibusbError> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        let (bus, port_path) = unsafe { (libusb_get_bus_number(usb_device.device), port_path(usb_device.device)?) };
+        read_metadata(&self.ctx.sysfs_root, bus, &port_path)
+    }
+
+    fn get_parent(&mut self, self_: Resource<UsbDevice>) -> Option<Resource<UsbDevice>> {
+        let usb_device = self.table.get(&self_).expect("Failed to get device");
+        unsafe {
+            // libusb keeps a reference to the parent for as long as the child is alive
+            let parent = libusb_get_parent(usb_device.device);
+            if parent.is_null() {
+                return None;
+            }
+            // hubs are subject to the device policy like any other device
+            let descriptor = device_descriptor(parent).ok()?;
+            let device_id = USBDeviceIdentifier {
+                vendor_id: descriptor.vendor_id,
+                product_id: descriptor.product_id,
+            };
+            if !self.ctx.allowed_usbdevices.is_allowed(&device_id) {
+                debug!("Parent hub not allowed: {:?}", device_id);
+                return None;
+            }
+            let resource = self
+                .table
+                .push(UsbDevice { device: libusb_ref_device(parent) })
+                .expect("Failed to push device");
+            Some(resource)
+        }
+    }
+
+    fn drop(&mut self, rep: Resource<UsbDevice>) -> Result<(), Error> {
+        trace!("Drop device");
+        if let Ok(device) = self.table.delete(rep) {
+            unsafe {
+                libusb_unref_device(device.device);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl HostDeviceHandle for WasiUsbCtxView<'_> {
+    fn get_configuration(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<u8, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let mut config: i32 = 0;
+            let res = libusb_get_configuration(usb_device_handle.handle, &mut config);
+            match res {
+                0.. => Ok(config as u8),
+                _ => Err(LibusbError::from_raw(res)),
+            }
+        }
+    }
+
+    fn set_configuration(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        config: ConfigValue,
+    ) -> Result<(), LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let config_value = match config {
+                ConfigValue::Value(value) => value as i32,
+                ConfigValue::Unconfigured => 0,
+            };
+            let res = libusb_set_configuration(usb_device_handle.handle, config_value);
+            let result = LibusbError::check(res);
+            self.ctx.audit(
+                "set-configuration",
+                usb_device_handle.audit_device(),
+                &outcome(&result),
+                json!({ "configuration": config_value }),
+            );
+            result
+        }
+    }
+
+    fn claim_interface(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ifac: u8,
+    ) -> Result<Resource<ClaimedInterface>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        let key = usb_device_handle.interface_key(ifac);
+        let result = unsafe { device_descriptor(usb_device_handle.device) }
+            .and_then(|descriptor| self.ctx.check_interface(&descriptor, ifac))
+            .and_then(|()| self.ctx.arbitrate_claim(key))
+            .and_then(|fresh| unsafe {
+                let res = libusb_claim_interface(usb_device_handle.handle, ifac as i32);
+                debug!("Claim interface result: {:?}", res);
+                let result = LibusbError::check(res);
+                if result.is_err() && fresh {
+                    self.ctx.arbitrate_release(key);
+                }
+                result
+            });
+        if result.is_ok() {
+            if !usb_device_handle.claimed_interfaces.contains(&ifac) {
+                usb_device_handle.claimed_interfaces.push(ifac);
+                usb_device_handle.alt_settings.insert(ifac, 0);
+            }
+            *usb_device_handle.claims.entry(ifac).or_default() += 1;
+        }
+        self.ctx.audit(
+            "claim-interface",
+            usb_device_handle.audit_device(),
+            &outcome(&result),
+            json!({ "interface": ifac }),
+        );
+        result?;
+        let claimed = ClaimedInterface {
+            handle: self_.rep(),
+            raw_handle: usb_device_handle.handle,
+            interface: ifac,
+        };
+        self.table.push(claimed).or(Err(LibusbError::Other))
+    }
+
+    fn clear_halt(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        endpoint: u8,
+    ) -> Result<(), LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let res = libusb_clear_halt(usb_device_handle.handle, endpoint);
+            match res {
+                0.. => Ok(()),
+                _ => Err(LibusbError::from_raw(res)),
+            }
+        }
+    }
+
+    async fn reset_device(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<(), LibusbError> {
+        let (result, bus, port_path) = {
+            let usb_device_handle = live_handle(self.table, &self_)?;
+            unsafe {
+                // the device may re-enumerate, so identify it before resetting
+                let device = usb_device_handle.audit_device();
+                let dev = usb_device_handle.device;
+                let (bus, port_path) = (libusb_get_bus_number(dev), port_path(dev));
+                let result = usb_device_handle.reset();
+                usb_device_handle.reenumerated = result == Err(LibusbError::NotFound);
+                self.ctx.audit("reset-device", device, &outcome(&result), json!({}));
+                (result, bus, port_path)
+            }
+        };
+        if result == Err(LibusbError::NotFound) {
+            match port_path {
+                Ok(port_path) => self.queue_reenumerated(bus, port_path).await,
+                Err(e) => warn!("Cannot look for the re-enumerated device without its port path: {}", e),
+            }
+        }
+        result
+    }
+
+    fn alloc_streams(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        num_streams: u32,
+        endpoints: Vec<u8>,
+    ) -> Result<(), component::usb::device::LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        let num_endpoints = endpoints.len() as i32;
+        let endpoints_ptr = endpoints.as_ptr() as *mut u8;
+        unsafe {
+            let res = libusb_alloc_streams(
+                usb_device_handle.handle,
+                num_streams,
+                endpoints_ptr,
+                num_endpoints,
+            );
+            match res {
+                0.. => Ok(()),
+                _ => Err(LibusbError::from_raw(res)),
+            }
+        }
+    }
+
+    fn free_streams(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        endpoints: Vec<u8>,
+    ) -> Result<(), component::usb::device::LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        let num_endpoints = endpoints.len() as i32;
+        let endpoints_ptr = endpoints.as_ptr() as *mut u8;
+        unsafe {
+            let res = libusb_free_streams(usb_device_handle.handle, endpoints_ptr, num_endpoints);
+            match res {
+                0.. => Ok(()),
+                _ => Err(LibusbError::from_raw(res)),
+            }
+        }
+    }
+
+    fn kernel_driver_active(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ifac: u8,
+    ) -> Result<bool, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let res = libusb_kernel_driver_active(usb_device_handle.handle, ifac as i32);
+            match res {
+                0 => Ok(false),
+                1.. => Ok(true),
+                _ => Err(LibusbError::from_raw(res)),
+            }
+        }
+    }
+
+    fn detach_kernel_driver(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ifac: u8,
+    ) -> Result<(), LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            // the kernel keeps the interfaces the policy leaves to it
+            let result = device_descriptor(usb_device_handle.device)
+                .and_then(|descriptor| self.ctx.check_interface(&descriptor, ifac))
+                .and_then(|()| LibusbError::check(libusb_detach_kernel_driver(usb_device_handle.handle, ifac as i32)));
+            if result.is_ok() && !usb_device_handle.detached_kernel_drivers.contains(&ifac) {
+                usb_device_handle.detached_kernel_drivers.push(ifac);
+            }
+            self.ctx.audit(
+                "detach-kernel-driver",
+                usb_device_handle.audit_device(),
+                &outcome(&result),
+                json!({ "interface": ifac }),
+            );
+            result
+        }
+    }
+
+    fn set_auto_detach_kernel_driver(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        enable: bool,
+    ) -> Result<(), LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let res = libusb_set_auto_detach_kernel_driver(usb_device_handle.handle, enable as i32);
+            let result = LibusbError::check(res);
+            self.ctx.audit(
+                "set-auto-detach-kernel-driver",
+                usb_device_handle.audit_device(),
+                &outcome(&result),
+                json!({ "enable": enable }),
+            );
+            result
+        }
+    }
+
+    fn attach_kernel_driver(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ifac: u8,
+    ) -> Result<(), LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let res = libusb_attach_kernel_driver(usb_device_handle.handle, ifac as i32);
+            let result = LibusbError::check(res);
+            if result.is_ok() {
+                usb_device_handle.detached_kernel_drivers.retain(|i| *i != ifac);
+            }
+            self.ctx.audit(
+                "attach-kernel-driver",
+                usb_device_handle.audit_device(),
+                &outcome(&result),
+                json!({ "interface": ifac }),
+            );
+            result
+        }
+    }
+
+    fn get_string_descriptor(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        index: u8,
+        langid: u16,
+    ) -> Result<String, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        usb_device_handle.string_descriptor(index, langid)
+    }
+
+    fn get_supported_languages(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<Vec<u16>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        usb_device_handle.supported_languages()
+    }
+
+    fn get_manufacturer(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<Option<String>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        usb_device_handle.manufacturer()
+    }
+
+    fn get_product(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<Option<String>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        usb_device_handle.product()
+    }
+
+    fn get_serial_number(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<Option<String>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        usb_device_handle.serial_number()
+    }
+
+    fn get_raw_configuration_descriptor(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        config_index: u8,
+    ) -> Result<Vec<u8>, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            // read the header first to learn wTotalLength, then everything
+            let mut header = [0u8; 9];
+            let res = libusb_get_descriptor(usb_device_handle.handle, LIBUSB_DT_CONFIG, config_index, 0, header.as_mut_ptr(), header.len() as i32);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            if res < 4 {
+                return Err(LibusbError::Io);
+            }
+            let total_length = u16::from_le_bytes([header[2], header[3]]);
+            let mut data = vec![0u8; total_length as usize];
+            let res = libusb_get_descriptor(usb_device_handle.handle, LIBUSB_DT_CONFIG, config_index, 0, data.as_mut_ptr(), data.len() as i32);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            data.truncate(res as usize);
+            Ok(data)
+        }
+    }
+
+    fn get_bos_descriptor(&mut self, self_: Resource<UsbDeviceHandle>) -> Result<BosDescriptor, LibusbError> {
+        let usb_device_handle = live_handle(self.table, &self_)?;
+        unsafe {
+            let mut bos: *const libusb_bos_descriptor = std::ptr::null();
+            let res = libusb_get_bos_descriptor(usb_device_handle.handle, &mut bos);
+            if res < 0 {
+                return Err(LibusbError::from_raw(res));
+            }
+            let descriptor = generate_bos_descriptor(bos);
+            libusb_free_bos_descriptor(bos as *mut _);
+            Ok(descriptor)
+        }
+    }
+
+    fn new_control_transfer(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        setup: TransferSetup,
+        buf_size: u32,
+        timeout_ms: u32,
+    ) -> Result<Resource<Transfer>, LibusbError> {
+        let target = self.control_target(&self_)?;
+        let opts = TransferOptions { timeout_ms, stream_id: 0, iso_packets: 0 };
+        self.alloc_transfer(target, Some(setup), buf_size, opts)
+    }
+
+    async fn control_in(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        setup: TransferSetup,
+        length: u16,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, LibusbError> {
+        if setup.bm_request_type & 0x80 == 0 {
+            return Err(LibusbError::InvalidParam);
+        }
+        let transfer = {
+            let target = self.control_target(&self_)?;
+            self.submit_sync(target, Some(setup), length as u32, timeout_ms, Vec::new())?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await
+    }
+
+    async fn control_out(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        setup: TransferSetup,
+        data: Vec<u8>,
+        timeout_ms: u32,
+    ) -> Result<(), LibusbError> {
+        if setup.bm_request_type & 0x80 != 0 || data.len() > u16::MAX as usize {
+            return Err(LibusbError::InvalidParam);
+        }
+        let transfer = {
+            let target = self.control_target(&self_)?;
+            self.submit_sync(target, Some(setup), data.len() as u32, timeout_ms, data)?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await?;
+        Ok(())
+    }
+
+    async fn bulk_read(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ep: Resource<Endpoint>,
+        length: u32,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, LibusbError> {
+        let transfer = {
+            let target = self.endpoint_target(&self_, &ep, TransferType::Bulk, Direction::In)?;
+            self.submit_sync(target, None, length, timeout_ms, Vec::new())?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await
+    }
+
+    async fn bulk_write(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ep: Resource<Endpoint>,
+        data: Vec<u8>,
+        timeout_ms: u32,
+    ) -> Result<(), LibusbError> {
+        let transfer = {
+            let target = self.endpoint_target(&self_, &ep, TransferType::Bulk, Direction::Out)?;
+            self.submit_sync(target, None, data.len() as u32, timeout_ms, data)?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await?;
+        Ok(())
+    }
+
+    async fn interrupt_read(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ep: Resource<Endpoint>,
+        length: u32,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, LibusbError> {
+        let transfer = {
+            let target = self.endpoint_target(&self_, &ep, TransferType::Interrupt, Direction::In)?;
+            self.submit_sync(target, None, length, timeout_ms, Vec::new())?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await
+    }
+
+    async fn interrupt_write(
+        &mut self,
+        self_: Resource<UsbDeviceHandle>,
+        ep: Resource<Endpoint>,
+        data: Vec<u8>,
+        timeout_ms: u32,
+    ) -> Result<(), LibusbError> {
+        let transfer = {
+            let target = self.endpoint_target(&self_, &ep, TransferType::Interrupt, Direction::Out)?;
+            self.submit_sync(target, None, data.len() as u32, timeout_ms, data)?
+        };
+        component::usb::transfers::Host::await_transfer(self, transfer).await?;
+        Ok(())
+    }
+
+    fn device_id(&mut self, self_: Resource<UsbDeviceHandle>) -> u64 {
+        let usb_device_handle = self.table.get(&self_).expect("Failed to get device handle");
+        usb_device_handle.session
+    }
+
+    fn on_disconnect(&mut self, self_: Resource<UsbDeviceHandle>) -> Resource<DynPollable> {
+        let usb_device_handle = self.table.get(&self_).expect("Failed to get device handle");
+        let pollable = DisconnectPollable(usb_device_handle.disconnect.clone());
+        let pollable = self.table.push(pollable).expect("Failed to push pollable");
+        subscribe(self.table, pollable).expect("Failed to subscribe")
+    }
+
+    fn close(&mut self, self_: Resource<UsbDeviceHandle>) {
+        debug!("close handle: does not do anything as drop will be automatically called");
+        //
+        // if (!self_.owned()) {
+        //     return Ok(())
+        // }
+        //
+        // if let Ok(handle) = self.table.get(&self_) {
+        //     unsafe {
+        //         libusb_close(handle.handle);
+        //     }
+        //     self.table.delete(self_).expect("resource was al dada");
+        // }
+        
+    }
+
+    fn drop(&mut self, rep: Resource<UsbDeviceHandle>) -> Result<(), Error> {
+        debug!("Drop device handle: {}", rep.owned());
+        if let Ok(handle) = self.table.get(&rep) {
+            self.ctx.audit("close", handle.audit_device(), "ok", json!({}));
+            for ifac in &handle.claimed_interfaces {
+                self.ctx.arbitrate_release(handle.interface_key(*ifac));
+            }
+            // closing happens when the handle itself is dropped
+            self.table.delete(rep).expect("resource was al dada");
+        }
+        Ok(())
+    }
+}
+
+impl component::usb::device::Host for WasiUsbCtxView<'_> {
+    fn init(&mut self) -> Result<(), component::usb::device::LibusbError> {
+        debug!("Init host");
+        if self.ctx.context.is_some() {
+            return Ok(());
+        }
+        unsafe {
+            let ctx = match self.ctx.shared_context {
+                Some(ctx) => ctx,
+                None => {
+                    let mut ctx: *mut libusb_context = std::ptr::null_mut();
+                    let res = libusb_init(&mut ctx);
+                    if res < 0 {
+                        return Err(LibusbError::from_raw(res));
+                    }
+                    ctx
+                }
+            };
+
+            self.ctx.context = Some(ctx);
+
+            if libusb_has_capability(LIBUSB_CAP_HAS_HOTPLUG) != 0 {
+                // the context keeps the watch alive until the callback is deregistered
+                let user_data = Arc::as_ptr(&self.ctx.disconnects) as *mut std::ffi::c_void;
+                let mut handle: libusb_hotplug_callback_handle = 0;
+                let rc = libusb_hotplug_register_callback(
+                    ctx,
+                    LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT,
+                    LIBUSB_HOTPLUG_NO_FLAGS,
+                    LIBUSB_HOTPLUG_MATCH_ANY,
+                    LIBUSB_HOTPLUG_MATCH_ANY,
+                    LIBUSB_HOTPLUG_MATCH_ANY,
+                    disconnect_cb,
+                    user_data,
+                    &mut handle,
+                );
+                if rc < 0 {
+                    warn!("Failed to watch for disconnects: {}", LibusbError::from_raw(rc));
+                } else {
+                    self.ctx.disconnect_handle = Some(handle);
+                }
+            }
+
+            let flag = Arc::new(AtomicBool::new(true));
+            self.ctx.event_loop_flag = Some(flag.clone());
+            let ctx_num = ctx as usize;
+            //spawn new thread to handle events (with timeout)
+            let handle = thread::spawn(move || {
+                let ctx = ctx_num as *mut libusb_context;
+                let tv = timeval { tv_sec: 0, tv_usec: 20_000 }; // 20 ms
+                while flag.load(Ordering::SeqCst) {
+                    let rc = libusb_handle_events_timeout_completed(ctx_num as *mut libusb_context, &tv, std::ptr::null_mut());
+                    if rc < 0 {
+                        error!("Error in libusb_handle_events_timeout: {}", rc);
+                        break;
+                    }
+                }
+            });
+            self.ctx.event_thread = Some(handle);
+            Ok(())
+        }
+    }
+
+    fn list_devices(
+        &mut self,
+        filter: Option<DeviceFilter>,
+    ) -> Result<Vec<(Resource<UsbDevice>, DeviceDescriptor, DeviceLocation, Option<DeviceMetadata>)>, LibusbError> {
+        info!("list_devices called.");
+        let context = self.ctx.context.ok_or(LibusbError::NotFound)?;
+        let (found, denied) = unsafe { enumerate_devices(context, &self.ctx.allowed_usbdevices)? };
+        let found = match &filter {
+            Some(filter) => unsafe { filter_devices(self.ctx, found, filter) },
+            None => found,
+        };
+        let mut devices = Vec::new();
+        let mut audited: Vec<AuditDevice> = Vec::new();
+        for device in found {
+            audited.push(AuditDevice {
+                bus: device.location.bus_number,
+                address: device.location.device_address,
+                vendor_id: device.descriptor.vendor_id,
+                product_id: device.descriptor.product_id,
+            });
+            let metadata = unsafe { port_path(device.device) }
+                .and_then(|port_path| read_metadata(&self.ctx.sysfs_root, device.location.bus_number, &port_path))
+                .ok();
+            let resource = self
+                .table
+                .push(UsbDevice { device: device.device })
+                .or(Err(LibusbError::Other))?;
+            devices.push((resource, device.descriptor, device.location, metadata));
+        }
+        self.ctx.audit("list-devices", None, "ok", json!({ "devices": audited, "denied": denied }));
+        info!("Returning {} device(s).", devices.len());
+        Ok(devices)
+    }
+
+    fn open_device_with_vid_pid(
+        &mut self,
+        vendor_id: u16,
+        product_id: u16,
+    ) -> Result<Resource<UsbDeviceHandle>, LibusbError> {
+        let context = self.ctx.context.ok_or(LibusbError::NotFound)?;
+        let filter = DeviceFilter {
+            vendor_id: Some(vendor_id),
+            product_id: Some(product_id),
+            class: None,
+            bus: None,
+            port_path: None,
+            serial_number: None,
+        };
+        unsafe {
+            let (found, _) = enumerate_devices(context, &self.ctx.allowed_usbdevices)?;
+            let mut found = filter_devices(self.ctx, found, &filter).into_iter();
+            let Some(first) = found.next() else {
+                return Err(LibusbError::NotFound);
+            };
+            for device in found {
+                libusb_unref_device(device.device);
+            }
+            let handle = self.ctx.open_device(first.device);
+            self.ctx.audit("open", AuditDevice::from_raw(first.device), &outcome(&handle), json!({}));
+            let handle = handle.inspect_err(|_| libusb_unref_device(first.device))?;
+            // the handle keeps its own reference to the device
+            libusb_unref_device(first.device);
+            self.ctx.disconnects.add(handle.session, &handle.disconnect);
+            self.table.push(handle).or(Err(LibusbError::Other))
+        }
+    }
+
+    fn capabilities(&mut self) -> BackendCapabilities {
+        unsafe {
+            let version = &*libusb_get_version();
+            let rc = if version.rc.is_null() {
+                String::new()
+            } else {
+                std::ffi::CStr::from_ptr(version.rc).to_string_lossy().into_owned()
+            };
+            BackendCapabilities {
+                has_hotplug: libusb_has_capability(LIBUSB_CAP_HAS_HOTPLUG) != 0,
+                supports_detach_kernel_driver: libusb_has_capability(LIBUSB_CAP_SUPPORTS_DETACH_KERNEL_DRIVER) != 0,
+                has_hid_access: libusb_has_capability(LIBUSB_CAP_HAS_HID_ACCESS) != 0,
+                backend: "libusb".to_string(),
+                version: format!("{}.{}.{}{}", version.major, version.minor, version.micro, rc),
+            }
+        }
+    }
+}
+
+impl HostClaimedInterface for WasiUsbCtxView<'_> {
+    fn interface_number(&mut self, self_: Resource<ClaimedInterface>) -> u8 {
+        self.table.get(&self_).expect("Failed to get interface").interface
+    }
+
+    fn alt_setting(&mut self, self_: Resource<ClaimedInterface>) -> u8 {
+        let claimed = self.table.get(&self_).expect("Failed to get interface");
+        let (handle, raw_handle, interface) = (claimed.handle, claimed.raw_handle, claimed.interface);
+        claimed_handle(self.table, handle, raw_handle, interface)
+            .ok()
+            .and_then(|usb_device_handle| usb_device_handle.alt_settings.get(&interface).copied())
+            .unwrap_or(0)
+    }
+
+    fn set_alt_setting(&mut self, self_: Resource<ClaimedInterface>, alt_setting: u8) -> Result<(), LibusbError> {
+        let claimed = self.table.get(&self_).expect("Failed to get interface");
+        let (handle, raw_handle, interface) = (claimed.handle, claimed.raw_handle, claimed.interface);
+        let usb_device_handle = claimed_handle(self.table, handle, raw_handle, interface)?;
+        unsafe {
+            LibusbError::check(libusb_set_interface_alt_setting(
+                usb_device_handle.handle,
+                interface as i32,
+                alt_setting as i32,
+            ))?;
+        }
+        usb_device_handle.alt_settings.insert(interface, alt_setting);
+        Ok(())
+    }
+
+    fn endpoints(&mut self, self_: Resource<ClaimedInterface>) -> Result<Vec<Resource<Endpoint>>, LibusbError> {
+        let claimed = self.table.get(&self_).expect("Failed to get interface");
+        let (handle, raw_handle, interface) = (claimed.handle, claimed.raw_handle, claimed.interface);
+        let descriptors = claimed_handle(self.table, handle, raw_handle, interface)?.endpoint_descriptors(interface)?;
+        descriptors
+            .into_iter()
+            .map(|descriptor| {
+                self.table
+                    .push(Endpoint { handle, raw_handle, interface, descriptor })
+                    .or(Err(LibusbError::Other))
+            })
+            .collect()
+    }
+
+    fn endpoint(&mut self, self_: Resource<ClaimedInterface>, address: u8) -> Result<Resource<Endpoint>, LibusbError> {
+        let claimed = self.table.get(&self_).expect("Failed to get interface");
+        let (handle, raw_handle, interface) = (claimed.handle, claimed.raw_handle, claimed.interface);
+        let descriptor = claimed_handle(self.table, handle, raw_handle, interface)?
+            .endpoint_descriptors(interface)?
+            .into_iter()
+            .find(|descriptor| descriptor.endpoint_address == address)
+            .ok_or(LibusbError::NotFound)?;
+        self.table
+            .push(Endpoint { handle, raw_handle, interface, descriptor })
+            .or(Err(LibusbError::Other))
+    }
+
+    fn drop(&mut self, rep: Resource<ClaimedInterface>) -> Result<(), Error> {
+        trace!("Drop claimed interface");
+        let claimed = self.table.delete(rep)?;
+        // nothing to release if the handle was closed, closing released the interface already
+        if let Ok(usb_device_handle) = claimed_handle(self.table, claimed.handle, claimed.raw_handle, claimed.interface) {
+            let ifac = claimed.interface;
+            // other resources for the same interface keep it claimed
+            let claims = usb_device_handle.claims.entry(ifac).or_default();
+            *claims = claims.saturating_sub(1);
+            if *claims > 0 {
+                return Ok(());
+            }
+            usb_device_handle.claims.remove(&ifac);
+            let result = unsafe { LibusbError::check(libusb_release_interface(usb_device_handle.handle, ifac as i32)) };
+            if result.is_ok() {
+                usb_device_handle.claimed_interfaces.retain(|i| *i != ifac);
+                usb_device_handle.alt_settings.remove(&ifac);
+                self.ctx.arbitrate_release(usb_device_handle.interface_key(ifac));
+            }
+            self.ctx.audit(
+                "release-interface",
+                usb_device_handle.audit_device(),
+                &outcome(&result),
+                json!({ "interface": ifac }),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl HostEndpoint for WasiUsbCtxView<'_> {
+    fn address(&mut self, self_: Resource<Endpoint>) -> u8 {
+        self.table.get(&self_).expect("Failed to get endpoint").descriptor.endpoint_address
+    }
+
+    fn direction(&mut self, self_: Resource<Endpoint>) -> Direction {
+        let endpoint = self.table.get(&self_).expect("Failed to get endpoint");
+        if endpoint.descriptor.endpoint_address & 0x80 != 0 {
+            Direction::In
+        } else {
+            Direction::Out
+        }
+    }
+
+    fn transfer_type(&mut self, self_: Resource<Endpoint>) -> TransferType {
+        self.table.get(&self_).expect("Failed to get endpoint").transfer_type()
+    }
+
+    fn max_packet_size(&mut self, self_: Resource<Endpoint>) -> u16 {
+        self.table.get(&self_).expect("Failed to get endpoint").descriptor.max_packet_size
+    }
+
+    fn descriptor(&mut self, self_: Resource<Endpoint>) -> EndpointDescriptor {
+        self.table.get(&self_).expect("Failed to get endpoint").descriptor.clone()
+    }
+
+    fn new_transfer(
+        &mut self,
+        self_: Resource<Endpoint>,
+        buf_size: u32,
+        opts: TransferOptions,
+    ) -> Result<Resource<Transfer>, LibusbError> {
+        let endpoint = self.table.get(&self_).expect("Failed to get endpoint");
+        let (handle, raw_handle, interface) = (endpoint.handle, endpoint.raw_handle, endpoint.interface);
+        let (address, transfer_type) = (endpoint.descriptor.endpoint_address, endpoint.transfer_type());
+        let usb_handle = claimed_handle(self.table, handle, raw_handle, interface)?;
+        let target = TransferTarget {
+            handle: raw_handle,
+            disconnect: usb_handle.disconnect.clone(),
+            device: self.ctx.audit_log.as_ref().and_then(|_| usb_handle.audit_device()),
+            endpoint: address,
+            transfer_type,
+        };
+        self.alloc_transfer(target, None, buf_size, opts)
+    }
+
+    fn clear_halt(&mut self, self_: Resource<Endpoint>) -> Result<(), LibusbError> {
+        let endpoint = self.table.get(&self_).expect("Failed to get endpoint");
+        let (handle, raw_handle, interface) = (endpoint.handle, endpoint.raw_handle, endpoint.interface);
+        let address = endpoint.descriptor.endpoint_address;
+        claimed_handle(self.table, handle, raw_handle, interface)?;
+        unsafe { LibusbError::check(libusb_clear_halt(raw_handle, address)) }
+    }
+
+    fn drop(&mut self, rep: Resource<Endpoint>) -> Result<(), Error> {
+        self.table.delete(rep)?;
+        Ok(())
+    }
+}
+
+impl component::usb::usb_hotplug::Host for WasiUsbCtxView<'_> {
+    fn enable_hotplug(&mut self) -> Result<(), LibusbError> {
+        if self.ctx.hotplug_enabled {
+            return Ok(());
+        }
+        unsafe {
+            if libusb_has_capability(LIBUSB_CAP_HAS_HOTPLUG) == 0 {
+                // no hotplug support
+                return Err(LibusbError::NotSupported);
+            }
+
+            // the context keeps the state alive until the callback is deregistered
+            let state = Arc::new(HotplugState {
+                allowed_usbdevices: self.ctx.allowed_usbdevices.clone(),
+                queue: Mutex::new(VecDeque::new()),
+            });
+            let user_data = Arc::as_ptr(&state) as *mut std::ffi::c_void;
+
+            let mut handle: libusb_hotplug_callback_handle = 0;
+            let rc = libusb_hotplug_register_callback(
+                self.ctx.context.ok_or(LibusbError::NotFound)?,
+                LIBUSB_HOTPLUG_EVENT_DEVICE_ARRIVED | LIBUSB_HOTPLUG_EVENT_DEVICE_LEFT,
+                LIBUSB_HOTPLUG_NO_FLAGS,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                LIBUSB_HOTPLUG_MATCH_ANY,
+                hotplug_cb,
+                user_data,
+                &mut handle,
+            );
+            if rc < 0 {
+                return Err(LibusbError::from_raw(rc));
+            }
+            self.ctx.hotplug_handle = Some(handle);
+            self.ctx.hotplug_state = Some(state);
+            self.ctx.hotplug_enabled = true;
+        }
+
+        Ok(())
+    }
+
+    fn poll_events(&mut self) -> Vec<(Event, Info, Resource<UsbDevice>)> {
+        // events of this store, e.g. re-enumerations after a reset, come first
+        let mut q: VecDeque<_> = self.ctx.pending_events.drain(..).collect();
+        if let Some(state) = &self.ctx.hotplug_state {
+            q.extend(state.queue.lock().unwrap().drain(..));
+        }
+        let mut out = Vec::with_capacity(q.len());
+        while let Some(ev) = q.pop_front() {
+            let device = self
+                .table
+                .push(ev.2)
+                .or(Err(LibusbError::Other))
+                .unwrap();
+            let ev2 = (ev.0, ev.1, device);
+            out.push(ev2);
+        }
+        out
+    }
+}
@@ -0,0 +1,375 @@
+//! Host implementation of the WASI-USB interfaces for wasmtime.
+//!
+//! Embedders keep a [`WasiUsbCtx`] in their store state, implement [`WasiUsbView`] for it
+//! and call [`add_to_linker_async`]:
+//!
+//! ```ignore
+//! struct MyState {
+//!     table: ResourceTable,
+//!     usb: WasiUsbCtx,
+//! }
+//!
+//! impl WasiUsbView for MyState {
+//!     fn usb(&mut self) -> WasiUsbCtxView<'_> {
+//!         WasiUsbCtxView { ctx: &mut self.usb, table: &mut self.table }
+//!     }
+//! }
+//!
+//! let mut linker = Linker::<MyState>::new(&engine);
+//! usb_wasi_host::add_to_linker_async(&mut linker)?;
+//! ```
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use libusb1_sys::{libusb_context, libusb_device, libusb_exit, libusb_hotplug_callback_handle, libusb_hotplug_deregister_callback};
+use log::warn;
+use wasmtime::component::{bindgen, Linker, ResourceTable};
+
+use crate::arbiter::{InterfaceArbiter, InterfaceKey};
+use crate::audit::{AuditDevice, AuditLog};
+use crate::broker::BrokerClient;
+use crate::component::usb::descriptors::DeviceDescriptor;
+use crate::component::usb::errors::LibusbError;
+use crate::component::usb::usb_hotplug::{Event, Info};
+use crate::host::{DisconnectWatch, HotplugState, InFlightTransfer};
+
+pub mod arbiter;
+pub mod audit;
+pub mod broker;
+pub mod descriptors;
+pub mod driver;
+pub mod enumerate;
+mod host;
+pub mod policy;
+pub mod sysfs;
+
+pub use crate::host::{ClaimedInterface, DisconnectPollable, Endpoint, UsbDevice, UsbDeviceHandle, UsbTransfer};
+pub use crate::policy::{AllowedInterfaces, AllowedUSBDevices, USBDeviceIdentifier};
+
+bindgen!({
+    world: "host",
+    path: "../wit",
+    with: {
+        "component:usb/transfers/transfer": UsbTransfer,
+        "component:usb/device/usb-device": UsbDevice,
+        "component:usb/device/device-handle": UsbDeviceHandle,
+        "component:usb/device/claimed-interface": ClaimedInterface,
+        "component:usb/device/endpoint": Endpoint,
+        "wasi:io/poll": wasmtime_wasi::bindings::io::poll,
+    },
+    async: {
+        only_imports: [
+            "await-transfer",
+            "await-any",
+            "[method]device-handle.reset-device",
+            "[method]device-handle.control-in",
+            "[method]device-handle.control-out",
+            "[method]device-handle.bulk-read",
+            "[method]device-handle.bulk-write",
+            "[method]device-handle.interrupt-read",
+            "[method]device-handle.interrupt-write",
+        ]
+    },
+});
+
+/// Limits applied to the requests of a guest.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UsbLimits {
+    /// Largest buffer a single transfer may allocate
+    pub max_transfer_size: Option<u32>,
+    /// Largest number of packets in a single isochronous transfer
+    pub max_iso_packets: Option<u32>,
+}
+
+/// Per-store state of the WASI-USB host.
+pub struct WasiUsbCtx {
+    pub(crate) context: Option<*mut libusb_context>, // do not need contexts as passing a nullptr will give the default context each time
+    pub(crate) shared_context: Option<*mut libusb_context>,
+    pub(crate) event_loop_flag: Option<Arc<AtomicBool>>,
+    pub(crate) event_thread: Option<thread::JoinHandle<()>>,
+    pub(crate) hotplug_enabled: bool,
+    pub(crate) hotplug_handle: Option<libusb_hotplug_callback_handle>,
+    pub(crate) hotplug_state: Option<Arc<HotplugState>>,
+    pub(crate) disconnect_handle: Option<libusb_hotplug_callback_handle>,
+    pub(crate) disconnects: Arc<DisconnectWatch>,
+    pub(crate) allowed_usbdevices: AllowedUSBDevices,
+    pub(crate) allowed_interfaces: AllowedInterfaces,
+    pub(crate) audit_log: Option<Arc<AuditLog>>,
+    pub(crate) limits: UsbLimits,
+    pub(crate) arbiter: Option<(Arc<InterfaceArbiter>, String)>,
+    pub(crate) in_flight: Vec<InFlightTransfer>,
+    // events raised by this store itself, delivered through poll-events
+    pub(crate) pending_events: VecDeque<(Event, Info, UsbDevice)>,
+    pub(crate) sysfs_root: PathBuf,
+    pub(crate) broker: Option<Arc<BrokerClient>>,
+}
+
+unsafe impl Send for WasiUsbCtx {}
+unsafe impl Sync for WasiUsbCtx {}
+
+impl WasiUsbCtx {
+    pub fn builder() -> WasiUsbCtxBuilder {
+        WasiUsbCtxBuilder::new()
+    }
+
+    pub(crate) fn audit(&self, operation: &str, device: Option<AuditDevice>, result: &str, fields: serde_json::Value) {
+        if let Some(audit_log) = &self.audit_log {
+            audit_log.record(operation, device, result, fields);
+        }
+    }
+
+    /// Let the transfers the guest still has in flight finish, cancelling those that take
+    /// longer than `grace`. Returns false if libusb did not complete them in time either.
+    /// The libusb events must keep being handled while this runs.
+    pub async fn drain_transfers(&mut self, grace: Duration) -> bool {
+        if !self.wait_for_transfers(grace).await {
+            for t in &self.in_flight {
+                unsafe {
+                    t.completed.cancel(t.transfer);
+                }
+            }
+            if !self.wait_for_transfers(grace).await {
+                return false;
+            }
+        }
+        self.in_flight.clear();
+        true
+    }
+
+    async fn wait_for_transfers(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.in_flight.iter().any(|t| !t.completed.is_set()) {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        true
+    }
+
+    /// Open `device` for the guest, through the broker if the host has one. Hosts that hand
+    /// a guest an already open handle, like the driver host, open it here as well.
+    ///
+    /// # Safety
+    /// `device` must be a valid libusb device of this context.
+    pub unsafe fn open_device(&self, device: *mut libusb_device) -> Result<UsbDeviceHandle, LibusbError> {
+        let mut handle = match &self.broker {
+            Some(broker) => UsbDeviceHandle::open_brokered(self.libusb_context(), device, broker)?,
+            None => UsbDeviceHandle::open(device)?,
+        };
+        handle.session = self.session_id(device);
+        Ok(handle)
+    }
+
+    /// The id of `device` as the guest sees it, see [`enumerate::session_id`].
+    ///
+    /// # Safety
+    /// `device` must be a valid libusb device of this context.
+    pub(crate) unsafe fn session_id(&self, device: *mut libusb_device) -> u64 {
+        enumerate::session_id(self.libusb_context(), device)
+    }
+
+    /// The context devices are opened in, a shared one is in use before the guest calls `init`.
+    fn libusb_context(&self) -> *mut libusb_context {
+        self.context.or(self.shared_context).unwrap_or(std::ptr::null_mut())
+    }
+
+    /// Fails with access for an interface the policy of the device does not list.
+    pub(crate) fn check_interface(&self, device: &DeviceDescriptor, interface: u8) -> Result<(), LibusbError> {
+        let id = USBDeviceIdentifier { vendor_id: device.vendor_id, product_id: device.product_id };
+        if self.allowed_interfaces.is_allowed(&id, interface) {
+            return Ok(());
+        }
+        warn!("Interface {} of {:04x}:{:04x} is not in the policy", interface, id.vendor_id, id.product_id);
+        Err(LibusbError::Access)
+    }
+
+    /// Whether the claim is new, a claim the owner already held must outlive a failed re-claim.
+    pub(crate) fn arbitrate_claim(&self, key: InterfaceKey) -> Result<bool, LibusbError> {
+        let Some((arbiter, owner)) = &self.arbiter else {
+            return Ok(false);
+        };
+        arbiter.claim(key, owner).map_err(|current| {
+            warn!("Interface {:?} is already claimed by {}", key, current);
+            LibusbError::Busy
+        })
+    }
+
+    pub(crate) fn arbitrate_release(&self, key: InterfaceKey) {
+        if let Some((arbiter, owner)) = &self.arbiter {
+            arbiter.release(key, owner);
+        }
+    }
+}
+
+impl Drop for WasiUsbCtx {
+    fn drop(&mut self) {
+        if let Some((arbiter, owner)) = &self.arbiter {
+            arbiter.release_owner(owner);
+        }
+        if let Some(flag) = &self.event_loop_flag {
+            flag.store(false, Ordering::SeqCst);
+        }
+        if let Some(thread) = self.event_thread.take() {
+            let _ = thread.join();
+        }
+        if let Some(context) = self.context {
+            unsafe {
+                if let Some(handle) = self.hotplug_handle.take() {
+                    libusb_hotplug_deregister_callback(context, handle);
+                }
+                if let Some(handle) = self.disconnect_handle.take() {
+                    libusb_hotplug_deregister_callback(context, handle);
+                }
+                if self.shared_context.is_none() {
+                    enumerate::release_sessions(context);
+                    libusb_exit(context);
+                }
+            }
+        }
+    }
+}
+
+/// Builder for [`WasiUsbCtx`], modelled after `WasiCtxBuilder`.
+pub struct WasiUsbCtxBuilder {
+    allowed_usbdevices: AllowedUSBDevices,
+    allowed_interfaces: AllowedInterfaces,
+    audit_log: Option<Arc<AuditLog>>,
+    shared_context: Option<*mut libusb_context>,
+    limits: UsbLimits,
+    arbiter: Option<(Arc<InterfaceArbiter>, String)>,
+    sysfs_root: PathBuf,
+    broker: Option<Arc<BrokerClient>>,
+}
+
+impl Default for WasiUsbCtxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WasiUsbCtxBuilder {
+    /// By default every device is visible and a new libusb context is created on `init`.
+    pub fn new() -> Self {
+        Self {
+            allowed_usbdevices: AllowedUSBDevices::Denied(Vec::new()),
+            allowed_interfaces: AllowedInterfaces::default(),
+            audit_log: None,
+            shared_context: None,
+            limits: UsbLimits::default(),
+            arbiter: None,
+            sysfs_root: PathBuf::from(sysfs::DEFAULT_SYSFS_ROOT),
+            broker: None,
+        }
+    }
+
+    /// Restrict the devices the guest can see and open.
+    pub fn policy(&mut self, allowed_usbdevices: AllowedUSBDevices) -> &mut Self {
+        self.allowed_usbdevices = allowed_usbdevices;
+        self
+    }
+
+    /// Restrict the interfaces the guest can claim, e.g. to those of a [`PolicyFile`](policy::PolicyFile).
+    pub fn interfaces(&mut self, allowed_interfaces: AllowedInterfaces) -> &mut Self {
+        self.allowed_interfaces = allowed_interfaces;
+        self
+    }
+
+    /// Record the operations of the guest in an audit log.
+    pub fn audit_log(&mut self, audit_log: Arc<AuditLog>) -> &mut Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Use an existing libusb context as backend instead of creating one on `init`.
+    ///
+    /// # Safety
+    /// The context must stay valid for as long as the built [`WasiUsbCtx`] is in use.
+    pub unsafe fn libusb_context(&mut self, context: *mut libusb_context) -> &mut Self {
+        self.shared_context = Some(context);
+        self
+    }
+
+    pub fn limits(&mut self, limits: UsbLimits) -> &mut Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Share interface ownership with other stores, claims are made in the name of `owner`.
+    /// The stores must share a libusb context as well, see [`InterfaceKey`].
+    pub fn arbiter(&mut self, arbiter: Arc<InterfaceArbiter>, owner: impl Into<String>) -> &mut Self {
+        self.arbiter = Some((arbiter, owner.into()));
+        self
+    }
+
+    /// Read device metadata from another sysfs tree than `/sys/bus/usb/devices`, e.g. a fake one.
+    pub fn sysfs_root(&mut self, root: impl Into<PathBuf>) -> &mut Self {
+        self.sysfs_root = root.into();
+        self
+    }
+
+    /// Open devices through a privileged broker instead of opening their nodes directly.
+    pub fn broker(&mut self, broker: Arc<BrokerClient>) -> &mut Self {
+        self.broker = Some(broker);
+        self
+    }
+
+    pub fn build(&mut self) -> WasiUsbCtx {
+        WasiUsbCtx {
+            context: None,
+            shared_context: self.shared_context,
+            event_loop_flag: None,
+            event_thread: None,
+            hotplug_enabled: false,
+            hotplug_handle: None,
+            hotplug_state: None,
+            disconnect_handle: None,
+            disconnects: Arc::new(DisconnectWatch::default()),
+            allowed_usbdevices: self.allowed_usbdevices.clone(),
+            allowed_interfaces: self.allowed_interfaces.clone(),
+            audit_log: self.audit_log.clone(),
+            limits: self.limits,
+            arbiter: self.arbiter.clone(),
+            in_flight: Vec::new(),
+            pending_events: VecDeque::new(),
+            sysfs_root: self.sysfs_root.clone(),
+            broker: self.broker.clone(),
+        }
+    }
+}
+
+/// Borrowed view of the WASI-USB state and the resource table it stores its resources in.
+pub struct WasiUsbCtxView<'a> {
+    pub ctx: &'a mut WasiUsbCtx,
+    pub table: &'a mut ResourceTable,
+}
+
+/// Gives the WASI-USB host access to its state inside the store data, analogous to `WasiView`.
+pub trait WasiUsbView: Send {
+    fn usb(&mut self) -> WasiUsbCtxView<'_>;
+}
+
+/// Add all WASI-USB interfaces to `linker`. The linker must be used with an async engine.
+pub fn add_to_linker_async<T: WasiUsbView + 'static>(linker: &mut Linker<T>) -> wasmtime::Result<()> {
+    let closure = type_annotate::<T, _>(|t| t.usb());
+    component::usb::errors::add_to_linker_get_host(linker, closure)?;
+    component::usb::configuration::add_to_linker_get_host(linker, closure)?;
+    component::usb::descriptors::add_to_linker_get_host(linker, closure)?;
+    component::usb::transfers::add_to_linker_get_host(linker, closure)?;
+    component::usb::device::add_to_linker_get_host(linker, closure)?;
+    component::usb::usb_hotplug::add_to_linker_get_host(linker, closure)?;
+    Ok(())
+}
+
+// Helps the compiler infer the higher-ranked lifetime of the host getter closure.
+fn type_annotate<T, F>(val: F) -> F
+where
+    F: Fn(&mut T) -> WasiUsbCtxView<'_>,
+{
+    val
+}
@@ -0,0 +1,124 @@
+use std::future::Future;
+use std::time::Duration;
+
+use clap::Args;
+use log::{error, warn};
+use serde::Deserialize;
+use wasmtime::{Engine, Error, Store, StoreLimitsBuilder, Trap, UpdateDeadline};
+
+use crate::MyState;
+
+/// Interval at which the epoch of an engine with epoch interruption is advanced.
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// How long transfers of a guest that tripped a limit get before they are cancelled.
+const DRAIN_GRACE: Duration = Duration::from_millis(500);
+
+/// Bounds on the resources a single guest may use. When a limit trips the guest is
+/// torn down: its transfers are cancelled, its interfaces released and the kernel
+/// drivers it detached are reattached before the error is reported.
+#[derive(Args, Deserialize, Debug, Default, Clone)]
+pub struct GuestLimits {
+    /// Fuel the guest may consume before it traps (roughly one unit per wasm instruction)
+    #[arg(long)]
+    pub fuel: Option<u64>,
+
+    /// Interrupt the guest after this many milliseconds of wall-clock epoch ticks, which includes time blocked in host USB calls
+    #[arg(long)]
+    pub epoch_deadline: Option<u64>,
+
+    /// Wall-clock limit in seconds for the whole run, including time spent waiting on transfers
+    #[arg(long)]
+    pub timeout: Option<u64>,
+
+    /// Largest size in bytes a linear memory of the guest may grow to
+    #[arg(long)]
+    pub max_memory: Option<usize>,
+}
+
+/// Engine wide switches the limits of the guests running on an engine depend on.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EngineLimits {
+    pub fuel: bool,
+    pub epoch: bool,
+}
+
+impl EngineLimits {
+    pub fn of<'a>(limits: impl IntoIterator<Item = &'a GuestLimits>) -> Self {
+        limits.into_iter().fold(Self::default(), |engine, limits| Self {
+            fuel: engine.fuel || limits.fuel.is_some(),
+            // the wall-clock timeout needs a guest that yields, even when it never calls the host
+            epoch: engine.epoch || limits.epoch_deadline.is_some() || limits.timeout.is_some(),
+        })
+    }
+
+    /// Advance the epoch of `engine` for the rest of the process.
+    pub fn start_epoch_ticker(&self, engine: &Engine) {
+        if !self.epoch {
+            return;
+        }
+        let engine = engine.weak();
+        std::thread::spawn(move || {
+            while let Some(engine) = engine.upgrade() {
+                engine.increment_epoch();
+                drop(engine);
+                std::thread::sleep(EPOCH_TICK);
+            }
+        });
+    }
+}
+
+impl GuestLimits {
+    /// Configure a new store. Every store on an engine with fuel or epoch interruption
+    /// enabled must go through this, otherwise it traps right away.
+    pub fn apply(&self, store: &mut Store<MyState>, engine: EngineLimits) -> Result<(), Error> {
+        if engine.fuel {
+            store.set_fuel(self.fuel.unwrap_or(u64::MAX))?;
+        }
+        if engine.epoch {
+            let mut remaining = self
+                .epoch_deadline
+                .map(|ms| ms.div_ceil(EPOCH_TICK.as_millis() as u64));
+            store.set_epoch_deadline(1);
+            store.epoch_deadline_callback(move |_| match &mut remaining {
+                Some(0) => Err(Trap::Interrupt.into()),
+                Some(ticks) => {
+                    *ticks -= 1;
+                    Ok(UpdateDeadline::Yield(1))
+                }
+                None => Ok(UpdateDeadline::Yield(1)),
+            });
+        }
+        let mut store_limits = StoreLimitsBuilder::new();
+        if let Some(max_memory) = self.max_memory {
+            store_limits = store_limits.memory_size(max_memory);
+        }
+        store.data_mut().limits = store_limits.build();
+        store.limiter(|state| &mut state.limits);
+        Ok(())
+    }
+
+    /// Run `guest` under the wall-clock timeout, if any.
+    pub async fn run<F, T>(&self, guest: F) -> Result<T, Error>
+    where
+        F: Future<Output = Result<T, Error>>,
+    {
+        match self.timeout {
+            Some(secs) => tokio::time::timeout(Duration::from_secs(secs), guest)
+                .await
+                .unwrap_or_else(|_| Err(Error::msg(format!("guest exceeded its timeout of {}s", secs)))),
+            None => guest.await,
+        }
+    }
+}
+
+/// Clean up after a guest that failed or tripped a limit. Its transfers are drained
+/// first, dropping the store then releases its interfaces, reattaches the kernel drivers
+/// it detached and closes its handles.
+pub async fn tear_down(mut store: Store<MyState>, reason: &Error) {
+    error!("Tearing down guest: {:?}", reason);
+    if !store.data_mut().usb.drain_transfers(DRAIN_GRACE).await {
+        warn!("Transfers of the guest did not complete after cancelling them");
+    }
+    drop(store);
+}
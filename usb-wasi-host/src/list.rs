@@ -0,0 +1,368 @@
+use clap::Args;
+use libusb1_sys::{libusb_config_descriptor, libusb_context, libusb_device, libusb_exit, libusb_free_config_descriptor, libusb_get_config_descriptor, libusb_get_configuration, libusb_init, libusb_kernel_driver_active, libusb_unref_device};
+use serde_json::{json, Value};
+use std::path::Path;
+use wasmtime::Error;
+
+use usb_wasi_host::component::usb::descriptors::{ConfigurationDescriptor, DeviceDescriptor};
+use usb_wasi_host::component::usb::device::{DeviceLocation, DeviceMetadata};
+use usb_wasi_host::component::usb::errors::LibusbError;
+use usb_wasi_host::descriptors::generate_config_descriptor;
+use usb_wasi_host::enumerate::{enumerate_devices, port_path};
+use usb_wasi_host::sysfs::read_metadata;
+use usb_wasi_host::{AllowedUSBDevices, USBDeviceIdentifier, UsbDeviceHandle};
+
+#[derive(Args)]
+pub struct ListArgs {
+    /// Print the full configuration/interface/endpoint tree of every device
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Print the devices as a JSON array
+    #[arg(long, conflicts_with = "verbose")]
+    json: bool,
+
+    /// Only show these devices (vendor_id:product_id)
+    #[arg(long, short = 's')]
+    select: Vec<USBDeviceIdentifier>,
+
+    /// Print allow rules for the listed devices that can be pasted on the command line
+    #[arg(long)]
+    emit_policy: bool,
+}
+
+/// Everything the `list` subcommand reports about a device.
+struct ListedDevice {
+    descriptor: DeviceDescriptor,
+    location: DeviceLocation,
+    port_path: Vec<u8>,
+    configurations: Vec<ConfigurationDescriptor>,
+    /// Only available when the device could be opened
+    opened: Result<OpenedInfo, LibusbError>,
+    /// Read without opening the device, so also available for devices we lack access to
+    sysfs: Result<DeviceMetadata, LibusbError>,
+}
+
+struct OpenedInfo {
+    manufacturer: Option<String>,
+    product: Option<String>,
+    serial_number: Option<String>,
+    active_configuration: Option<u8>,
+    /// interface number and whether a kernel driver is bound to it
+    kernel_drivers: Vec<(u8, bool)>,
+}
+
+pub fn run(args: &ListArgs, allowed_usbdevices: &AllowedUSBDevices, sysfs_root: &Path) -> Result<(), Error> {
+    let devices = unsafe {
+        let mut ctx: *mut libusb_context = std::ptr::null_mut();
+        let res = libusb_init(&mut ctx);
+        if res < 0 {
+            return Err(LibusbError::from_raw(res).into());
+        }
+        let devices = collect_devices(ctx, allowed_usbdevices, &args.select, sysfs_root);
+        libusb_exit(ctx);
+        devices?
+    };
+
+    if args.json {
+        let devices: Vec<Value> = devices.iter().map(device_json).collect();
+        println!("{}", serde_json::to_string_pretty(&devices)?);
+    } else {
+        for device in &devices {
+            print_device(device, args.verbose);
+        }
+    }
+
+    if args.emit_policy {
+        print_policy(&devices);
+    }
+    Ok(())
+}
+
+unsafe fn collect_devices(
+    ctx: *mut libusb_context,
+    allowed_usbdevices: &AllowedUSBDevices,
+    select: &[USBDeviceIdentifier],
+    sysfs_root: &Path,
+) -> Result<Vec<ListedDevice>, LibusbError> {
+    let (found, _) = enumerate_devices(ctx, allowed_usbdevices)?;
+    let mut devices = Vec::new();
+    for device in found {
+        let id = USBDeviceIdentifier {
+            vendor_id: device.descriptor.vendor_id,
+            product_id: device.descriptor.product_id,
+        };
+        if select.is_empty() || select.contains(&id) {
+            let port_path = port_path(device.device).unwrap_or_default();
+            devices.push(ListedDevice {
+                sysfs: read_metadata(sysfs_root, device.location.bus_number, &port_path),
+                port_path,
+                configurations: configurations(device.device, device.descriptor.num_configurations),
+                opened: open_info(device.device, &device.descriptor),
+                descriptor: device.descriptor,
+                location: device.location,
+            });
+        }
+        libusb_unref_device(device.device);
+    }
+    Ok(devices)
+}
+
+unsafe fn configurations(dev: *mut libusb_device, num_configurations: u8) -> Vec<ConfigurationDescriptor> {
+    let mut configurations = Vec::new();
+    for index in 0..num_configurations {
+        let mut config_desc: *const libusb_config_descriptor = std::ptr::null();
+        if libusb_get_config_descriptor(dev, index, &mut config_desc) < 0 {
+            continue;
+        }
+        configurations.push(generate_config_descriptor(&*config_desc));
+        libusb_free_config_descriptor(config_desc);
+    }
+    configurations
+}
+
+unsafe fn open_info(dev: *mut libusb_device, descriptor: &DeviceDescriptor) -> Result<OpenedInfo, LibusbError> {
+    let mut handle = UsbDeviceHandle::open(dev)?;
+
+    let mut config: i32 = 0;
+    let active_configuration = match libusb_get_configuration(handle.as_raw(), &mut config) {
+        0.. if config > 0 => Some(config as u8),
+        _ => None,
+    };
+    let mut kernel_drivers = Vec::new();
+    for config in configurations(dev, descriptor.num_configurations) {
+        if Some(config.configuration_value) != active_configuration {
+            continue;
+        }
+        for interface in &config.interfaces {
+            let active = libusb_kernel_driver_active(handle.as_raw(), interface.interface_number as i32) == 1;
+            kernel_drivers.push((interface.interface_number, active));
+        }
+    }
+
+    // the same strings, in the same language, as the guest gets from the handle
+    Ok(OpenedInfo {
+        manufacturer: handle.manufacturer().ok().flatten(),
+        product: handle.product().ok().flatten(),
+        serial_number: handle.serial_number().ok().flatten(),
+        active_configuration,
+        kernel_drivers,
+    })
+}
+
+fn port_path_string(device: &ListedDevice) -> String {
+    let ports: Vec<String> = device.port_path.iter().map(|p| p.to_string()).collect();
+    format!("{}-{}", device.location.bus_number, ports.join("."))
+}
+
+fn print_device(device: &ListedDevice, verbose: bool) {
+    let d = &device.descriptor;
+    let name = match (&device.opened, &device.sysfs) {
+        (Ok(info), _) => [info.manufacturer.as_deref(), info.product.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" "),
+        (Err(_), Ok(metadata)) => [metadata.manufacturer.as_deref(), metadata.product.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" "),
+        (Err(e), Err(_)) => format!("<cannot open: {:?}>", e),
+    };
+    println!(
+        "Bus {:03} Device {:03}: ID {:04x}:{:04x} {}",
+        device.location.bus_number, device.location.device_address, d.vendor_id, d.product_id, name
+    );
+    if !verbose {
+        return;
+    }
+
+    println!("  Port path          {}", port_path_string(device));
+    println!("  Speed              {:?}", device.location.speed);
+    println!("  bcdUSB             {:04x}", d.usb_version_bcd);
+    println!("  Device Class       {:#04x}", d.device_class);
+    println!("  Subclass           {:#04x}", d.device_subclass);
+    println!("  Protocol           {:#04x}", d.device_protocol);
+    println!("  MaxPacketSize0     {}", d.max_packet_size0);
+    println!("  bcdDevice          {:04x}", d.device_version_bcd);
+    if let Ok(info) = &device.opened {
+        println!("  Manufacturer       {}", info.manufacturer.as_deref().unwrap_or(""));
+        println!("  Product            {}", info.product.as_deref().unwrap_or(""));
+        println!("  Serial             {}", info.serial_number.as_deref().unwrap_or(""));
+        match info.active_configuration {
+            Some(config) => println!("  Active config      {}", config),
+            None => println!("  Active config      <unconfigured>"),
+        }
+        if device.sysfs.is_err() {
+            for (interface, active) in &info.kernel_drivers {
+                println!(
+                    "  Interface {:<3}      kernel driver {}",
+                    interface,
+                    if *active { "active" } else { "not active" }
+                );
+            }
+        }
+    } else if let Ok(metadata) = &device.sysfs {
+        println!("  Manufacturer       {}", metadata.manufacturer.as_deref().unwrap_or(""));
+        println!("  Product            {}", metadata.product.as_deref().unwrap_or(""));
+        println!("  Serial             {}", metadata.serial_number.as_deref().unwrap_or(""));
+    }
+    if let Ok(metadata) = &device.sysfs {
+        for interface in &metadata.interfaces {
+            println!(
+                "  Interface {:<3}      class {:#04x}  kernel driver {}",
+                interface.interface_number,
+                interface.interface_class,
+                interface.driver.as_deref().unwrap_or("<none>")
+            );
+        }
+    }
+    for config in &device.configurations {
+        println!(
+            "  Configuration {}  attributes {:#04x}  max power {}mA",
+            config.configuration_value,
+            config.attributes,
+            config.max_power as u32 * 2
+        );
+        for interface in config.interfaces.iter().flat_map(|interface| &interface.alt_settings) {
+            println!(
+                "    Interface {} alt {}  class {:#04x}/{:#04x}/{:#04x}",
+                interface.interface_number,
+                interface.alternate_setting,
+                interface.interface_class,
+                interface.interface_subclass,
+                interface.interface_protocol
+            );
+            for endpoint in &interface.endpoints {
+                println!(
+                    "      Endpoint {:#04x}  {:<11} max packet {}  interval {}",
+                    endpoint.endpoint_address,
+                    endpoint_type(endpoint.attributes),
+                    endpoint.max_packet_size,
+                    endpoint.interval
+                );
+            }
+        }
+    }
+    println!();
+}
+
+fn endpoint_type(attributes: u8) -> &'static str {
+    match attributes & 0x03 {
+        0 => "control",
+        1 => "isochronous",
+        2 => "bulk",
+        _ => "interrupt",
+    }
+}
+
+fn print_policy(devices: &[ListedDevice]) {
+    println!("# allow rules, pass them together with --use-allow-list");
+    let mut emitted: Vec<(u16, u16)> = Vec::new();
+    for device in devices {
+        let id = (device.descriptor.vendor_id, device.descriptor.product_id);
+        if emitted.contains(&id) {
+            continue;
+        }
+        emitted.push(id);
+        let name = match (&device.opened, &device.sysfs) {
+            (Ok(info), _) => info.product.clone().unwrap_or_default(),
+            (Err(_), Ok(metadata)) => metadata.product.clone().unwrap_or_default(),
+            (Err(_), Err(_)) => String::new(),
+        };
+        println!("-d {:04x}:{:04x}  # {} ({})", id.0, id.1, name, port_path_string(device));
+    }
+}
+
+fn device_json(device: &ListedDevice) -> Value {
+    let d = &device.descriptor;
+    let configurations: Vec<Value> = device
+        .configurations
+        .iter()
+        .map(|config| {
+            let interfaces: Vec<Value> = config
+                .interfaces
+                .iter()
+                .flat_map(|interface| &interface.alt_settings)
+                .map(|interface| {
+                    let endpoints: Vec<Value> = interface
+                        .endpoints
+                        .iter()
+                        .map(|endpoint| json!({
+                            "endpoint_address": endpoint.endpoint_address,
+                            "transfer_type": endpoint_type(endpoint.attributes),
+                            "attributes": endpoint.attributes,
+                            "max_packet_size": endpoint.max_packet_size,
+                            "interval": endpoint.interval,
+                        }))
+                        .collect();
+                    json!({
+                        "interface_number": interface.interface_number,
+                        "alternate_setting": interface.alternate_setting,
+                        "interface_class": interface.interface_class,
+                        "interface_subclass": interface.interface_subclass,
+                        "interface_protocol": interface.interface_protocol,
+                        "interface_index": interface.interface_index,
+                        "endpoints": endpoints,
+                    })
+                })
+                .collect();
+            json!({
+                "configuration_value": config.configuration_value,
+                "configuration_index": config.configuration_index,
+                "attributes": config.attributes,
+                "max_power": config.max_power,
+                "interfaces": interfaces,
+            })
+        })
+        .collect();
+
+    let mut value = json!({
+        "bus_number": device.location.bus_number,
+        "device_address": device.location.device_address,
+        "port_path": device.port_path,
+        "speed": format!("{:?}", device.location.speed),
+        "vendor_id": d.vendor_id,
+        "product_id": d.product_id,
+        "usb_version_bcd": d.usb_version_bcd,
+        "device_class": d.device_class,
+        "device_subclass": d.device_subclass,
+        "device_protocol": d.device_protocol,
+        "max_packet_size0": d.max_packet_size0,
+        "device_version_bcd": d.device_version_bcd,
+        "num_configurations": d.num_configurations,
+        "configurations": configurations,
+    });
+    match &device.opened {
+        Ok(info) => {
+            value["manufacturer"] = json!(info.manufacturer);
+            value["product"] = json!(info.product);
+            value["serial_number"] = json!(info.serial_number);
+            value["active_configuration"] = json!(info.active_configuration);
+            value["kernel_drivers"] = info
+                .kernel_drivers
+                .iter()
+                .map(|(interface, active)| json!({ "interface": interface, "active": active }))
+                .collect();
+        }
+        Err(e) => value["open_error"] = json!(format!("{:?}", e)),
+    }
+    if let Ok(metadata) = &device.sysfs {
+        value["sysfs"] = json!({
+            "manufacturer": metadata.manufacturer,
+            "product": metadata.product,
+            "serial_number": metadata.serial_number,
+            "interfaces": metadata
+                .interfaces
+                .iter()
+                .map(|interface| json!({
+                    "interface_number": interface.interface_number,
+                    "interface_class": interface.interface_class,
+                    "driver": interface.driver,
+                }))
+                .collect::<Vec<_>>(),
+        });
+    }
+    value
+}
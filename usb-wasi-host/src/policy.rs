@@ -0,0 +1,141 @@
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct USBDeviceIdentifier {
+    pub vendor_id: u16,
+    pub product_id: u16
+}
+
+impl FromStr for USBDeviceIdentifier {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 2 {
+            return Err("Invalid format. Expected vendor_id:product_id");
+        }
+
+        let vendor_id = u16::from_str_radix(parts[0], 16).map_err(|_| "Invalid vendor_id")?;
+        let product_id = u16::from_str_radix(parts[1], 16).map_err(|_| "Invalid product_id")?;
+
+        Ok(Self { vendor_id, product_id })
+    }
+}
+
+impl<'de> Deserialize<'de> for USBDeviceIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        Self::from_str(&id).map_err(|e| serde::de::Error::custom(format!("{}: {}", id, e)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AllowedUSBDevices {
+    Allowed(Vec<USBDeviceIdentifier>),
+    Denied(Vec<USBDeviceIdentifier>)
+}
+
+impl AllowedUSBDevices {
+    pub fn is_allowed(&self, device: &USBDeviceIdentifier) -> bool {
+        match self {
+            Self::Allowed(devices) => devices.contains(device),
+            Self::Denied(devices) => !devices.contains(device)
+        }
+    }
+}
+
+/// Contents of `policy.toml`, the device policy shared by the host, the broker and the udev rules.
+#[derive(Debug, Deserialize)]
+pub struct PolicyFile {
+    /// Group that gets access to the devices and to the socket of the broker
+    #[serde(default = "default_group")]
+    pub group: String,
+    #[serde(rename = "device", default)]
+    pub devices: Vec<DevicePolicy>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DevicePolicy {
+    /// vendor_id:product_id
+    pub id: USBDeviceIdentifier,
+    /// Only these interfaces may be claimed by guests, all when absent. The host enforces this,
+    /// the device node itself gives access to every interface.
+    pub interfaces: Option<Vec<u8>>,
+}
+
+fn default_group() -> String {
+    "usbwasi".to_string()
+}
+
+impl PolicyFile {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        toml::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+    }
+
+    /// Only the devices listed in the file are allowed.
+    pub fn allowed_usbdevices(&self) -> AllowedUSBDevices {
+        AllowedUSBDevices::Allowed(self.devices.iter().map(|d| d.id.clone()).collect())
+    }
+
+    /// The interfaces of the devices that list them.
+    pub fn allowed_interfaces(&self) -> AllowedInterfaces {
+        AllowedInterfaces(
+            self.devices
+                .iter()
+                .filter_map(|d| Some((d.id.clone(), d.interfaces.clone()?)))
+                .collect(),
+        )
+    }
+}
+
+/// The interfaces guests may claim, by device. Devices without an entry are not restricted.
+#[derive(Debug, Clone, Default)]
+pub struct AllowedInterfaces(Vec<(USBDeviceIdentifier, Vec<u8>)>);
+
+impl AllowedInterfaces {
+    pub fn is_allowed(&self, device: &USBDeviceIdentifier, interface: u8) -> bool {
+        self.0
+            .iter()
+            .filter(|(id, _)| id == device)
+            .all(|(_, interfaces)| interfaces.contains(&interface))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STICK: USBDeviceIdentifier = USBDeviceIdentifier { vendor_id: 0x0951, product_id: 0x1666 };
+    const RECEIVER: USBDeviceIdentifier = USBDeviceIdentifier { vendor_id: 0x046d, product_id: 0xc52b };
+
+    #[test]
+    fn interfaces_of_the_policy() {
+        let policy: PolicyFile = toml::from_str(
+            r#"
+            [[device]]
+            id = "0951:1666"
+
+            [[device]]
+            id = "046d:c52b"
+            interfaces = [2]
+            "#,
+        )
+        .unwrap();
+        let allowed = policy.allowed_interfaces();
+        assert!(allowed.is_allowed(&STICK, 0));
+        assert!(allowed.is_allowed(&RECEIVER, 2));
+        assert!(!allowed.is_allowed(&RECEIVER, 0));
+    }
+
+    #[test]
+    fn no_interfaces_restricts_nothing() {
+        let allowed = AllowedInterfaces::default();
+        assert!(allowed.is_allowed(&RECEIVER, 0));
+        assert!(allowed.is_allowed(&STICK, 7));
+    }
+}
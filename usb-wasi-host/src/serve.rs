@@ -0,0 +1,483 @@
+use std::collections::BTreeMap;
+use std::fs::Permissions;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use clap::Args;
+use libusb1_sys::{libusb_context, libusb_exit, libusb_init};
+use log::{error, info, warn};
+use serde::Deserialize;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use wasmtime::component::{Component, Linker};
+use wasmtime::{Engine, Error, Store};
+use wasmtime_wasi::bindings::Command;
+
+use usb_wasi_host::arbiter::InterfaceArbiter;
+use usb_wasi_host::audit::{AuditLog, AuditPayloads};
+use usb_wasi_host::broker::BrokerClient;
+use usb_wasi_host::component::usb::errors::LibusbError;
+use usb_wasi_host::enumerate::release_sessions;
+use usb_wasi_host::policy::PolicyFile;
+use usb_wasi_host::{AllowedInterfaces, AllowedUSBDevices, USBDeviceIdentifier, WasiUsbCtx};
+
+use crate::compile::load_component;
+use crate::limits::{tear_down, EngineLimits, GuestLimits};
+use crate::wasi::{EnvVar, PreopenDir, WasiArgs};
+use crate::{new_engine, new_linker, MyState};
+
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Host configuration (TOML) listing the components to run
+    #[arg(long)]
+    config: PathBuf,
+}
+
+/// Contents of `host.toml`.
+#[derive(Deserialize)]
+struct HostConfig {
+    /// Unix socket accepting `status`, `start <name>` and `stop <name>` from the user running the host
+    control_socket: Option<PathBuf>,
+    #[serde(rename = "component", default)]
+    components: Vec<ComponentConfig>,
+}
+
+#[derive(Deserialize)]
+struct ComponentConfig {
+    name: String,
+    path: PathBuf,
+    /// Devices the component may use (vendor_id:product_id); none when empty
+    #[serde(default)]
+    devices: Vec<String>,
+    #[serde(default)]
+    restart: RestartPolicy,
+    #[serde(default = "default_autostart")]
+    autostart: bool,
+    #[serde(default)]
+    args: Vec<String>,
+    /// KEY=VAL
+    #[serde(default)]
+    env: Vec<String>,
+    /// host::guest[:ro]
+    #[serde(default)]
+    dirs: Vec<String>,
+    audit_log: Option<PathBuf>,
+    #[serde(default)]
+    audit_payloads: AuditPayloads,
+    #[serde(flatten)]
+    limits: GuestLimits,
+}
+
+fn default_autostart() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum RestartPolicy {
+    Never,
+    #[default]
+    OnFailure,
+    Always,
+}
+
+#[derive(Debug, Clone)]
+enum ComponentStatus {
+    Stopped,
+    Running,
+    Exited,
+    Failed(String),
+}
+
+/// A component from the configuration, compiled and ready to be instantiated.
+struct PreparedComponent {
+    name: String,
+    path: PathBuf,
+    component: Component,
+    allowed_usbdevices: AllowedUSBDevices,
+    allowed_interfaces: AllowedInterfaces,
+    wasi: WasiArgs,
+    restart: RestartPolicy,
+    audit_log: Option<Arc<AuditLog>>,
+    limits: GuestLimits,
+}
+
+struct Managed {
+    prepared: Arc<PreparedComponent>,
+    status: ComponentStatus,
+    restarts: u32,
+    task: Option<JoinHandle<()>>,
+    /// Asks the running task to tear its guest down and exit, a new one for every start
+    stop: Arc<Notify>,
+}
+
+/// Runs every component in its own store and restarts them according to their policy.
+/// All stores share one arbiter, so two components cannot claim the same interface.
+struct Supervisor {
+    engine: Engine,
+    engine_limits: EngineLimits,
+    linker: Linker<MyState>,
+    arbiter: Arc<InterfaceArbiter>,
+    /// The libusb context of all stores, so the arbiter sees the same session id for a device in each
+    context: usize,
+    /// Devices are opened through the broker when the host runs without privileges
+    broker: Option<Arc<BrokerClient>>,
+    sysfs_root: PathBuf,
+    components: Mutex<BTreeMap<String, Managed>>,
+}
+
+/// With a `policy`, every device of a component must also be listed in the policy.
+pub async fn run(
+    args: &ServeArgs,
+    policy: Option<&PolicyFile>,
+    broker: Option<Arc<BrokerClient>>,
+    sysfs_root: &Path,
+    cache_dir: Option<&Path>,
+) -> Result<(), Error> {
+    let config: HostConfig = toml::from_str(&std::fs::read_to_string(&args.config)?)?;
+    let engine_limits = EngineLimits::of(config.components.iter().map(|c| &c.limits));
+    let engine = new_engine(engine_limits)?;
+    let linker = new_linker(&engine)?;
+
+    let mut components = BTreeMap::new();
+    let mut autostart = Vec::new();
+    for component in &config.components {
+        if components.contains_key(&component.name) {
+            return Err(Error::msg(format!("Duplicate component name {}", component.name)));
+        }
+        let prepared = prepare(&engine, component, policy, cache_dir)?;
+        if component.autostart {
+            autostart.push(component.name.clone());
+        }
+        components.insert(
+            component.name.clone(),
+            Managed {
+                prepared: Arc::new(prepared),
+                status: ComponentStatus::Stopped,
+                restarts: 0,
+                task: None,
+                stop: Arc::new(Notify::new()),
+            },
+        );
+    }
+
+    let mut context: *mut libusb_context = std::ptr::null_mut();
+    LibusbError::check(unsafe { libusb_init(&mut context) })?;
+    let supervisor = Arc::new(Supervisor {
+        engine,
+        engine_limits,
+        linker,
+        arbiter: Arc::new(InterfaceArbiter::new()),
+        context: context as usize,
+        broker,
+        sysfs_root: sysfs_root.to_path_buf(),
+        components: Mutex::new(components),
+    });
+    for name in autostart {
+        supervisor.start(&name)?;
+    }
+
+    let control = config
+        .control_socket
+        .clone()
+        .map(|path| tokio::spawn(serve_control(supervisor.clone(), path)));
+
+    tokio::signal::ctrl_c().await?;
+    info!("Shutting down");
+    if let Some(control) = control {
+        control.abort();
+    }
+    if let Some(path) = &config.control_socket {
+        let _ = std::fs::remove_file(path);
+    }
+    let names: Vec<String> = supervisor.components.lock().unwrap().keys().cloned().collect();
+    for name in &names {
+        let _ = supervisor.stop(name);
+    }
+    let stopping: Vec<JoinHandle<()>> =
+        supervisor.components.lock().unwrap().values_mut().filter_map(|managed| managed.task.take()).collect();
+    // exiting before the guests are torn down would leave transfers submitted on closed handles
+    for task in stopping {
+        let _ = task.await;
+    }
+    unsafe {
+        release_sessions(context);
+        libusb_exit(context);
+    }
+    Ok(())
+}
+
+fn prepare(
+    engine: &Engine,
+    config: &ComponentConfig,
+    policy: Option<&PolicyFile>,
+    cache_dir: Option<&Path>,
+) -> Result<PreparedComponent, Error> {
+    let devices = config
+        .devices
+        .iter()
+        .map(|d| USBDeviceIdentifier::from_str(d).map_err(|e| Error::msg(format!("{}: {}", d, e))))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(policy) = policy {
+        let allowed = policy.allowed_usbdevices();
+        if let Some(denied) = devices.iter().find(|d| !allowed.is_allowed(d)) {
+            return Err(Error::msg(format!(
+                "Device {:04x}:{:04x} of component {} is not in the policy",
+                denied.vendor_id, denied.product_id, config.name
+            )));
+        }
+    }
+    // a component only sees the devices assigned to it
+    let allowed_usbdevices = AllowedUSBDevices::Allowed(devices);
+    let allowed_interfaces = policy.map(PolicyFile::allowed_interfaces).unwrap_or_default();
+    let wasi = WasiArgs {
+        envs: parse_all::<EnvVar>(&config.env)?,
+        dirs: parse_all::<PreopenDir>(&config.dirs)?,
+        guest_args: config.args.clone(),
+        ..Default::default()
+    };
+    let audit_log = match &config.audit_log {
+        Some(path) => Some(Arc::new(AuditLog::open(path, &config.path, config.audit_payloads)?)),
+        None => None,
+    };
+    info!("Compiling component {} ({})", config.name, config.path.display());
+    Ok(PreparedComponent {
+        name: config.name.clone(),
+        path: config.path.clone(),
+        component: load_component(engine, &config.path, cache_dir)?,
+        allowed_usbdevices,
+        allowed_interfaces,
+        wasi,
+        restart: config.restart,
+        audit_log,
+        limits: config.limits.clone(),
+    })
+}
+
+fn parse_all<T: FromStr<Err = &'static str>>(values: &[String]) -> Result<Vec<T>, Error> {
+    values
+        .iter()
+        .map(|v| T::from_str(v).map_err(|e| Error::msg(format!("{}: {}", v, e))))
+        .collect()
+}
+
+impl Supervisor {
+    fn start(self: &Arc<Self>, name: &str) -> Result<(), Error> {
+        let mut components = self.components.lock().unwrap();
+        let managed = components
+            .get_mut(name)
+            .ok_or_else(|| Error::msg(format!("Unknown component {}", name)))?;
+        // a stopped task keeps its handle until the guest is torn down, so a new instance
+        // cannot take claims that the store of the old one releases when it is dropped
+        if managed.task.as_ref().is_some_and(|task| !task.is_finished()) {
+            let state = if matches!(managed.status, ComponentStatus::Stopped) { "still stopping" } else { "already running" };
+            return Err(Error::msg(format!("Component {} is {}", name, state)));
+        }
+        info!("Starting component {}", name);
+        managed.status = ComponentStatus::Running;
+        managed.restarts = 0;
+        managed.stop = Arc::new(Notify::new());
+        managed.task = Some(tokio::spawn(self.clone().supervise(managed.prepared.clone(), managed.stop.clone())));
+        Ok(())
+    }
+
+    /// Ask the task of `name` to stop. The task drains the transfers of the guest before dropping
+    /// its store, aborting it instead would close handles with transfers still submitted.
+    fn stop(&self, name: &str) -> Result<(), Error> {
+        let mut components = self.components.lock().unwrap();
+        let managed = components
+            .get_mut(name)
+            .ok_or_else(|| Error::msg(format!("Unknown component {}", name)))?;
+        if managed.task.as_ref().is_some_and(|task| !task.is_finished()) {
+            info!("Stopping component {}", name);
+            managed.stop.notify_one();
+        }
+        managed.status = ComponentStatus::Stopped;
+        Ok(())
+    }
+
+    fn status(&self) -> Vec<String> {
+        let components = self.components.lock().unwrap();
+        let mut lines: Vec<String> = components
+            .iter()
+            .map(|(name, managed)| {
+                let status = match &managed.status {
+                    ComponentStatus::Stopped => "stopped".to_string(),
+                    ComponentStatus::Running => "running".to_string(),
+                    ComponentStatus::Exited => "exited".to_string(),
+                    ComponentStatus::Failed(e) => format!("failed: {}", e),
+                };
+                format!("{} {} restarts={} {}", name, managed.prepared.path.display(), managed.restarts, status)
+            })
+            .collect();
+        for (key, owner) in self.arbiter.claims() {
+            lines.push(format!("claim device={} interface={} owner={}", key.device, key.interface, owner));
+        }
+        lines
+    }
+
+    fn set_status(&self, name: &str, status: ComponentStatus) {
+        if let Some(managed) = self.components.lock().unwrap().get_mut(name) {
+            managed.status = status;
+        }
+    }
+
+    async fn supervise(self: Arc<Self>, prepared: Arc<PreparedComponent>, stop: Arc<Notify>) {
+        let mut backoff = Duration::from_secs(1);
+        loop {
+            self.set_status(&prepared.name, ComponentStatus::Running);
+            let Some(result) = self.run_once(&prepared, &stop).await else {
+                info!("Component {} stopped", prepared.name);
+                return;
+            };
+            let failed = result.is_err();
+            match result {
+                Ok(()) => {
+                    info!("Component {} finished", prepared.name);
+                    self.set_status(&prepared.name, ComponentStatus::Exited);
+                }
+                Err(e) => {
+                    error!("Component {} failed: {:?}", prepared.name, e);
+                    self.set_status(&prepared.name, ComponentStatus::Failed(e.to_string()));
+                }
+            }
+
+            let restart = match prepared.restart {
+                RestartPolicy::Never => false,
+                RestartPolicy::OnFailure => failed,
+                RestartPolicy::Always => true,
+            };
+            if !restart {
+                return;
+            }
+            warn!("Restarting component {} in {:?}", prepared.name, backoff);
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = stop.notified() => return,
+            }
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+            if let Some(managed) = self.components.lock().unwrap().get_mut(&prepared.name) {
+                managed.restarts += 1;
+            }
+        }
+    }
+
+    /// Run the component once, `None` if it was stopped before it finished.
+    async fn run_once(&self, prepared: &PreparedComponent, stop: &Notify) -> Option<Result<(), Error>> {
+        let wasi_ctx = match prepared.wasi.build_ctx(&prepared.path) {
+            Ok(wasi_ctx) => wasi_ctx,
+            Err(e) => return Some(Err(e)),
+        };
+        let usb_ctx = {
+            let mut builder = WasiUsbCtx::builder();
+            unsafe { builder.libusb_context(self.context as *mut libusb_context) };
+            builder
+                .policy(prepared.allowed_usbdevices.clone())
+                .interfaces(prepared.allowed_interfaces.clone())
+                .sysfs_root(&self.sysfs_root)
+                .arbiter(self.arbiter.clone(), prepared.name.clone());
+            if let Some(broker) = &self.broker {
+                builder.broker(broker.clone());
+            }
+            if let Some(audit_log) = &prepared.audit_log {
+                builder.audit_log(audit_log.clone());
+            }
+            builder.build()
+        };
+        let mut store = Store::new(&self.engine, MyState::new(wasi_ctx, usb_ctx));
+        if let Err(e) = prepared.limits.apply(&mut store, self.engine_limits) {
+            return Some(Err(e));
+        }
+        let guest = prepared.limits.run(async {
+            let command = Command::instantiate_async(&mut store, &prepared.component, &self.linker).await?;
+            command
+                .wasi_cli_run()
+                .call_run(&mut store)
+                .await?
+                .map_err(|()| Error::msg("component returned an error"))
+        });
+        let result = tokio::select! {
+            result = guest => Some(result),
+            _ = stop.notified() => None,
+        };
+        match &result {
+            Some(Err(e)) => tear_down(store, e).await,
+            None => tear_down(store, &Error::msg("component stopped")).await,
+            Some(Ok(())) => {}
+        }
+        result
+    }
+}
+
+async fn serve_control(supervisor: Arc<Supervisor>, path: PathBuf) {
+    if let Err(e) = accept_control(&supervisor, &path).await {
+        error!("Control socket {} failed: {:?}", path.display(), e);
+    }
+}
+
+async fn accept_control(supervisor: &Arc<Supervisor>, path: &Path) -> Result<(), Error> {
+    // a stale socket from a previous run would make bind fail
+    let _ = std::fs::remove_file(path);
+    // only the user running the host may start or stop its components
+    let mask = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(path);
+    unsafe { libc::umask(mask) };
+    let listener = listener?;
+    std::fs::set_permissions(path, Permissions::from_mode(0o600))?;
+    info!("Listening for control commands on {}", path.display());
+    let uid = unsafe { libc::geteuid() };
+    loop {
+        let (stream, _) = listener.accept().await?;
+        match stream.peer_cred() {
+            Ok(cred) if cred.uid() == uid || cred.uid() == 0 => {}
+            Ok(cred) => {
+                warn!("Refused control client pid {:?} uid {}", cred.pid(), cred.uid());
+                continue;
+            }
+            Err(e) => {
+                warn!("Failed to get the credentials of a control client: {}", e);
+                continue;
+            }
+        }
+        let supervisor = supervisor.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_control(&supervisor, stream).await {
+                warn!("Control connection failed: {:?}", e);
+            }
+        });
+    }
+}
+
+/// Line based protocol, every response ends with an `ok` or `error: ...` line.
+async fn handle_control(supervisor: &Arc<Supervisor>, stream: UnixStream) -> Result<(), Error> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Some(line) = lines.next_line().await? {
+        let mut words = line.split_whitespace();
+        let result = match (words.next(), words.next()) {
+            (Some("status"), None) => {
+                for status in supervisor.status() {
+                    writer.write_all(format!("{}\n", status).as_bytes()).await?;
+                }
+                Ok(())
+            }
+            (Some("start"), Some(name)) => supervisor.start(name),
+            // the task tears the guest down in the background
+            (Some("stop"), Some(name)) => supervisor.stop(name),
+            _ => Err(Error::msg("expected status, start <name> or stop <name>")),
+        };
+        let response = match result {
+            Ok(()) => "ok\n".to_string(),
+            Err(e) => format!("error: {}\n", e),
+        };
+        writer.write_all(response.as_bytes()).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! Device metadata from sysfs, which Linux exposes without opening the device and so
+//! without write access to `/dev/bus/usb`.
+
+use std::fs;
+use std::path::Path;
+
+use crate::component::usb::device::{DeviceMetadata, InterfaceMetadata};
+use crate::component::usb::errors::LibusbError;
+
+/// Where Linux lists the USB devices and their interfaces.
+pub const DEFAULT_SYSFS_ROOT: &str = "/sys/bus/usb/devices";
+
+/// Name of the sysfs directory of the device at `port_path` on `bus`,
+/// e.g. `1-2.4`, or `usb1` for the root hub.
+pub fn device_dir_name(bus: u8, port_path: &[u8]) -> String {
+    if port_path.is_empty() {
+        return format!("usb{}", bus);
+    }
+    let ports: Vec<String> = port_path.iter().map(|p| p.to_string()).collect();
+    format!("{}-{}", bus, ports.join("."))
+}
+
+/// Read the strings of the device at `port_path` on `bus` and the interfaces of its active
+/// configuration from the sysfs tree at `root`.
+/// Fails with not-found if `root` has no directory for the device, e.g. on other platforms.
+pub fn read_metadata(root: &Path, bus: u8, port_path: &[u8]) -> Result<DeviceMetadata, LibusbError> {
+    let name = device_dir_name(bus, port_path);
+    let dir = root.join(&name);
+    if !dir.is_dir() {
+        return Err(LibusbError::NotFound);
+    }
+
+    // interfaces are listed next to the device as <device>:<configuration>.<interface>,
+    // those of a root hub as <bus>-0:<configuration>.<interface>
+    let prefix = if port_path.is_empty() { format!("{}-0:", bus) } else { format!("{}:", name) };
+    let mut interfaces = Vec::new();
+    for entry in fs::read_dir(root).map_err(|_| LibusbError::Io)?.flatten() {
+        if !entry.file_name().to_string_lossy().starts_with(&prefix) {
+            continue;
+        }
+        let path = entry.path();
+        let (Some(interface_number), Some(interface_class)) =
+            (hex_attribute(&path, "bInterfaceNumber"), hex_attribute(&path, "bInterfaceClass"))
+        else {
+            continue;
+        };
+        interfaces.push(InterfaceMetadata { interface_number, interface_class, driver: driver(&path) });
+    }
+    interfaces.sort_by_key(|interface| interface.interface_number);
+
+    Ok(DeviceMetadata {
+        manufacturer: attribute(&dir, "manufacturer"),
+        product: attribute(&dir, "product"),
+        serial_number: attribute(&dir, "serial"),
+        interfaces,
+    })
+}
+
+fn attribute(dir: &Path, name: &str) -> Option<String> {
+    let value = fs::read_to_string(dir.join(name)).ok()?;
+    Some(value.trim_end_matches('\n').to_string())
+}
+
+/// Attributes such as bInterfaceClass are written as two hex digits.
+fn hex_attribute(dir: &Path, name: &str) -> Option<u8> {
+    u8::from_str_radix(attribute(dir, name)?.trim(), 16).ok()
+}
+
+/// The kernel driver bound to the interface at `dir`, from the name its `driver` link points to.
+fn driver(dir: &Path) -> Option<String> {
+    let target = fs::read_link(dir.join("driver")).ok()?;
+    Some(target.file_name()?.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// An empty directory standing in for /sys/bus/usb/devices, removed when dropped.
+    struct FakeSysfs(PathBuf);
+
+    impl FakeSysfs {
+        fn new() -> Self {
+            static NEXT: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+            let n = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let root = std::env::temp_dir().join(format!("usb-wasi-sysfs-{}-{}", std::process::id(), n));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(&root).unwrap();
+            Self(root)
+        }
+
+        /// Create `dir` with the attributes in `attributes`, written the way sysfs does with a newline.
+        fn dir(&self, dir: &str, attributes: &[(&str, &str)]) -> PathBuf {
+            let path = self.0.join(dir);
+            fs::create_dir_all(&path).unwrap();
+            for (name, value) in attributes {
+                fs::write(path.join(name), format!("{}\n", value)).unwrap();
+            }
+            path
+        }
+
+        fn interface(&self, dir: &str, number: &str, class: &str, driver: Option<&str>) {
+            let path = self.dir(dir, &[("bInterfaceNumber", number), ("bInterfaceClass", class)]);
+            if let Some(driver) = driver {
+                let target = self.0.join("drivers").join(driver);
+                fs::create_dir_all(&target).unwrap();
+                std::os::unix::fs::symlink(target, path.join("driver")).unwrap();
+            }
+        }
+    }
+
+    impl Drop for FakeSysfs {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn dir_name_follows_the_port_path() {
+        assert_eq!(device_dir_name(1, &[]), "usb1");
+        assert_eq!(device_dir_name(1, &[2]), "1-2");
+        assert_eq!(device_dir_name(3, &[2, 4, 1]), "3-2.4.1");
+    }
+
+    #[test]
+    fn metadata_has_the_strings_and_interfaces_of_the_device() {
+        let sysfs = FakeSysfs::new();
+        sysfs.dir("1-2.4", &[("manufacturer", "SanDisk"), ("product", "Ultra Fit"), ("serial", "4C530001")]);
+        sysfs.interface("1-2.4:1.1", "01", "03", None);
+        sysfs.interface("1-2.4:1.0", "00", "08", Some("usb-storage"));
+        // a device further down the same hub and another device on the hub
+        sysfs.dir("1-2.4.1", &[("product", "Other")]);
+        sysfs.interface("1-2.4.1:1.0", "00", "ff", None);
+        sysfs.interface("1-2.3:1.0", "00", "09", Some("hub"));
+
+        let metadata = read_metadata(&sysfs.0, 1, &[2, 4]).unwrap();
+        assert_eq!(metadata.manufacturer.as_deref(), Some("SanDisk"));
+        assert_eq!(metadata.product.as_deref(), Some("Ultra Fit"));
+        assert_eq!(metadata.serial_number.as_deref(), Some("4C530001"));
+        let interfaces: Vec<_> = metadata
+            .interfaces
+            .iter()
+            .map(|i| (i.interface_number, i.interface_class, i.driver.as_deref()))
+            .collect();
+        assert_eq!(interfaces, [(0, 0x08, Some("usb-storage")), (1, 0x03, None)]);
+    }
+
+    #[test]
+    fn missing_attributes_are_none() {
+        let sysfs = FakeSysfs::new();
+        sysfs.dir("2-1", &[]);
+        // an interface without its class is left out
+        sysfs.dir("2-1:1.0", &[("bInterfaceNumber", "00")]);
+        let metadata = read_metadata(&sysfs.0, 2, &[1]).unwrap();
+        assert_eq!((metadata.manufacturer, metadata.product, metadata.serial_number), (None, None, None));
+        assert!(metadata.interfaces.is_empty());
+    }
+
+    #[test]
+    fn root_hub_interfaces_are_found() {
+        let sysfs = FakeSysfs::new();
+        sysfs.dir("usb1", &[("product", "xHCI Host Controller")]);
+        sysfs.interface("1-0:1.0", "00", "09", Some("hub"));
+        // the root hub of another bus
+        sysfs.interface("2-0:1.0", "00", "09", Some("hub"));
+
+        let metadata = read_metadata(&sysfs.0, 1, &[]).unwrap();
+        assert_eq!(metadata.product.as_deref(), Some("xHCI Host Controller"));
+        assert_eq!(metadata.interfaces.len(), 1);
+        assert_eq!(metadata.interfaces[0].driver.as_deref(), Some("hub"));
+    }
+
+    #[test]
+    fn unknown_device_is_not_found() {
+        let sysfs = FakeSysfs::new();
+        assert!(matches!(read_metadata(&sysfs.0, 1, &[7]), Err(LibusbError::NotFound)));
+        assert!(matches!(read_metadata(&sysfs.0.join("missing"), 1, &[7]), Err(LibusbError::NotFound)));
+    }
+}
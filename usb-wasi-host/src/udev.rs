@@ -0,0 +1,192 @@
+use std::ffi::CString;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use clap::Args;
+use libusb1_sys::{libusb_close, libusb_context, libusb_device_handle, libusb_exit, libusb_init, libusb_open, libusb_unref_device};
+use wasmtime::Error;
+
+use usb_wasi_host::component::usb::errors::LibusbError;
+use usb_wasi_host::enumerate::enumerate_devices;
+use usb_wasi_host::policy::PolicyFile;
+use usb_wasi_host::{AllowedUSBDevices, USBDeviceIdentifier};
+
+/// The devices and the group come from the policy given with --policy.
+#[derive(Args)]
+pub struct UdevRulesArgs {
+    /// Write the rules to this file instead of stdout, e.g. /etc/udev/rules.d/70-usb-wasi.rules
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+
+    /// Instead of emitting rules, check that the current user can open every allowed device
+    #[arg(long)]
+    check: bool,
+}
+
+pub fn run(args: &UdevRulesArgs, policy: &PolicyFile) -> Result<(), Error> {
+    let devices: Vec<USBDeviceIdentifier> = policy.devices.iter().map(|d| d.id.clone()).collect();
+
+    if args.check {
+        return check(&policy.group, &devices);
+    }
+
+    let rules = rules(&policy.group, &devices);
+    match &args.output {
+        Some(path) => std::fs::write(path, rules)?,
+        None => print!("{}", rules),
+    }
+    Ok(())
+}
+
+/// Rules that give `group` access to the device nodes. They only grant access, the interfaces
+/// the guest may use are left to the host, so kernel drivers keep working for everyone else.
+fn rules(group: &str, devices: &[USBDeviceIdentifier]) -> String {
+    let mut rules = String::new();
+    rules.push_str("# generated by `usb-wasi-host udev-rules`, reload with `udevadm control --reload && udevadm trigger`\n");
+    for id in devices {
+        let (vendor, product) = (id.vendor_id, id.product_id);
+        let _ = writeln!(rules, "\n# {:04x}:{:04x}", vendor, product);
+        let _ = writeln!(
+            rules,
+            "SUBSYSTEM==\"usb\", ENV{{DEVTYPE}}==\"usb_device\", ATTR{{idVendor}}==\"{:04x}\", ATTR{{idProduct}}==\"{:04x}\", MODE=\"0660\", GROUP=\"{}\"",
+            vendor, product, group
+        );
+    }
+    rules
+}
+
+/// Try to open every connected device the policy allows as the current user.
+fn check(group: &str, ids: &[USBDeviceIdentifier]) -> Result<(), Error> {
+    if !in_group(group) {
+        println!("warning: you are not in group {}, log in again after adding yourself", group);
+    }
+    let allowed_usbdevices = AllowedUSBDevices::Allowed(ids.to_vec());
+    let mut failed = 0;
+    unsafe {
+        let mut ctx: *mut libusb_context = std::ptr::null_mut();
+        LibusbError::check(libusb_init(&mut ctx))?;
+        let found = enumerate_devices(ctx, &allowed_usbdevices);
+        if let Ok((devices, _)) = &found {
+            for device in devices {
+                let mut handle: *mut libusb_device_handle = std::ptr::null_mut();
+                let res = libusb_open(device.device, &mut handle);
+                let result = match LibusbError::check(res) {
+                    Ok(()) => {
+                        libusb_close(handle);
+                        "ok".to_string()
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        e.to_string()
+                    }
+                };
+                println!(
+                    "{:04x}:{:04x} bus {:03} device {:03}: {}",
+                    device.descriptor.vendor_id,
+                    device.descriptor.product_id,
+                    device.location.bus_number,
+                    device.location.device_address,
+                    result
+                );
+                libusb_unref_device(device.device);
+            }
+            for id in ids {
+                let connected = devices
+                    .iter()
+                    .any(|d| d.descriptor.vendor_id == id.vendor_id && d.descriptor.product_id == id.product_id);
+                if !connected {
+                    println!("{:04x}:{:04x}: not connected", id.vendor_id, id.product_id);
+                }
+            }
+        }
+        libusb_exit(ctx);
+        found?;
+    }
+    if failed > 0 {
+        return Err(Error::msg(format!("{} allowed device(s) cannot be opened", failed)));
+    }
+    Ok(())
+}
+
+/// Whether `group` is the effective or a supplementary group of the process.
+fn in_group(group: &str) -> bool {
+    let Ok(name) = CString::new(group) else {
+        return false;
+    };
+    unsafe {
+        let entry = libc::getgrnam(name.as_ptr());
+        if entry.is_null() {
+            return false;
+        }
+        let gid = (*entry).gr_gid;
+        if libc::getegid() == gid {
+            return true;
+        }
+        let count = libc::getgroups(0, std::ptr::null_mut());
+        if count <= 0 {
+            return false;
+        }
+        let mut groups = vec![0; count as usize];
+        let count = libc::getgroups(count, groups.as_mut_ptr());
+        groups.iter().take(count.max(0) as usize).any(|g| *g == gid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(vendor_id: u16, product_id: u16) -> USBDeviceIdentifier {
+        USBDeviceIdentifier { vendor_id, product_id }
+    }
+
+    #[test]
+    fn rules_grant_the_group_access_to_each_device() {
+        let rules = rules("usbwasi", &[id(0x0951, 0x1666), id(0x046d, 0xc52b)]);
+        let lines: Vec<&str> = rules.lines().filter(|l| !l.is_empty() && !l.starts_with('#')).collect();
+        assert_eq!(
+            lines,
+            [
+                r#"SUBSYSTEM=="usb", ENV{DEVTYPE}=="usb_device", ATTR{idVendor}=="0951", ATTR{idProduct}=="1666", MODE="0660", GROUP="usbwasi""#,
+                r#"SUBSYSTEM=="usb", ENV{DEVTYPE}=="usb_device", ATTR{idVendor}=="046d", ATTR{idProduct}=="c52b", MODE="0660", GROUP="usbwasi""#,
+            ]
+        );
+        assert!(rules.contains("\n# 0951:1666\n"));
+    }
+
+    #[test]
+    fn rules_never_deauthorize() {
+        let rules = rules("plugdev", &[id(0x0781, 0x5581)]);
+        assert!(!rules.contains("authorized"));
+        assert!(rules.contains(r#"GROUP="plugdev""#));
+    }
+
+    #[test]
+    fn rules_without_devices_only_have_the_header() {
+        let rules = rules("usbwasi", &[]);
+        assert_eq!(rules.lines().count(), 1);
+        assert!(rules.starts_with("# generated by"));
+    }
+
+    #[test]
+    fn rules_from_a_policy_file() {
+        let policy: PolicyFile = toml::from_str(
+            r#"
+            group = "usbwasi"
+
+            [[device]]
+            id = "0951:1666"
+            "#,
+        )
+        .unwrap();
+        let devices: Vec<USBDeviceIdentifier> = policy.devices.iter().map(|d| d.id.clone()).collect();
+        assert_eq!(devices, [id(0x0951, 0x1666)]);
+        assert!(rules(&policy.group, &devices).contains(r#"ATTR{idVendor}=="0951", ATTR{idProduct}=="1666""#));
+    }
+
+    #[test]
+    fn policy_file_rejects_bad_ids() {
+        let policy = toml::from_str::<PolicyFile>("[[device]]\nid = \"0951-1666\"\n");
+        assert!(policy.unwrap_err().to_string().contains("0951-1666"));
+    }
+}
@@ -0,0 +1,149 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::Args;
+use wasmtime::Error;
+use wasmtime_wasi::pipe::AsyncReadStream;
+use wasmtime_wasi::{AsyncStdinStream, DirPerms, FilePerms, OutputFile, WasiCtx, WasiCtxBuilder};
+
+/// Options that shape the WASI environment the guest runs in.
+#[derive(Args, Debug, Default, Clone)]
+pub struct WasiArgs {
+    /// Environment variable passed to the guest (KEY=VAL), can be repeated
+    #[arg(long = "env", short = 'e')]
+    pub envs: Vec<EnvVar>,
+
+    /// Preopen a host directory in the guest (host::guest[:ro]), can be repeated.
+    /// Nothing is preopened by default.
+    #[arg(long = "dir")]
+    pub dirs: Vec<PreopenDir>,
+
+    /// Read the guest's stdin from this file instead of inheriting it
+    #[arg(long)]
+    pub stdin: Option<PathBuf>,
+
+    /// Write the guest's stdout to this file instead of inheriting it
+    #[arg(long)]
+    pub stdout: Option<PathBuf>,
+
+    /// Arguments passed to the guest, after `--`
+    #[arg(last = true)]
+    pub guest_args: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvVar {
+    pub key: String,
+    pub value: String,
+}
+
+impl FromStr for EnvVar {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s.split_once('=').ok_or("Invalid format. Expected KEY=VAL")?;
+        if key.is_empty() {
+            return Err("Environment variable name must not be empty");
+        }
+        Ok(Self { key: key.to_string(), value: value.to_string() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreopenDir {
+    pub host: PathBuf,
+    pub guest: String,
+    pub read_only: bool,
+}
+
+impl FromStr for PreopenDir {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (host, guest) = s.split_once("::").ok_or("Invalid format. Expected host::guest[:ro]")?;
+        let (guest, read_only) = match guest.strip_suffix(":ro") {
+            Some(guest) => (guest, true),
+            None => (guest, false),
+        };
+        if host.is_empty() || guest.is_empty() {
+            return Err("Host and guest path must not be empty");
+        }
+        Ok(Self { host: PathBuf::from(host), guest: guest.to_string(), read_only })
+    }
+}
+
+impl WasiArgs {
+    /// Build the WASI context for a guest. argv[0] is the component path.
+    pub fn build_ctx(&self, component_path: &Path) -> Result<WasiCtx, Error> {
+        let mut builder = WasiCtxBuilder::new();
+        builder.arg(component_path.display().to_string());
+        builder.args(&self.guest_args);
+        for env in &self.envs {
+            builder.env(&env.key, &env.value);
+        }
+        for dir in &self.dirs {
+            let (dir_perms, file_perms) = if dir.read_only {
+                (DirPerms::READ, FilePerms::READ)
+            } else {
+                (DirPerms::all(), FilePerms::all())
+            };
+            builder.preopened_dir(&dir.host, &dir.guest, dir_perms, file_perms)?;
+        }
+
+        match &self.stdin {
+            Some(path) => {
+                let file = tokio::fs::File::from_std(std::fs::File::open(path)?);
+                builder.stdin(AsyncStdinStream::new(AsyncReadStream::new(file)));
+            }
+            None => {
+                builder.inherit_stdin();
+            }
+        }
+        match &self.stdout {
+            Some(path) => {
+                builder.stdout(OutputFile::new(std::fs::File::create(path)?));
+            }
+            None => {
+                builder.inherit_stdout();
+            }
+        }
+        builder.inherit_stderr();
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_var_splits_at_the_first_equals_sign() {
+        let env: EnvVar = "RUST_LOG=usb=debug".parse().unwrap();
+        assert_eq!(env, EnvVar { key: "RUST_LOG".into(), value: "usb=debug".into() });
+        let empty: EnvVar = "EMPTY=".parse().unwrap();
+        assert_eq!(empty.value, "");
+    }
+
+    #[test]
+    fn env_var_needs_a_name_and_equals_sign() {
+        assert!("NOVALUE".parse::<EnvVar>().is_err());
+        assert!("=value".parse::<EnvVar>().is_err());
+    }
+
+    #[test]
+    fn preopen_dir_parses_read_only_suffix() {
+        let dir: PreopenDir = "/tmp/data::/data:ro".parse().unwrap();
+        assert_eq!(dir, PreopenDir { host: PathBuf::from("/tmp/data"), guest: "/data".into(), read_only: true });
+        let dir: PreopenDir = "out::/out".parse().unwrap();
+        assert!(!dir.read_only);
+        assert_eq!(dir.guest, "/out");
+    }
+
+    #[test]
+    fn preopen_dir_rejects_missing_parts() {
+        assert!("/tmp/data".parse::<PreopenDir>().is_err());
+        assert!("::/data".parse::<PreopenDir>().is_err());
+        assert!("/tmp/data::".parse::<PreopenDir>().is_err());
+        assert!("/tmp/data:::ro".parse::<PreopenDir>().is_err());
+    }
+}
@@ -0,0 +1,1645 @@
+// Generated by `generate::isle_macro`, /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/lib.rs:22
+#[macro_export] // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate.rs:38
+macro_rules! isle_assembler_methods {
+    () => {
+        fn x64_addb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_addw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_addl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_addq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_addb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_addb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::addb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_addw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_addl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_addq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::addq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_adcb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_adcw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_adcl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_adcq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_adcb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_adcb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::adcb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_adcw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_adcl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_adcq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::adcq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_andb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_andw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_andl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_andq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_andb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_andb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::andb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_andw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_andl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_andq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::andq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_orb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_orw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_orl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_orq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_orb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_orb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_orw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_orl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_orq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::orq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_orpd_a_raw(&mut self, xmm: Xmm, rm128: &XmmMem) -> AssemblerOutputs {
+            let xmm = self.convert_xmm_to_assembler_read_write_xmm(xmm); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm128 = self.convert_xmm_mem_to_assembler_read_xmm_mem(rm128); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::orpd_a::new(xmm.clone(), rm128.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let xmm = xmm.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:313
+            AssemblerOutputs::RetXmm { inst, xmm }
+        }
+        fn x64_shldw_mri_raw(&mut self, rm16: &GprMem, r16: Gpr, imm8: u8) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::shldw_mri::new(
+                rm16.clone(),
+                r16.clone(),
+                imm8.clone(),
+            )
+            .into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_shldw_mrc_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::shldw_mrc::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_shldl_mri_raw(&mut self, rm32: &GprMem, r32: Gpr, imm8: u8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::shldl_mri::new(
+                rm32.clone(),
+                r32.clone(),
+                imm8.clone(),
+            )
+            .into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_shldq_mri_raw(&mut self, rm64: &GprMem, r64: Gpr, imm8: u8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::shldq_mri::new(
+                rm64.clone(),
+                r64.clone(),
+                imm8.clone(),
+            )
+            .into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_shldl_mrc_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::shldl_mrc::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_shldq_mrc_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::shldq_mrc::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_subw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_subl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_subq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_subb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_subb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::subb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_subw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_subl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_subq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::subq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_sbbb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_sbbw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_sbbl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_sbbq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_sbbb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_sbbb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::sbbb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_sbbw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_sbbl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_sbbq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::sbbq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_xorb_i_raw(&mut self, imm8: u8) -> AssemblerOutputs {
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorb_i::new(imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_xorw_i_raw(&mut self, imm16: u16) -> AssemblerOutputs {
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorw_i::new(imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_xorl_i_raw(&mut self, imm32: u32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorl_i::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_xorq_i_sxl_raw(&mut self, imm32: i32) -> AssemblerOutputs {
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorq_i_sxl::new(imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            todo!() // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:308
+        }
+        fn x64_xorb_mi_raw(&mut self, rm8: &GprMem, imm8: u8) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Imm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorb_mi::new(rm8.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorw_mi_raw(&mut self, rm16: &GprMem, imm16: u16) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm16 = cranelift_assembler_x64::Imm16::new(imm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorw_mi::new(rm16.clone(), imm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorl_mi_raw(&mut self, rm32: &GprMem, imm32: u32) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Imm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorl_mi::new(rm32.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorq_mi_sxl_raw(&mut self, rm64: &GprMem, imm32: i32) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm32 = cranelift_assembler_x64::Simm32::new(imm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorq_mi_sxl::new(rm64.clone(), imm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorl_mi_sxb_raw(&mut self, rm32: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorl_mi_sxb::new(rm32.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorq_mi_sxb_raw(&mut self, rm64: &GprMem, imm8: i8) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let imm8 = cranelift_assembler_x64::Simm8::new(imm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorq_mi_sxb::new(rm64.clone(), imm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorb_mr_raw(&mut self, rm8: &GprMem, r8: Gpr) -> AssemblerOutputs {
+            let rm8 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r8 = cranelift_assembler_x64::Gpr::new(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorb_mr::new(rm8.clone(), r8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm8 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorw_mr_raw(&mut self, rm16: &GprMem, r16: Gpr) -> AssemblerOutputs {
+            let rm16 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r16 = cranelift_assembler_x64::Gpr::new(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorw_mr::new(rm16.clone(), r16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm16 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorl_mr_raw(&mut self, rm32: &GprMem, r32: Gpr) -> AssemblerOutputs {
+            let rm32 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r32 = cranelift_assembler_x64::Gpr::new(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorl_mr::new(rm32.clone(), r32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm32 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorq_mr_raw(&mut self, rm64: &GprMem, r64: Gpr) -> AssemblerOutputs {
+            let rm64 = self.convert_gpr_mem_to_assembler_read_write_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let r64 = cranelift_assembler_x64::Gpr::new(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorq_mr::new(rm64.clone(), r64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            match rm64 {
+                asm::GprMem::Gpr(reg) => {
+                    let gpr = reg.write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:344
+                    AssemblerOutputs::RetGpr { inst, gpr } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:345
+                }
+                asm::GprMem::Mem(_) => {
+                    AssemblerOutputs::SideEffect { inst } // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:349
+                }
+            }
+        }
+        fn x64_xorb_rm_raw(&mut self, r8: Gpr, rm8: &GprMem) -> AssemblerOutputs {
+            let r8 = self.convert_gpr_to_assembler_read_write_gpr(r8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm8 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm8); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst = cranelift_assembler_x64::inst::xorb_rm::new(r8.clone(), rm8.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r8.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_xorw_rm_raw(&mut self, r16: Gpr, rm16: &GprMem) -> AssemblerOutputs {
+            let r16 = self.convert_gpr_to_assembler_read_write_gpr(r16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm16 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm16); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorw_rm::new(r16.clone(), rm16.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r16.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_xorl_rm_raw(&mut self, r32: Gpr, rm32: &GprMem) -> AssemblerOutputs {
+            let r32 = self.convert_gpr_to_assembler_read_write_gpr(r32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm32 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm32); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorl_rm::new(r32.clone(), rm32.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r32.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+        fn x64_xorq_rm_raw(&mut self, r64: Gpr, rm64: &GprMem) -> AssemblerOutputs {
+            let r64 = self.convert_gpr_to_assembler_read_write_gpr(r64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let rm64 = self.convert_gpr_mem_to_assembler_read_gpr_mem(rm64); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:288
+            let inst =
+                cranelift_assembler_x64::inst::xorq_rm::new(r64.clone(), rm64.clone()).into(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:297
+            let inst = MInst::External { inst }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:298
+            let gpr = r64.as_ref().write.to_reg(); // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate/inst.rs:317
+            AssemblerOutputs::RetGpr { inst, gpr }
+        }
+    }; // /root/.cargo/registry/src/index.crates.io-1949cf8c6b5b557f/cranelift-assembler-x64-meta-0.118.0/src/generate.rs:47
+}
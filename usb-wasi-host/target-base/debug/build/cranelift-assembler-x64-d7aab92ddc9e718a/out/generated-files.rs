@@ -0,0 +1,5 @@
+vec![
+  "/root/crate/usb-wasi-host/target-base/debug/build/cranelift-assembler-x64-d7aab92ddc9e718a/out/assembler.rs".into(),
+  "/root/crate/usb-wasi-host/target-base/debug/build/cranelift-assembler-x64-d7aab92ddc9e718a/out/assembler-isle-macro.rs".into(),
+  "/root/crate/usb-wasi-host/target-base/debug/build/cranelift-assembler-x64-d7aab92ddc9e718a/out/assembler-definitions.isle".into(),
+]
@@ -0,0 +1,133 @@
+use crate::bindings::component::usb::device as wit;
+use crate::{BackendCapabilities, Device, DeviceHandle, Result};
+
+/// Entry point of the API, the USB backend of the host is initialized while one exists.
+#[derive(Debug)]
+pub struct Context {
+    _private: (),
+}
+
+impl Context {
+    pub fn new() -> Result<Self> {
+        wit::init()?;
+        Ok(Self { _private: () })
+    }
+
+    /// What the USB backend of the host supports.
+    pub fn capabilities(&self) -> BackendCapabilities {
+        wit::capabilities()
+    }
+
+    /// All devices the device policy of the host lets the guest see.
+    pub fn devices(&self) -> Result<Vec<Device>> {
+        list(None)
+    }
+
+    /// The devices that match every criterion set in `filter`.
+    pub fn devices_matching(&self, filter: &Filter) -> Result<Vec<Device>> {
+        list(Some(&filter.to_wit()))
+    }
+
+    /// Open the first device with the given vendor and product id.
+    pub fn open(&self, vendor_id: u16, product_id: u16) -> Result<DeviceHandle> {
+        Ok(DeviceHandle::new(wit::open_device_with_vid_pid(vendor_id, product_id)?))
+    }
+}
+
+fn list(filter: Option<&wit::DeviceFilter>) -> Result<Vec<Device>> {
+    let devices = wit::list_devices(filter)?;
+    Ok(devices
+        .into_iter()
+        .map(|(device, descriptor, location, metadata)| Device::new(device, descriptor, location, metadata))
+        .collect())
+}
+
+/// Criteria for [`Context::devices_matching`], unset fields match any device.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    vendor_id: Option<u16>,
+    product_id: Option<u16>,
+    class: Option<u8>,
+    bus: Option<u8>,
+    port_path: Option<Vec<u8>>,
+    serial_number: Option<String>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn vendor_id(mut self, vendor_id: u16) -> Self {
+        self.vendor_id = Some(vendor_id);
+        self
+    }
+
+    pub fn product_id(mut self, product_id: u16) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Matches the device class and the classes of the interfaces of the active configuration.
+    pub fn class(mut self, class: u8) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn bus(mut self, bus: u8) -> Self {
+        self.bus = Some(bus);
+        self
+    }
+
+    /// Port numbers from the root hub down to the device, as [`Device::port_path`] returns them.
+    pub fn port_path(mut self, port_path: impl Into<Vec<u8>>) -> Self {
+        self.port_path = Some(port_path.into());
+        self
+    }
+
+    /// The host has to open devices to compare serial numbers, so only devices it has access to match.
+    pub fn serial_number(mut self, serial_number: impl Into<String>) -> Self {
+        self.serial_number = Some(serial_number.into());
+        self
+    }
+
+    fn to_wit(&self) -> wit::DeviceFilter {
+        wit::DeviceFilter {
+            vendor_id: self.vendor_id,
+            product_id: self.product_id,
+            class: self.class,
+            bus: self.bus,
+            port_path: self.port_path.clone(),
+            serial_number: self.serial_number.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = Filter::new().to_wit();
+        assert_eq!((filter.vendor_id, filter.product_id, filter.class, filter.bus), (None, None, None, None));
+        assert_eq!(filter.port_path, None);
+        assert_eq!(filter.serial_number, None);
+    }
+
+    #[test]
+    fn filter_to_wit() {
+        let filter = Filter::new()
+            .vendor_id(0x0781)
+            .product_id(0x5567)
+            .class(0x08)
+            .bus(3)
+            .port_path([1, 4])
+            .serial_number("4C530001")
+            .to_wit();
+        assert_eq!((filter.vendor_id, filter.product_id), (Some(0x0781), Some(0x5567)));
+        assert_eq!((filter.class, filter.bus), (Some(0x08), Some(3)));
+        assert_eq!(filter.port_path, Some(vec![1, 4]));
+        assert_eq!(filter.serial_number.as_deref(), Some("4C530001"));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::bindings::component::usb::device as wit;
+use crate::{ConfigurationDescriptor, DeviceDescriptor, DeviceHandle, DeviceLocation, DeviceMetadata, Result, UsbSpeed};
+
+/// A device attached to the host, with the descriptor, location and metadata it was enumerated with.
+#[derive(Debug)]
+pub struct Device {
+    inner: wit::UsbDevice,
+    descriptor: DeviceDescriptor,
+    location: DeviceLocation,
+    metadata: Option<DeviceMetadata>,
+}
+
+impl Device {
+    pub(crate) fn new(
+        inner: wit::UsbDevice,
+        descriptor: DeviceDescriptor,
+        location: DeviceLocation,
+        metadata: Option<DeviceMetadata>,
+    ) -> Self {
+        Self { inner, descriptor, location, metadata }
+    }
+
+    pub fn open(&self) -> Result<DeviceHandle> {
+        Ok(DeviceHandle::new(self.inner.open()?))
+    }
+
+    /// Identifies the device while it is attached, the same as [`DeviceHandle::device_id`].
+    pub fn id(&self) -> u64 {
+        self.inner.id()
+    }
+
+    pub fn descriptor(&self) -> &DeviceDescriptor {
+        &self.descriptor
+    }
+
+    pub fn vendor_id(&self) -> u16 {
+        self.descriptor.vendor_id
+    }
+
+    pub fn product_id(&self) -> u16 {
+        self.descriptor.product_id
+    }
+
+    pub fn location(&self) -> &DeviceLocation {
+        &self.location
+    }
+
+    pub fn bus_number(&self) -> u8 {
+        self.location.bus_number
+    }
+
+    pub fn address(&self) -> u8 {
+        self.location.device_address
+    }
+
+    pub fn speed(&self) -> UsbSpeed {
+        self.location.speed
+    }
+
+    /// Port numbers from the root hub down to the device, empty for a root hub.
+    pub fn port_path(&self) -> Result<Vec<u8>> {
+        Ok(self.inner.get_port_path()?)
+    }
+
+    pub fn config_descriptor(&self, index: u8) -> Result<ConfigurationDescriptor> {
+        Ok(self.inner.get_configuration_descriptor(index)?)
+    }
+
+    pub fn config_descriptor_by_value(&self, value: u8) -> Result<ConfigurationDescriptor> {
+        Ok(self.inner.get_configuration_descriptor_by_value(value)?)
+    }
+
+    pub fn active_config_descriptor(&self) -> Result<ConfigurationDescriptor> {
+        Ok(self.inner.get_active_configuration_descriptor()?)
+    }
+
+    /// Strings and interface drivers as the host's OS lists them, without opening the device.
+    /// Devices from [`Context::devices`](crate::Context::devices) already carry them.
+    pub fn metadata(&self) -> Result<DeviceMetadata> {
+        match &self.metadata {
+            Some(metadata) => Ok(metadata.clone()),
+            None => Ok(self.inner.get_metadata()?),
+        }
+    }
+
+    /// The hub the device is connected to, none for a root hub or a hub the guest may not see.
+    pub fn parent(&self) -> Result<Option<Device>> {
+        let Some(parent) = self.inner.get_parent() else {
+            return Ok(None);
+        };
+        let descriptor = parent.get_device_descriptor()?;
+        let location = parent.get_location();
+        Ok(Some(Device::new(parent, descriptor, location, None)))
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+use crate::LibusbError;
+
+/// Error of a WASI-USB call, wrapping the [`LibusbError`] the host returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Error(LibusbError);
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// The error code the host returned.
+    pub fn kind(&self) -> LibusbError {
+        self.0
+    }
+
+    /// Whether the device is gone, after which the handle and everything opened on it stop working.
+    pub fn is_disconnect(&self) -> bool {
+        self.0 == LibusbError::NoDevice
+    }
+}
+
+impl From<LibusbError> for Error {
+    fn from(error: LibusbError) -> Self {
+        Self(error)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.0 {
+            LibusbError::Io => "input/output error",
+            LibusbError::InvalidParam => "invalid parameter",
+            LibusbError::Access => "access denied",
+            LibusbError::NoDevice => "no such device, it may have been disconnected",
+            LibusbError::NotFound => "entity not found",
+            LibusbError::Busy => "resource busy",
+            LibusbError::Timeout => "operation timed out",
+            LibusbError::Overflow => "overflow",
+            LibusbError::Pipe => "pipe error, the endpoint stalled",
+            LibusbError::Interrupted => "system call interrupted",
+            LibusbError::NoMem => "insufficient memory",
+            LibusbError::NotSupported => "operation not supported",
+            LibusbError::Other => "other error",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn wraps_the_host_error() {
+        let error = Error::from(LibusbError::Pipe);
+        assert_eq!(error.kind(), LibusbError::Pipe);
+        assert_eq!(error.to_string(), "pipe error, the endpoint stalled");
+        // the message already describes the host error, there is nothing underneath it
+        assert!(error.source().is_none());
+        assert!(!error.is_disconnect());
+        assert!(Error::from(LibusbError::NoDevice).is_disconnect());
+    }
+}
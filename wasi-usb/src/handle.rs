@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use crate::bindings::component::usb::configuration::ConfigValue;
+use crate::bindings::component::usb::device as wit;
+use crate::{timeout_ms, BosDescriptor, ControlRequest, Direction, Error, Interface, LibusbError, Pollable, Result, Transfer};
+
+/// An open device, closed when it is dropped.
+#[derive(Debug)]
+pub struct DeviceHandle {
+    inner: wit::DeviceHandle,
+}
+
+impl DeviceHandle {
+    pub(crate) fn new(inner: wit::DeviceHandle) -> Self {
+        Self { inner }
+    }
+
+    pub(crate) fn inner(&self) -> &wit::DeviceHandle {
+        &self.inner
+    }
+
+    /// The identifier [`Device::id`](crate::Device::id) returns for the device.
+    pub fn device_id(&self) -> u64 {
+        self.inner.device_id()
+    }
+
+    /// Ready once the device is unplugged, from then on every call fails with no-device.
+    pub fn on_disconnect(&self) -> Pollable {
+        self.inner.on_disconnect()
+    }
+
+    /// The bConfigurationValue of the active configuration, none if the device is unconfigured.
+    pub fn configuration(&self) -> Result<Option<u8>> {
+        let value = self.inner.get_configuration()?;
+        Ok((value != 0).then_some(value))
+    }
+
+    /// Select configuration `value`, or unconfigure the device with none.
+    pub fn set_configuration(&self, value: Option<u8>) -> Result<()> {
+        let config = match value {
+            Some(value) => ConfigValue::Value(value),
+            None => ConfigValue::Unconfigured,
+        };
+        Ok(self.inner.set_configuration(config)?)
+    }
+
+    /// Claim interface `number`, it is released when the returned [`Interface`] is dropped.
+    pub fn claim_interface(&self, number: u8) -> Result<Interface<'_>> {
+        Ok(Interface::new(self, self.inner.claim_interface(number)?))
+    }
+
+    /// Clear the halt condition of the endpoint at `address`.
+    pub fn clear_halt(&self, address: u8) -> Result<()> {
+        Ok(self.inner.clear_halt(address)?)
+    }
+
+    /// Reset the device. The configuration and claimed interfaces are restored unless the device
+    /// came back with other descriptors, then this fails with not-found and the handle is gone.
+    pub fn reset(&self) -> Result<()> {
+        Ok(self.inner.reset_device()?)
+    }
+
+    pub fn kernel_driver_active(&self, interface: u8) -> Result<bool> {
+        Ok(self.inner.kernel_driver_active(interface)?)
+    }
+
+    pub fn detach_kernel_driver(&self, interface: u8) -> Result<()> {
+        Ok(self.inner.detach_kernel_driver(interface)?)
+    }
+
+    pub fn attach_kernel_driver(&self, interface: u8) -> Result<()> {
+        Ok(self.inner.attach_kernel_driver(interface)?)
+    }
+
+    /// Let claiming an interface detach its kernel driver and releasing it attach the driver again.
+    pub fn set_auto_detach_kernel_driver(&self, enable: bool) -> Result<()> {
+        Ok(self.inner.set_auto_detach_kernel_driver(enable)?)
+    }
+
+    pub fn alloc_streams(&self, num_streams: u32, endpoints: &[u8]) -> Result<()> {
+        Ok(self.inner.alloc_streams(num_streams, endpoints)?)
+    }
+
+    pub fn free_streams(&self, endpoints: &[u8]) -> Result<()> {
+        Ok(self.inner.free_streams(endpoints)?)
+    }
+
+    pub fn manufacturer(&self) -> Result<Option<String>> {
+        Ok(self.inner.get_manufacturer()?)
+    }
+
+    pub fn product(&self) -> Result<Option<String>> {
+        Ok(self.inner.get_product()?)
+    }
+
+    pub fn serial_number(&self) -> Result<Option<String>> {
+        Ok(self.inner.get_serial_number()?)
+    }
+
+    pub fn languages(&self) -> Result<Vec<u16>> {
+        Ok(self.inner.get_supported_languages()?)
+    }
+
+    pub fn string_descriptor(&self, index: u8, language_id: u16) -> Result<String> {
+        Ok(self.inner.get_string_descriptor(index, language_id)?)
+    }
+
+    /// Configuration descriptor `index` as the raw bytes the device sent.
+    pub fn raw_config_descriptor(&self, index: u8) -> Result<Vec<u8>> {
+        Ok(self.inner.get_raw_configuration_descriptor(index)?)
+    }
+
+    pub fn bos_descriptor(&self) -> Result<BosDescriptor> {
+        Ok(self.inner.get_bos_descriptor()?)
+    }
+
+    /// Send an IN `request` and read up to `length` bytes of its data stage.
+    pub fn control_in(&self, request: ControlRequest, length: u16, timeout: Duration) -> Result<Vec<u8>> {
+        if request.direction() != Direction::In {
+            return Err(Error::from(LibusbError::InvalidParam));
+        }
+        Ok(self.inner.control_in(request.setup(), length, timeout_ms(timeout))?)
+    }
+
+    /// Send an OUT `request` with `data` as its data stage.
+    pub fn control_out(&self, request: ControlRequest, data: &[u8], timeout: Duration) -> Result<()> {
+        if request.direction() != Direction::Out {
+            return Err(Error::from(LibusbError::InvalidParam));
+        }
+        Ok(self.inner.control_out(request.setup(), data, timeout_ms(timeout))?)
+    }
+
+    /// Create a control transfer for `request` to submit and wait for later.
+    /// `length` is the number of bytes to read for IN requests or to send for OUT requests.
+    pub fn control_transfer(&self, request: ControlRequest, length: u32, timeout: Duration) -> Result<Transfer> {
+        let transfer = self.inner.new_control_transfer(request.setup(), length, timeout_ms(timeout))?;
+        Ok(Transfer::new(transfer))
+    }
+
+    /// Close the handle now instead of when it is dropped.
+    pub fn close(self) {
+        self.inner.close();
+    }
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use crate::bindings::component::usb::device as wit;
+use crate::bindings::component::usb::transfers::TransferOptions;
+use crate::{timeout_ms, DeviceHandle, Direction, EndpointDescriptor, Error, LibusbError, Result, Transfer, TransferType};
+
+/// A claimed interface, released when it is dropped. It borrows the handle it was claimed on,
+/// so the handle cannot be closed while the interface is in use.
+#[derive(Debug)]
+pub struct Interface<'h> {
+    handle: &'h DeviceHandle,
+    inner: wit::ClaimedInterface,
+}
+
+impl<'h> Interface<'h> {
+    pub(crate) fn new(handle: &'h DeviceHandle, inner: wit::ClaimedInterface) -> Self {
+        Self { handle, inner }
+    }
+
+    /// The bInterfaceNumber of the interface.
+    pub fn number(&self) -> u8 {
+        self.inner.interface_number()
+    }
+
+    pub fn alt_setting(&self) -> u8 {
+        self.inner.alt_setting()
+    }
+
+    /// Select another alternate setting, endpoints taken before keep referring to the old one.
+    pub fn set_alt_setting(&self, alt_setting: u8) -> Result<()> {
+        Ok(self.inner.set_alt_setting(alt_setting)?)
+    }
+
+    /// The endpoints of the current alternate setting.
+    pub fn endpoints(&self) -> Result<Vec<Endpoint<'h>>> {
+        let endpoints = self.inner.endpoints()?;
+        Ok(endpoints.into_iter().map(|e| Endpoint::new(self.handle, e)).collect())
+    }
+
+    /// The endpoint at `address` (with direction bit) in the current alternate setting.
+    pub fn endpoint(&self, address: u8) -> Result<Endpoint<'h>> {
+        Ok(Endpoint::new(self.handle, self.inner.endpoint(address)?))
+    }
+
+    /// The first endpoint of the current alternate setting with the given type and direction,
+    /// e.g. the bulk IN endpoint of a mass storage interface.
+    pub fn find_endpoint(&self, transfer_type: TransferType, direction: Direction) -> Result<Endpoint<'h>> {
+        self.endpoints()?
+            .into_iter()
+            .find(|e| e.transfer_type() == transfer_type && e.direction() == direction)
+            .ok_or(Error::from(LibusbError::NotFound))
+    }
+}
+
+/// An endpoint of a claimed interface, it stops working once the interface is released.
+#[derive(Debug)]
+pub struct Endpoint<'h> {
+    handle: &'h DeviceHandle,
+    inner: wit::Endpoint,
+    address: u8,
+    direction: Direction,
+    transfer_type: TransferType,
+}
+
+impl<'h> Endpoint<'h> {
+    fn new(handle: &'h DeviceHandle, inner: wit::Endpoint) -> Self {
+        Self {
+            handle,
+            address: inner.address(),
+            direction: inner.direction(),
+            transfer_type: inner.transfer_type(),
+            inner,
+        }
+    }
+
+    /// Endpoint address with direction bit.
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn transfer_type(&self) -> TransferType {
+        self.transfer_type
+    }
+
+    pub fn max_packet_size(&self) -> u16 {
+        self.inner.max_packet_size()
+    }
+
+    pub fn descriptor(&self) -> EndpointDescriptor {
+        self.inner.descriptor()
+    }
+
+    pub fn clear_halt(&self) -> Result<()> {
+        Ok(self.inner.clear_halt()?)
+    }
+
+    /// Read up to `length` bytes from a bulk or interrupt IN endpoint.
+    pub fn read(&self, length: u32, timeout: Duration) -> Result<Vec<u8>> {
+        let handle = self.handle.inner();
+        let data = match self.transfer_type {
+            TransferType::Bulk => handle.bulk_read(&self.inner, length, timeout_ms(timeout))?,
+            TransferType::Interrupt => handle.interrupt_read(&self.inner, length, timeout_ms(timeout))?,
+            _ => return Err(LibusbError::InvalidParam.into()),
+        };
+        Ok(data)
+    }
+
+    /// Write `data` to a bulk or interrupt OUT endpoint.
+    pub fn write(&self, data: &[u8], timeout: Duration) -> Result<()> {
+        let handle = self.handle.inner();
+        match self.transfer_type {
+            TransferType::Bulk => handle.bulk_write(&self.inner, data, timeout_ms(timeout))?,
+            TransferType::Interrupt => handle.interrupt_write(&self.inner, data, timeout_ms(timeout))?,
+            _ => return Err(LibusbError::InvalidParam.into()),
+        }
+        Ok(())
+    }
+
+    /// Create a transfer of `length` bytes to submit and wait for later.
+    pub fn transfer(&self, length: u32, timeout: Duration) -> Result<Transfer> {
+        self.transfer_with(length, timeout_ms(timeout), 0, 0)
+    }
+
+    /// Create an isochronous transfer of `packets` packets sharing `length` bytes.
+    pub fn iso_transfer(&self, length: u32, packets: u32, timeout: Duration) -> Result<Transfer> {
+        self.transfer_with(length, timeout_ms(timeout), 0, packets)
+    }
+
+    /// Create a bulk transfer on USB 3 stream `stream_id`, see [`DeviceHandle::alloc_streams`].
+    pub fn stream_transfer(&self, length: u32, stream_id: u32, timeout: Duration) -> Result<Transfer> {
+        self.transfer_with(length, timeout_ms(timeout), stream_id, 0)
+    }
+
+    fn transfer_with(&self, length: u32, timeout_ms: u32, stream_id: u32, iso_packets: u32) -> Result<Transfer> {
+        let opts = TransferOptions { timeout_ms, stream_id, iso_packets };
+        Ok(Transfer::new(self.inner.new_transfer(length, opts)?))
+    }
+}
@@ -0,0 +1,110 @@
+//! Rust API for WASI-USB guests.
+//!
+//! Wraps the bindings `wit-bindgen` generates for the `component:usb` guest world in types that
+//! release what they hold when dropped, a [`std::error::Error`] and builders for the standard
+//! control requests, so guest code does not depend on generated module paths.
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use wasi_usb::{Context, ControlRequest, DescriptorType};
+//!
+//! let context = Context::new()?;
+//! for device in context.devices()? {
+//!     let handle = device.open()?;
+//!     let request = ControlRequest::get_descriptor(DescriptorType::Device, 0, 0);
+//!     let descriptor = handle.control_in(request, 18, Duration::from_secs(1))?;
+//!     println!("{:04x}:{:04x} {:?}", device.vendor_id(), device.product_id(), descriptor);
+//! }
+//! # Ok::<(), wasi_usb::Error>(())
+//! ```
+
+mod context;
+mod device;
+mod error;
+mod handle;
+mod interface;
+mod request;
+mod transfer;
+
+mod bindings {
+    wit_bindgen::generate!({
+        world: "guest",
+        path: "wit",
+        with: {
+            "wasi:io/poll@0.2.5": generate,
+        },
+    });
+}
+
+pub use context::{Context, Filter};
+pub use device::Device;
+pub use error::{Error, Result};
+pub use handle::DeviceHandle;
+pub use interface::{Endpoint, Interface};
+pub use request::{ControlRequest, DescriptorType, Recipient, RequestType};
+pub use transfer::{submit_batch, wait_any, Transfer};
+
+pub use bindings::component::usb::descriptors::{
+    BosDescriptor, ConfigurationDescriptor, DeviceCapability, DeviceDescriptor, EndpointDescriptor,
+    InterfaceDescriptor, UsbInterface,
+};
+pub use bindings::component::usb::device::{
+    BackendCapabilities, DeviceLocation, DeviceMetadata, Direction, InterfaceMetadata, UsbSpeed,
+};
+pub use bindings::component::usb::errors::LibusbError;
+pub use bindings::component::usb::transfers::TransferType;
+pub use bindings::wasi::io::poll::Pollable;
+
+/// WIT takes timeouts in milliseconds, 0 meaning none.
+fn timeout_ms(timeout: std::time::Duration) -> u32 {
+    timeout.as_millis().try_into().unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// The `.wit` files under `dir`, relative to it.
+    fn wit_files(dir: &Path, relative: &Path, files: &mut Vec<PathBuf>) {
+        for entry in fs::read_dir(dir.join(relative)).unwrap() {
+            let entry = entry.unwrap();
+            let path = relative.join(entry.file_name());
+            if entry.file_type().unwrap().is_dir() {
+                wit_files(dir, &path, files);
+            } else if path.extension().is_some_and(|extension| extension == "wit") {
+                files.push(path);
+            }
+        }
+    }
+
+    #[test]
+    fn vendored_wit_matches_repo() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let (vendored, upstream) = (manifest.join("wit"), manifest.join("../wit"));
+        if !upstream.exists() {
+            return; // built outside the repo, e.g. from the published crate
+        }
+        let mut expected = Vec::new();
+        for entry in fs::read_dir(&upstream).unwrap() {
+            let path = PathBuf::from(entry.unwrap().file_name());
+            if path.extension().is_some_and(|extension| extension == "wit") {
+                expected.push(path);
+            }
+        }
+        wit_files(&upstream, Path::new("deps"), &mut expected);
+        let mut actual = Vec::new();
+        wit_files(&vendored, Path::new(""), &mut actual);
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected, "wit/ lists other files than /wit, copy /wit over it again");
+        for path in expected {
+            assert_eq!(
+                fs::read_to_string(vendored.join(&path)).unwrap(),
+                fs::read_to_string(upstream.join(&path)).unwrap(),
+                "wit/{} differs from /wit, copy /wit over it again",
+                path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::bindings::component::usb::transfers::TransferSetup;
+use crate::Direction;
+
+/// Who defines the meaning of a control request (bmRequestType bits 5..6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestType {
+    Standard,
+    Class,
+    Vendor,
+}
+
+/// What a control request is addressed to (bmRequestType bits 0..4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recipient {
+    Device,
+    Interface,
+    Endpoint,
+    Other,
+}
+
+/// Descriptor types of the standard GET_DESCRIPTOR request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DescriptorType {
+    Device,
+    Configuration,
+    String,
+    Interface,
+    Endpoint,
+    DeviceQualifier,
+    Bos,
+    /// A class or vendor specific type, e.g. 0x22 for a HID report descriptor
+    Other(u8),
+}
+
+impl From<DescriptorType> for u8 {
+    fn from(descriptor_type: DescriptorType) -> u8 {
+        match descriptor_type {
+            DescriptorType::Device => 0x01,
+            DescriptorType::Configuration => 0x02,
+            DescriptorType::String => 0x03,
+            DescriptorType::Interface => 0x04,
+            DescriptorType::Endpoint => 0x05,
+            DescriptorType::DeviceQualifier => 0x06,
+            DescriptorType::Bos => 0x0f,
+            DescriptorType::Other(value) => value,
+        }
+    }
+}
+
+/// The setup packet of a control transfer without its wLength, which is given with the buffer
+/// when the request is sent. The constructors build the standard requests of USB 2.0 chapter 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ControlRequest {
+    pub request_type: u8,
+    pub request: u8,
+    pub value: u16,
+    pub index: u16,
+}
+
+impl ControlRequest {
+    pub fn new(
+        direction: Direction,
+        request_type: RequestType,
+        recipient: Recipient,
+        request: u8,
+        value: u16,
+        index: u16,
+    ) -> Self {
+        let direction = match direction {
+            Direction::In => 0x80,
+            Direction::Out => 0x00,
+        };
+        let request_type = match request_type {
+            RequestType::Standard => 0x00,
+            RequestType::Class => 0x20,
+            RequestType::Vendor => 0x40,
+        };
+        let recipient = match recipient {
+            Recipient::Device => 0x00,
+            Recipient::Interface => 0x01,
+            Recipient::Endpoint => 0x02,
+            Recipient::Other => 0x03,
+        };
+        Self { request_type: direction | request_type | recipient, request, value, index }
+    }
+
+    /// A class specific request, e.g. the GET_MAX_LUN of mass storage devices.
+    pub fn class(direction: Direction, recipient: Recipient, request: u8, value: u16, index: u16) -> Self {
+        Self::new(direction, RequestType::Class, recipient, request, value, index)
+    }
+
+    pub fn vendor(direction: Direction, recipient: Recipient, request: u8, value: u16, index: u16) -> Self {
+        Self::new(direction, RequestType::Vendor, recipient, request, value, index)
+    }
+
+    pub fn get_status(recipient: Recipient, index: u16) -> Self {
+        Self::new(Direction::In, RequestType::Standard, recipient, 0x00, 0, index)
+    }
+
+    /// `feature` is e.g. 0 for ENDPOINT_HALT or 1 for DEVICE_REMOTE_WAKEUP.
+    pub fn clear_feature(recipient: Recipient, feature: u16, index: u16) -> Self {
+        Self::new(Direction::Out, RequestType::Standard, recipient, 0x01, feature, index)
+    }
+
+    pub fn set_feature(recipient: Recipient, feature: u16, index: u16) -> Self {
+        Self::new(Direction::Out, RequestType::Standard, recipient, 0x03, feature, index)
+    }
+
+    /// `language_id` is only used for string descriptors and 0 otherwise.
+    pub fn get_descriptor(descriptor_type: DescriptorType, index: u8, language_id: u16) -> Self {
+        let value = (u8::from(descriptor_type) as u16) << 8 | index as u16;
+        Self::new(Direction::In, RequestType::Standard, Recipient::Device, 0x06, value, language_id)
+    }
+
+    pub fn get_configuration() -> Self {
+        Self::new(Direction::In, RequestType::Standard, Recipient::Device, 0x08, 0, 0)
+    }
+
+    pub fn get_interface(interface: u8) -> Self {
+        Self::new(Direction::In, RequestType::Standard, Recipient::Interface, 0x0a, 0, interface as u16)
+    }
+
+    pub fn synch_frame(endpoint: u8) -> Self {
+        Self::new(Direction::In, RequestType::Standard, Recipient::Endpoint, 0x0c, 0, endpoint as u16)
+    }
+
+    /// The direction of the data stage, from bit 7 of bmRequestType.
+    pub fn direction(&self) -> Direction {
+        if self.request_type & 0x80 != 0 {
+            Direction::In
+        } else {
+            Direction::Out
+        }
+    }
+
+    pub(crate) fn setup(&self) -> TransferSetup {
+        TransferSetup {
+            bm_request_type: self.request_type,
+            b_request: self.request,
+            w_value: self.value,
+            w_index: self.index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_request_type() {
+        let request = ControlRequest::new(Direction::In, RequestType::Vendor, Recipient::Other, 0x42, 0x1234, 5);
+        assert_eq!(request.request_type, 0xc3);
+        assert_eq!((request.request, request.value, request.index), (0x42, 0x1234, 5));
+        assert_eq!(request.direction(), Direction::In);
+        let request = ControlRequest::class(Direction::Out, Recipient::Interface, 0xff, 0, 1);
+        assert_eq!(request.request_type, 0x21);
+        assert_eq!(request.direction(), Direction::Out);
+        assert_eq!(ControlRequest::vendor(Direction::Out, Recipient::Endpoint, 1, 0, 0x81).request_type, 0x42);
+    }
+
+    #[test]
+    fn standard_requests() {
+        assert_eq!(
+            ControlRequest::get_status(Recipient::Endpoint, 0x81),
+            ControlRequest { request_type: 0x82, request: 0x00, value: 0, index: 0x81 }
+        );
+        assert_eq!(
+            ControlRequest::clear_feature(Recipient::Endpoint, 0, 0x02),
+            ControlRequest { request_type: 0x02, request: 0x01, value: 0, index: 0x02 }
+        );
+        assert_eq!(
+            ControlRequest::set_feature(Recipient::Device, 1, 0),
+            ControlRequest { request_type: 0x00, request: 0x03, value: 1, index: 0 }
+        );
+        assert_eq!(
+            ControlRequest::get_configuration(),
+            ControlRequest { request_type: 0x80, request: 0x08, value: 0, index: 0 }
+        );
+        assert_eq!(
+            ControlRequest::get_interface(2),
+            ControlRequest { request_type: 0x81, request: 0x0a, value: 0, index: 2 }
+        );
+        assert_eq!(
+            ControlRequest::synch_frame(0x83),
+            ControlRequest { request_type: 0x82, request: 0x0c, value: 0, index: 0x83 }
+        );
+    }
+
+    #[test]
+    fn get_descriptor_value() {
+        let request = ControlRequest::get_descriptor(DescriptorType::String, 2, 0x0409);
+        assert_eq!(request, ControlRequest { request_type: 0x80, request: 0x06, value: 0x0302, index: 0x0409 });
+        assert_eq!(ControlRequest::get_descriptor(DescriptorType::Bos, 0, 0).value, 0x0f00);
+        assert_eq!(ControlRequest::get_descriptor(DescriptorType::Other(0x22), 0, 0).value, 0x2200);
+    }
+
+    #[test]
+    fn setup_packet() {
+        let setup = ControlRequest::get_descriptor(DescriptorType::Device, 0, 0).setup();
+        assert_eq!((setup.bm_request_type, setup.b_request, setup.w_value, setup.w_index), (0x80, 0x06, 0x0100, 0));
+    }
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use crate::bindings::component::usb::transfers;
+use crate::{timeout_ms, Error, Result};
+
+/// A transfer created by [`Endpoint::transfer`](crate::Endpoint::transfer) or
+/// [`DeviceHandle::control_transfer`](crate::DeviceHandle::control_transfer), for callers
+/// that keep several transfers in flight. Dropping a submitted transfer cancels it.
+#[derive(Debug)]
+pub struct Transfer {
+    inner: transfers::Transfer,
+}
+
+impl Transfer {
+    pub(crate) fn new(inner: transfers::Transfer) -> Self {
+        Self { inner }
+    }
+
+    /// Hand the transfer to the device, with `data` for OUT transfers and nothing for IN transfers.
+    pub fn submit(&self, data: &[u8]) -> Result<()> {
+        Ok(self.inner.submit_transfer(data)?)
+    }
+
+    /// Ask for the transfer to be cancelled, [`Transfer::wait`] then fails.
+    pub fn cancel(&self) -> Result<()> {
+        Ok(self.inner.cancel_transfer()?)
+    }
+
+    /// Block until the submitted transfer completes, returning the data an IN transfer read.
+    pub fn wait(self) -> Result<Vec<u8>> {
+        Ok(transfers::await_transfer(self.inner)?)
+    }
+}
+
+/// Submit `transfers` in one call, `data[i]` being the data of `transfers[i]`.
+/// Stops at the first transfer that fails to submit and returns its index with the error.
+pub fn submit_batch(transfers: &[&Transfer], data: &[Vec<u8>]) -> Result<(), (usize, Error)> {
+    let borrowed: Vec<&transfers::Transfer> = transfers.iter().map(|t| &t.inner).collect();
+    transfers::submit_batch(&borrowed, data).map_err(|e| (e.index as usize, e.error.into()))
+}
+
+/// Block until the first of the submitted `transfers` completes, for at most `timeout`
+/// (zero waits forever). Returns its index and result, the others stay pending.
+pub fn wait_any(transfers: &[&Transfer], timeout: Duration) -> Result<(usize, Result<Vec<u8>>)> {
+    let borrowed: Vec<&transfers::Transfer> = transfers.iter().map(|t| &t.inner).collect();
+    let (index, result) = transfers::await_any(&borrowed, timeout_ms(timeout))?;
+    Ok((index as usize, result.map_err(Error::from)))
+}